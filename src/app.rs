@@ -1,20 +1,35 @@
-use dirs::home_dir;
 use log::LevelFilter;
 use toml::Value;
 
 use crate::{
-    constants::{DisplayMode, Pages, Popups},
-    game_logic::{bot::Bot, game::Game, opponent::Opponent},
+    clipboard::copy_to_clipboard,
+    constants::{config_folder, DisplayMode, Pages, Popups, DEMO_RANDOM_MOVES, NETWORK_PORT},
+    export::{board_to_unicode_block, save_board_diagram},
+    game_logic::{
+        board::chess960_board,
+        board_editor::BoardEditor,
+        bot::{detect_engines, Bot, BotDemo, EngineOptions},
+        evaluation::Evaluator,
+        game::{Game, GameState, Settings},
+        game_board::GameBoard,
+        opponent::{Connection, Opponent},
+    },
+    keybindings::KeyBindings,
+    last_game::SavedGame,
     pieces::PieceColor,
-    server::game_server::GameServer,
+    server::{
+        discovery::{HostDiscovery, DISCOVERY_TIMEOUT},
+        game_server::GameServer,
+    },
+    utils::{address_with_port, get_local_ip, get_local_ipv6},
 };
 use std::{
-    error,
-    fs::{self, File},
-    io::Write,
-    net::{IpAddr, UdpSocket},
+    error, fs,
+    net::{IpAddr, SocketAddr, UdpSocket},
+    path::PathBuf,
+    sync::mpsc,
     thread::sleep,
-    time::Duration,
+    time::{Duration, Instant},
 };
 
 /// Application result type.
@@ -36,11 +51,59 @@ pub struct App {
     pub hosting: Option<bool>,
     /// Host Ip
     pub host_ip: Option<String>,
+    /// Port the games are hosted on, and joined on when the address has no port
+    pub network_port: u16,
+    /// Announce the hosted games on the local network and list the announced ones when joining
+    pub lan_discovery: bool,
+    /// The scan of the local network of the join popup
+    pub host_discovery: Option<HostDiscovery>,
     /// menu current cursor
     pub menu_cursor: u8,
     /// path of the chess engine
     pub chess_engine_path: Option<String>,
+    /// Let the chess engine think on the player's time
+    pub engine_ponder: bool,
+    /// Threads, hash and think time of the engine
+    pub engine_options: EngineOptions,
+    /// Show the evaluation of the position by the engine next to the board in bot games
+    pub engine_evaluation: bool,
+    /// The engine process evaluating the positions, started with the game against the bot
+    pub evaluator: Option<Evaluator>,
+    /// The engine versus engine game started with `--bot-vs-bot`
+    pub bot_demo: Option<BotDemo>,
+    /// Chess engines found in the PATH, offered when no engine path is configured
+    pub detected_engines: Vec<PathBuf>,
+    /// Keep the white pieces at the bottom when playing black against the bot
+    pub bot_white_perspective: bool,
+    /// Turn the board toward the player to move in solo games, white stays at the bottom otherwise
+    pub solo_auto_flip: bool,
+    /// Number of random moves played by the hidden demo key of the solo games
+    pub demo_random_moves: usize,
     pub log_level: LevelFilter,
+    /// Board only view (no panels, no help text) used for streaming / recording
+    pub presentation_mode: bool,
+    /// The exported board shown for a manual copy when the clipboard can't be used
+    pub exported_board: Option<String>,
+    /// Key opening the help popup on every page
+    pub help_key: char,
+    /// Keys of the game actions, set with the `[keybindings]` table
+    pub keybindings: KeyBindings,
+    /// The position set up in the board editor
+    pub board_editor: BoardEditor,
+    /// The configured startup page that couldn't be opened, explained in a popup on the home page
+    pub unavailable_startup_page: Option<String>,
+    /// Display modes used instead of the global one in each game mode
+    pub solo_display_mode: Option<DisplayMode>,
+    pub bot_display_mode: Option<DisplayMode>,
+    pub multiplayer_display_mode: Option<DisplayMode>,
+    /// The global display mode, kept while a game mode uses its own
+    pub saved_display_mode: Option<DisplayMode>,
+    /// The solo game left unfinished on the last exit, offered in the menu
+    pub last_game: Option<SavedGame>,
+    /// Number of moves played when the repetition draw was offered, a declined claim isn't asked again
+    pub repetition_prompted_at: Option<usize>,
+    /// The answer of the UCI check of the chess engine, running on its own thread
+    pub engine_check: Option<mpsc::Receiver<bool>>,
 }
 
 impl Default for App {
@@ -53,9 +116,34 @@ impl Default for App {
             selected_color: None,
             hosting: None,
             host_ip: None,
+            network_port: NETWORK_PORT,
+            lan_discovery: false,
+            host_discovery: None,
             menu_cursor: 0,
             chess_engine_path: None,
+            engine_ponder: false,
+            engine_options: EngineOptions::default(),
+            engine_evaluation: false,
+            evaluator: None,
+            bot_demo: None,
+            detected_engines: vec![],
+            bot_white_perspective: false,
+            solo_auto_flip: true,
+            demo_random_moves: DEMO_RANDOM_MOVES,
             log_level: LevelFilter::Off,
+            presentation_mode: false,
+            exported_board: None,
+            help_key: '?',
+            keybindings: KeyBindings::default(),
+            board_editor: BoardEditor::default(),
+            unavailable_startup_page: None,
+            solo_display_mode: None,
+            bot_display_mode: None,
+            multiplayer_display_mode: None,
+            saved_display_mode: None,
+            last_game: None,
+            repetition_prompted_at: None,
+            engine_check: None,
         }
     }
 }
@@ -68,6 +156,60 @@ impl App {
             self.current_popup = Some(Popups::Help);
         }
     }
+    /// Copy the latest move in standard algebraic notation to the clipboard
+    pub fn copy_last_move(&mut self) {
+        let Some(san) = self.game.game_board.san_history.last() else {
+            return;
+        };
+
+        self.game.ui.status_message = Some(match copy_to_clipboard(san) {
+            Ok(()) => format!("Copied {san} to the clipboard"),
+            Err(e) => {
+                log::error!("Failed to copy {san} to the clipboard: {e}");
+                format!("Could not copy {san}: {e}")
+            }
+        });
+    }
+
+    /// Copy the board, as it is drawn, as a unicode block to the clipboard
+    /// The block is shown in a popup when the clipboard can't be used
+    pub fn copy_board(&mut self) {
+        let flipped = self.game.game_board.is_flipped != self.game.is_view_rotated();
+        let block = board_to_unicode_block(&self.game.game_board, flipped);
+
+        match copy_to_clipboard(&block) {
+            Ok(()) => {
+                self.game.ui.status_message = Some("Copied the board to the clipboard".to_string())
+            }
+            Err(e) => {
+                log::error!("Failed to copy the board to the clipboard: {e}");
+                self.exported_board = Some(block);
+                self.current_popup = Some(Popups::ExportedBoard);
+            }
+        }
+    }
+
+    /// Write the position, its FEN and a unicode diagram, in a file of the configuration folder
+    pub fn save_board(&mut self) {
+        let diagram = self.game.game_board.ascii_diagram(self.game.player_turn);
+        let saved = config_folder()
+            .map_err(|e| e.to_string())
+            .and_then(|folder_path| {
+                save_board_diagram(&folder_path, &diagram).map_err(|e| e.to_string())
+            });
+
+        self.game.ui.status_message = Some(match saved {
+            Ok(path) => format!("Saved the board to {}", path.display()),
+            Err(e) => {
+                log::error!("Failed to save the board: {e}");
+                format!("Could not save the board: {e}")
+            }
+        });
+    }
+
+    pub fn toggle_presentation_mode(&mut self) {
+        self.presentation_mode = !self.presentation_mode;
+    }
     pub fn toggle_credit_popup(&mut self) {
         if self.current_page == Pages::Home {
             self.current_page = Pages::Credit;
@@ -78,11 +220,16 @@ impl App {
 
     pub fn setup_game_server(&mut self, host_color: PieceColor) {
         let is_host_white = host_color == PieceColor::White;
+        let port = self.network_port;
+        let lan_discovery = self.lan_discovery;
 
         log::info!("Starting game server with host color: {:?}", host_color);
 
         std::thread::spawn(move || {
-            let game_server = GameServer::new(is_host_white);
+            let game_server = GameServer {
+                lan_discovery,
+                ..GameServer::new(is_host_white, port)
+            };
             log::info!("Game server created, starting server...");
             game_server.run();
         });
@@ -100,11 +247,11 @@ impl App {
         if self.hosting.unwrap() {
             log::info!("Setting up host with color: {:?}", self.selected_color);
             self.current_popup = Some(Popups::WaitingForOpponentToJoin);
-            self.host_ip = Some(format!("{}:2308", self.get_host_ip()));
+            self.host_ip = Some(self.host_address());
         }
 
         let addr = self.host_ip.as_ref().unwrap().to_string();
-        let addr_with_port = addr.to_string();
+        let addr_with_port = address_with_port(&addr, self.network_port);
         log::info!("Attempting to connect to: {}", addr_with_port);
 
         // ping the server to see if it's up
@@ -120,40 +267,263 @@ impl App {
         }
 
         log::info!("Creating opponent with color: {:?}", other_player_color);
-        self.game.opponent = Some(Opponent::new(addr_with_port, other_player_color));
+        let opponent = Opponent::new(addr_with_port, other_player_color);
+        // The color of the local player is always the opposite of the opponent's one, for the host and the client
+        self.selected_color = Some(opponent.color.opposite());
+        log::debug!("Orienting the board for {:?}", self.selected_color);
+        self.game.set_opponent(opponent);
 
         if !self.hosting.unwrap() {
             log::info!("Setting up client (non-host) player");
-            self.selected_color = Some(self.game.opponent.as_mut().unwrap().color.opposite());
             self.game.opponent.as_mut().unwrap().game_started = true;
         }
-
-        if self.selected_color.unwrap() == PieceColor::Black {
-            log::debug!("Flipping board for black player");
-            self.game.game_board.flip_the_board();
-        }
     }
 
     pub fn go_to_home(&mut self) {
+        // The solo game left is the one to resume, or none once it is over
+        if self.current_page == Pages::Solo {
+            self.last_game = SavedGame::from_game(&self.game);
+        }
         self.current_page = Pages::Home;
+        self.bot_demo = None;
         self.restart();
+        self.restore_display_mode();
+        self.game.ui.white_perspective = false;
+    }
+
+    /// Use the display mode configured for the current game mode, if any
+    pub fn apply_page_display_mode(&mut self) {
+        let page_display_mode = match self.current_page {
+            Pages::Solo => self.solo_display_mode,
+            Pages::Bot => self.bot_display_mode,
+            Pages::Multiplayer => self.multiplayer_display_mode,
+            _ => None,
+        };
+
+        if let Some(display_mode) = page_display_mode {
+            // A restart applies it again, the global mode is already saved then
+            if self.saved_display_mode.is_none() {
+                self.saved_display_mode = Some(self.game.ui.display_mode);
+            }
+            self.game.ui.display_mode = display_mode;
+        }
+    }
+
+    /// The display mode of the configuration, not the one of the current game mode
+    pub fn global_display_mode(&self) -> DisplayMode {
+        self.saved_display_mode.unwrap_or(self.game.ui.display_mode)
+    }
+
+    /// Go back to the global display mode when leaving a game mode
+    pub fn restore_display_mode(&mut self) {
+        if let Some(display_mode) = self.saved_display_mode.take() {
+            self.game.ui.display_mode = display_mode;
+        }
     }
 
     pub fn get_host_ip(&self) -> IpAddr {
-        let socket = UdpSocket::bind("0.0.0.0:0").unwrap();
-        socket.connect("8.8.8.8:80").unwrap(); // Use an external IP to identify the default route
+        get_local_ip()
+    }
+
+    /// The address the other player joins the hosted game with
+    pub fn host_address(&self) -> String {
+        SocketAddr::new(self.get_host_ip(), self.network_port).to_string()
+    }
 
-        socket.local_addr().unwrap().ip()
+    /// Ask for the address of the game to join, the games of the local network are listed when enabled
+    pub fn open_join_popup(&mut self) {
+        self.current_popup = Some(Popups::EnterHostIP);
+        if self.lan_discovery && self.host_discovery.is_none() {
+            self.host_discovery = Some(HostDiscovery::start(DISCOVERY_TIMEOUT));
+        }
+    }
+
+    /// The IPv6 address to join the hosted game with, when it isn't already the main one
+    pub fn host_ipv6_address(&self) -> Option<String> {
+        get_local_ipv6()
+            .filter(|&ip| ip != self.get_host_ip())
+            .map(|ip| SocketAddr::new(ip, self.network_port).to_string())
     }
 
     /// Handles the tick event of the terminal.
-    pub fn tick(&self) {}
+    pub fn tick(&mut self) {
+        self.game.ui.tick_animation();
+        self.play_bot_demo_move();
+        self.update_evaluation();
+        self.ask_repetition_claim();
+        if let Some(host_discovery) = self.host_discovery.as_mut() {
+            host_discovery.poll();
+        }
+        self.game.update_opponent_connection();
+        self.update_connection_popup();
+        self.poll_engine_check();
+    }
+
+    /// Show the answer of the UCI check once the engine gave it, the color is picked after a working engine
+    fn poll_engine_check(&mut self) {
+        let Some(engine_check) = self.engine_check.as_ref() else {
+            return;
+        };
+        let is_uci = match engine_check.try_recv() {
+            Ok(is_uci) => is_uci,
+            Err(mpsc::TryRecvError::Empty) => return,
+            Err(mpsc::TryRecvError::Disconnected) => false,
+        };
+        self.engine_check = None;
+        if self.current_popup == Some(Popups::CheckingEngine) {
+            self.current_popup = (!is_uci).then_some(Popups::EngineNotUci);
+        }
+    }
+
+    /// Tell the player when the multiplayer connection dropped, until it is back or given up
+    pub fn update_connection_popup(&mut self) {
+        let Some(opponent) = self.game.opponent.as_ref() else {
+            return;
+        };
+        match opponent.connection {
+            Connection::Reconnecting { .. } if self.current_popup.is_none() => {
+                self.current_popup = Some(Popups::Reconnecting);
+            }
+            Connection::Connected if self.current_popup == Some(Popups::Reconnecting) => {
+                self.current_popup = None;
+            }
+            Connection::Lost => self.current_popup = Some(Popups::ConnectionLost),
+            _ => {}
+        }
+    }
+
+    /// Ask the player to claim the draw when a position is reached for the third time
+    /// It is asked once per position reached, the draw can still be claimed with `x` after declining
+    pub fn ask_repetition_claim(&mut self) {
+        let moves = self.game.game_board.move_history.len();
+        if self.current_popup.is_none()
+            && self.game.game_state == GameState::Playing
+            && self.repetition_prompted_at != Some(moves)
+            && self.game.game_board.is_repetition_to_claim()
+        {
+            self.repetition_prompted_at = Some(moves);
+            self.current_popup = Some(Popups::ClaimDraw);
+        }
+    }
+
+    /// Start a game where two instances of the engine play each other, with a pause between the moves
+    /// Without a working engine the bot page asks for one as usual
+    pub fn start_bot_vs_bot(&mut self, delay: Duration) {
+        self.current_page = Pages::Bot;
+        self.apply_page_display_mode();
+        // Both engines are started right away, so the check waits for the answer
+        self.check_chess_engine();
+        if let Some(engine_check) = self.engine_check.take() {
+            self.current_popup = match engine_check.recv() {
+                Ok(true) => None,
+                _ => Some(Popups::EngineNotUci),
+            };
+        }
+        if self.current_popup.is_some() {
+            log::warn!("The bot versus bot game needs a working engine");
+            return;
+        }
+        let Some(engine_path) = self.chess_engine_path.clone() else {
+            return;
+        };
+
+        // Each side gets its own engine process
+        let engine_options = self.engine_options;
+        let new_bot = || {
+            let mut bot = Bot::new(&engine_path, false);
+            bot.set_options(&engine_options);
+            bot
+        };
+        self.selected_color = Some(PieceColor::White);
+        self.game.bot = Some(new_bot());
+        self.bot_demo = Some(BotDemo {
+            waiting_bot: new_bot(),
+            delay,
+            last_move_at: Instant::now(),
+        });
+    }
+
+    /// Let the engine to move play once the pause is over, then hand the game to the other engine
+    /// The board stays with the white pieces at the bottom
+    pub fn play_bot_demo_move(&mut self) {
+        let Some(bot_demo) = self.bot_demo.as_mut() else {
+            return;
+        };
+        if self.current_page != Pages::Bot
+            || self.current_popup.is_some()
+            || self.game.game_state != GameState::Playing
+            || bot_demo.last_move_at.elapsed() < bot_demo.delay
+        {
+            return;
+        }
+        let Some(bot) = self.game.bot.as_mut() else {
+            return;
+        };
+
+        // A starting bot plays white, its move turns the board toward black as for a player
+        let is_white_move = self.game.player_turn == PieceColor::White;
+        bot.is_bot_starting = is_white_move;
+        self.game.execute_bot_move();
+        if is_white_move {
+            self.game.flip_the_board();
+        }
+        self.game.switch_player_turn();
+
+        if self.game.game_board.is_checkmate(self.game.player_turn) {
+            self.game.game_state = GameState::Checkmate;
+        } else if self.game.game_board.is_draw(self.game.player_turn) {
+            self.game.game_state = GameState::Draw;
+        }
+
+        if let Some(bot) = self.game.bot.as_mut() {
+            bot.is_bot_starting = false;
+            std::mem::swap(bot, &mut bot_demo.waiting_bot);
+        }
+        bot_demo.last_move_at = Instant::now();
+    }
+
+    /// Send the position of the game against the bot to the evaluation engine and collect its score
+    /// The engine is started with the game and stopped once the bot page is left
+    pub fn update_evaluation(&mut self) {
+        if !self.engine_evaluation || self.current_page != Pages::Bot || self.game.bot.is_none() {
+            self.evaluator = None;
+            return;
+        }
+
+        if self.evaluator.is_none() {
+            let Some(engine_path) = self.chess_engine_path.as_ref() else {
+                return;
+            };
+            self.evaluator = Evaluator::new(engine_path);
+            // The engine is not started again at every tick
+            if self.evaluator.is_none() {
+                self.engine_evaluation = false;
+                return;
+            }
+        }
+
+        let Some(evaluator) = self.evaluator.as_mut() else {
+            return;
+        };
+        if self.game.game_state == GameState::Playing {
+            evaluator.evaluate(
+                self.game.game_board.position_fen(self.game.player_turn),
+                self.game.player_turn,
+            );
+        }
+        evaluator.poll();
+    }
 
     /// Set running to false to quit the application.
     pub fn quit(&mut self) {
         self.running = false;
     }
 
+    /// Number of entries of the home menu, the saved game adds one at the end
+    pub fn menu_len(&self) -> u8 {
+        Pages::variant_count() as u8 + self.last_game.is_some() as u8
+    }
+
     pub fn menu_cursor_up(&mut self, l: u8) {
         if self.menu_cursor > 0 {
             self.menu_cursor -= 1;
@@ -193,6 +563,35 @@ impl App {
         self.selected_color = Some(color);
     }
 
+    /// Make sure the chess engine speaks UCI before playing against it, a popup waits for its answer
+    /// Without an engine path we look for an installed engine the player can pick
+    pub fn check_chess_engine(&mut self) {
+        match self.chess_engine_path.as_ref() {
+            Some(engine_path) if !engine_path.is_empty() => {
+                self.engine_check = Some(Bot::check_uci_engine(engine_path));
+                self.current_popup = Some(Popups::CheckingEngine);
+            }
+            _ => {
+                self.detected_engines = detect_engines();
+                self.current_popup = Some(Popups::EnginePathError);
+            }
+        }
+    }
+
+    /// Use the engine picked in the detected engines and save it in the configuration
+    pub fn select_detected_engine(&mut self) {
+        let Some(engine_path) = self.detected_engines.get(self.menu_cursor as usize) else {
+            return;
+        };
+
+        self.chess_engine_path = Some(engine_path.to_string_lossy().to_string());
+        self.detected_engines.clear();
+        self.menu_cursor = 0;
+        self.current_popup = None;
+        self.update_config();
+        self.check_chess_engine();
+    }
+
     pub fn bot_setup(&mut self) {
         let empty = "".to_string();
         let path = match self.chess_engine_path.as_ref() {
@@ -203,7 +602,11 @@ impl App {
         // if the selected Color is Black, we need to switch the Game
         if let Some(color) = self.selected_color {
             if color == PieceColor::Black {
-                self.game.bot = Some(Bot::new(path, true));
+                let mut bot = Bot::new(path, true);
+                bot.set_ponder(self.engine_ponder);
+                bot.set_options(&self.engine_options);
+                self.game.bot = Some(bot);
+                self.game.ui.white_perspective = self.bot_white_perspective;
 
                 self.game.execute_bot_move();
                 self.game.player_turn = PieceColor::Black;
@@ -220,11 +623,15 @@ impl App {
     pub fn restart(&mut self) {
         let bot = self.game.bot.clone();
         let opponent = self.game.opponent.clone();
+        let settings = Settings::of(&self.game);
         self.game = Game::default();
-
+        settings.apply_to(&mut self.game);
         self.game.bot = bot;
-        self.game.opponent = opponent;
+        if let Some(opponent) = opponent {
+            self.game.set_opponent(opponent);
+        }
         self.current_popup = None;
+        self.repetition_prompted_at = None;
 
         if self.game.bot.as_ref().is_some()
             && self
@@ -240,31 +647,150 @@ impl App {
 
     pub fn menu_select(&mut self) {
         match self.menu_cursor {
-            0 => self.current_page = Pages::Solo,
+            0 => {
+                self.current_page = Pages::Solo;
+                self.apply_page_display_mode();
+                self.game.ui.white_perspective = !self.solo_auto_flip;
+            }
             1 => {
                 self.menu_cursor = 0;
-                self.current_page = Pages::Multiplayer
+                self.current_page = Pages::Multiplayer;
+                self.apply_page_display_mode();
             }
             2 => {
                 self.menu_cursor = 0;
-                self.current_page = Pages::Bot
+                self.current_page = Pages::Bot;
+                self.apply_page_display_mode();
+                self.check_chess_engine();
             }
-            3 => {
+            3 => self.open_board_editor(),
+            4 => {
                 self.game.ui.display_mode = match self.game.ui.display_mode {
                     DisplayMode::ASCII => DisplayMode::DEFAULT,
                     DisplayMode::DEFAULT => DisplayMode::ASCII,
                 };
                 self.update_config();
             }
-            4 => self.toggle_help_popup(),
-            5 => self.current_page = Pages::Credit,
+            5 => self.toggle_help_popup(),
+            6 => self.current_page = Pages::Credit,
+            7 => self.resume_last_game(),
             _ => {}
         }
     }
 
+    /// Open the board editor on the starting position
+    pub fn open_board_editor(&mut self) {
+        self.current_page = Pages::BoardEditor;
+        self.board_editor = BoardEditor::default();
+        self.game.ui.reset();
+    }
+
+    /// Start a solo game from the position of the board editor
+    /// The position stays in the editor with the reason when it can't be played
+    pub fn start_board_editor_game(&mut self) {
+        if let Err(error) = self.board_editor.validate() {
+            self.board_editor.error = Some(error);
+            return;
+        }
+
+        self.start_position_game(self.board_editor.game_board());
+    }
+
+    /// Start a solo game from a set up position, as the board editor and the `--fen` argument do
+    /// The board has to be stored with the player to move at the bottom
+    pub fn start_position_game(&mut self, game_board: GameBoard) {
+        self.restart();
+        let settings = Settings::of(&self.game);
+        self.game.player_turn = if game_board.is_flipped {
+            PieceColor::Black
+        } else {
+            PieceColor::White
+        };
+        self.game.game_board = game_board;
+        settings.apply_to(&mut self.game);
+        if self.game.game_board.is_checkmate(self.game.player_turn) {
+            self.game.game_state = GameState::Checkmate;
+        } else if self.game.game_board.is_draw(self.game.player_turn) {
+            self.game.game_state = GameState::Draw;
+        }
+
+        self.current_page = Pages::Solo;
+        self.apply_page_display_mode();
+        self.game.ui.white_perspective = !self.solo_auto_flip;
+    }
+
+    /// Start a solo game again from the saved game, replaying its moves
+    /// The replay stops at the first move that can't be played, the moves before it are kept
+    pub fn resume_last_game(&mut self) {
+        let Some(saved_game) = self.last_game.take() else {
+            return;
+        };
+        let game_board = match GameBoard::from_fen(&saved_game.start_fen) {
+            Ok(game_board) => game_board,
+            Err(e) => {
+                log::warn!("The saved game can't be resumed: {e}");
+                return;
+            }
+        };
+
+        self.start_position_game(game_board);
+        for saved_move in &saved_game.moves {
+            if !self.game.play_saved_move(saved_move) {
+                log::warn!("The saved move {saved_move} can't be played, the replay stops there");
+                break;
+            }
+        }
+    }
+
+    /// Start a solo game from a Chess960 starting position, given by its number between 0 and 959
+    /// The king castles with both rooks, wherever they start on the first rank
+    pub fn start_chess960_game(&mut self, position: u16) {
+        let Some(board) = chess960_board(position) else {
+            log::warn!("{position} is not a Chess960 starting position, they go from 0 to 959");
+            return;
+        };
+        let board_editor = BoardEditor {
+            board,
+            ..Default::default()
+        };
+        let mut game_board = board_editor.game_board();
+        game_board.chess960 = true;
+        self.start_position_game(game_board);
+    }
+
+    /// Open the page set by the `startup_page` option as if it was picked in the menu
+    /// The pages that can't be opened leave the application on the home page with a popup telling why
+    pub fn open_startup_page(&mut self, startup_page: &str) {
+        match startup_page {
+            "home" => {}
+            "solo" => {
+                self.menu_cursor = 0;
+                self.menu_select();
+            }
+            // The engine checks show their own popups when the engine is missing
+            "bot" => {
+                self.menu_cursor = 2;
+                self.menu_select();
+            }
+            _ => {
+                log::warn!(
+                    "The {startup_page} startup page is not available, staying on the home page"
+                );
+                self.unavailable_startup_page = Some(startup_page.to_string());
+                self.current_popup = Some(Popups::StartupPageUnavailable);
+            }
+        }
+    }
+
+    /// Write the settings changed in the application to the configuration file, the failures are only logged
     pub fn update_config(&self) {
-        let home_dir = home_dir().expect("Could not get home directory");
-        let config_path = home_dir.join(".config/chess-tui/config.toml");
+        let config_path = match config_folder() {
+            Ok(folder_path) => folder_path.join("config.toml"),
+            Err(e) => {
+                log::error!("Failed to update the configuration: {e}");
+                return;
+            }
+        };
         let mut config = match fs::read_to_string(config_path.clone()) {
             Ok(content) => content
                 .parse::<Value>()
@@ -273,9 +799,23 @@ impl App {
         };
 
         if let Some(table) = config.as_table_mut() {
+            if let Some(engine_path) = &self.chess_engine_path {
+                table.insert(
+                    "engine_path".to_string(),
+                    Value::String(engine_path.clone()),
+                );
+            }
             table.insert(
                 "display_mode".to_string(),
-                Value::String(self.game.ui.display_mode.to_string()),
+                Value::String(self.global_display_mode().to_string()),
+            );
+            table.insert(
+                "show_captured".to_string(),
+                Value::Boolean(self.game.ui.show_captured),
+            );
+            table.insert(
+                "show_coordinates".to_string(),
+                Value::Boolean(self.game.ui.show_coordinates),
             );
             table.insert(
                 "log_level".to_string(),
@@ -283,8 +823,12 @@ impl App {
             );
         }
 
-        let mut file = File::create(config_path.clone()).unwrap();
-        file.write_all(config.to_string().as_bytes()).unwrap();
+        if let Err(e) = fs::write(&config_path, config.to_string()) {
+            log::error!(
+                "Failed to write the configuration to {}: {e}",
+                config_path.display()
+            );
+        }
     }
 
     pub fn reset(&mut self) {
@@ -0,0 +1,46 @@
+use std::{
+    io::{self, Write},
+    process::{Command, Stdio},
+};
+
+// Clipboard programs tried in order, the first one that works is used
+const CLIPBOARD_COMMANDS: [(&str, &[&str]); 5] = [
+    ("pbcopy", &[]),
+    ("wl-copy", &[]),
+    ("xclip", &["-selection", "clipboard"]),
+    ("xsel", &["--clipboard", "--input"]),
+    ("clip", &[]),
+];
+
+/// Copy a text to the system clipboard using the clipboard program available on the system
+pub fn copy_to_clipboard(text: &str) -> io::Result<()> {
+    for (program, args) in CLIPBOARD_COMMANDS {
+        let Ok(mut child) = Command::new(program)
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+        else {
+            continue;
+        };
+
+        // The stdin is closed before waiting for the program, which reads until its end
+        let written = match child.stdin.take() {
+            Some(mut stdin) => stdin.write_all(text.as_bytes()),
+            None => Ok(()),
+        };
+
+        // The program is waited for even when the text could not be written, not to leave a zombie process
+        let status = child.wait()?;
+        written?;
+        if status.success() {
+            return Ok(());
+        }
+    }
+
+    Err(io::Error::new(
+        io::ErrorKind::NotFound,
+        "No clipboard program found (pbcopy, wl-copy, xclip, xsel or clip)",
+    ))
+}
@@ -4,6 +4,18 @@ use std::path::PathBuf;
 use ratatui::style::Color;
 
 pub const UNDEFINED_POSITION: u8 = u8::MAX;
+/// Number of ticks for a piece to slide to its destination
+pub const MOVE_ANIMATION_FRAMES: u8 = 6;
+/// Number of ticks for a flipped board to be drawn in its new orientation, one row per tick
+pub const FLIP_ANIMATION_FRAMES: u8 = 8;
+/// Tick rate in ms used when the moves are animated
+pub const MOVE_ANIMATION_TICK_RATE: u64 = 40;
+/// Number of random moves played by the demo shortcut, changed with the `demo_random_moves` option
+pub const DEMO_RANDOM_MOVES: usize = 20;
+/// Port the multiplayer games are hosted on, changed with `--port` or the `port` option
+pub const NETWORK_PORT: u16 = 2308;
+/// Lowest port a game can be hosted on, the ones below are reserved to the system services
+pub const MIN_NETWORK_PORT: u16 = 1024;
 pub const WHITE: Color = Color::Rgb(160, 160, 160);
 pub const BLACK: Color = Color::Rgb(128, 95, 69);
 
@@ -31,6 +43,40 @@ impl fmt::Display for DisplayMode {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HistoryNotation {
+    // piece glyph and the cells of the move (e.g "♘ g1-f3")
+    Coordinate,
+    // standard algebraic notation (e.g "Nf3")
+    San,
+}
+
+impl fmt::Display for HistoryNotation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            HistoryNotation::Coordinate => write!(f, "coordinate"),
+            HistoryNotation::San => write!(f, "san"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CoordinatesDisplay {
+    // every file and rank label
+    Always,
+    // only the file and rank of the cursor cell, highlighted
+    Hover,
+}
+
+impl fmt::Display for CoordinatesDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            CoordinatesDisplay::Always => write!(f, "always"),
+            CoordinatesDisplay::Hover => write!(f, "hover"),
+        }
+    }
+}
+
 pub fn home_dir() -> Result<PathBuf, &'static str> {
     match dirs::home_dir() {
         Some(dir) => Ok(dir),
@@ -38,17 +84,23 @@ pub fn home_dir() -> Result<PathBuf, &'static str> {
     }
 }
 
+/// The folder of the configuration file, where the saved games and boards are written too
+pub fn config_folder() -> Result<PathBuf, &'static str> {
+    Ok(home_dir()?.join(".config/chess-tui"))
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub enum Pages {
     Home,
     Solo,
     Multiplayer,
     Bot,
+    BoardEditor,
     Credit,
 }
 impl Pages {
     pub fn variant_count() -> usize {
-        6
+        7
     }
 }
 
@@ -59,5 +111,13 @@ pub enum Popups {
     EnterHostIP,
     WaitingForOpponentToJoin,
     EnginePathError,
+    CheckingEngine,
+    EngineNotUci,
+    ExportedBoard,
+    StartupPageUnavailable,
+    DrawOffer,
+    ClaimDraw,
+    Reconnecting,
+    ConnectionLost,
     Help,
 }
@@ -0,0 +1,78 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use chrono::Local;
+
+use crate::{
+    game_logic::{coord::Coord, game_board::GameBoard},
+    pieces::{PieceColor, PieceType},
+    utils::col_to_letter,
+};
+
+/// Unicode chess symbols, white pieces are the outlined ones as in most fonts
+fn piece_to_unicode(piece_type: PieceType, piece_color: PieceColor) -> char {
+    match (piece_type, piece_color) {
+        (PieceType::King, PieceColor::White) => '♔',
+        (PieceType::Queen, PieceColor::White) => '♕',
+        (PieceType::Rook, PieceColor::White) => '♖',
+        (PieceType::Bishop, PieceColor::White) => '♗',
+        (PieceType::Knight, PieceColor::White) => '♘',
+        (PieceType::Pawn, PieceColor::White) => '♙',
+        (PieceType::King, PieceColor::Black) => '♚',
+        (PieceType::Queen, PieceColor::Black) => '♛',
+        (PieceType::Rook, PieceColor::Black) => '♜',
+        (PieceType::Bishop, PieceColor::Black) => '♝',
+        (PieceType::Knight, PieceColor::Black) => '♞',
+        (PieceType::Pawn, PieceColor::Black) => '♟',
+    }
+}
+
+/// Human readable board with a border and the coordinates, to be pasted in a chat
+/// `flipped` draws the board from black's point of view, whatever the way the board is stored
+pub fn board_to_unicode_block(game_board: &GameBoard, flipped: bool) -> String {
+    // The stored board is upside down compared to the wanted one
+    let is_rotated = flipped != game_board.is_flipped;
+
+    let mut block = String::from("  ┌─────────────────┐\n");
+    for row in 0..8u8 {
+        let rank = if flipped { row + 1 } else { 8 - row };
+        block.push_str(&format!("{rank} │"));
+
+        for col in 0..8u8 {
+            let coord = if is_rotated {
+                Coord::new(7 - row, 7 - col)
+            } else {
+                Coord::new(row, col)
+            };
+            let symbol = match game_board.board[&coord] {
+                Some((piece_type, piece_color)) => piece_to_unicode(piece_type, piece_color),
+                None => '·',
+            };
+            block.push(' ');
+            block.push(symbol);
+        }
+        block.push_str(" │\n");
+    }
+    block.push_str("  └─────────────────┘\n   ");
+
+    for col in 0..8u8 {
+        let file = if flipped { 7 - col } else { col };
+        block.push(' ');
+        block.push_str(&col_to_letter(file));
+    }
+
+    block
+}
+
+/// Write a board diagram in a new file of `folder_path`, named after the current time
+/// Returns the path of the written file
+pub fn save_board_diagram(folder_path: &Path, diagram: &str) -> io::Result<PathBuf> {
+    let path = folder_path.join(format!(
+        "board-{}.txt",
+        Local::now().format("%Y-%m-%d_%H-%M-%S")
+    ));
+    fs::write(&path, diagram)?;
+    Ok(path)
+}
@@ -1,5 +1,6 @@
 use super::coord::Coord;
 use crate::pieces::{PieceColor, PieceType};
+use std::hash::{BuildHasher, RandomState};
 
 pub type Board = [[Option<(PieceType, PieceColor)>; 8]; 8];
 
@@ -65,3 +66,71 @@ pub fn init_board() -> Board {
         ],
     ]
 }
+
+/// Number of the usual starting position among the Chess960 ones
+pub const CHESS960_STANDARD_POSITION: u16 = 518;
+
+/// The cells of the two knights among the five cells left once the bishops and the queen are placed
+const CHESS960_KNIGHTS: [(usize, usize); 10] = [
+    (0, 1),
+    (0, 2),
+    (0, 3),
+    (0, 4),
+    (1, 2),
+    (1, 3),
+    (1, 4),
+    (2, 3),
+    (2, 4),
+    (3, 4),
+];
+
+/// The starting position of a Chess960 game from its number between 0 and 959, in Scharnagl's numbering
+/// The bishops stand on cells of opposite colors and the king between the rooks, the black pieces mirror the white ones
+pub fn chess960_board(position: u16) -> Option<Board> {
+    if position >= 960 {
+        return None;
+    }
+    let mut back_rank: [Option<PieceType>; 8] = [None; 8];
+    let mut number = position as usize;
+
+    // The light cells bishop on b, d, f or h and the dark cells one on a, c, e or g
+    back_rank[2 * (number % 4) + 1] = Some(PieceType::Bishop);
+    number /= 4;
+    back_rank[2 * (number % 4)] = Some(PieceType::Bishop);
+    number /= 4;
+
+    // The other pieces go on the cells still empty, counted from the a file
+    let mut place = |empty_index: usize, piece_type: PieceType| {
+        if let Some(cell) = back_rank
+            .iter_mut()
+            .filter(|cell| cell.is_none())
+            .nth(empty_index)
+        {
+            *cell = Some(piece_type);
+        }
+    };
+    place(number % 6, PieceType::Queen);
+    number /= 6;
+    // The second knight first so the first one's cell is still counted the same way
+    let (first_knight, second_knight) = CHESS960_KNIGHTS[number];
+    place(second_knight, PieceType::Knight);
+    place(first_knight, PieceType::Knight);
+    for piece_type in [PieceType::Rook, PieceType::King, PieceType::Rook] {
+        place(0, piece_type);
+    }
+
+    let mut board: Board = [[None; 8]; 8];
+    for (col, piece_type) in back_rank.iter().enumerate() {
+        let piece_type = (*piece_type)?;
+        board[0][col] = Some((piece_type, PieceColor::Black));
+        board[1][col] = Some((PieceType::Pawn, PieceColor::Black));
+        board[6][col] = Some((PieceType::Pawn, PieceColor::White));
+        board[7][col] = Some((piece_type, PieceColor::White));
+    }
+    Some(board)
+}
+
+/// A Chess960 starting position number picked at random
+pub fn random_chess960_position() -> u16 {
+    (RandomState::new().hash_one(0u8) % 960) as u16
+}
@@ -0,0 +1,266 @@
+use super::{
+    board::{init_board, Board},
+    coord::Coord,
+    game_board::GameBoard,
+};
+use crate::{
+    pieces::{PieceColor, PieceType},
+    utils::invert_position,
+};
+
+/// Order in which a cell goes through the pieces, the white ones first then the black ones
+const PIECE_CYCLE: [PieceType; 6] = [
+    PieceType::Pawn,
+    PieceType::Knight,
+    PieceType::Bishop,
+    PieceType::Rook,
+    PieceType::Queen,
+    PieceType::King,
+];
+
+/// The castlings in the FEN order (KQkq): the letter, the player, its first rank with the white pieces at the bottom and the side
+/// The king side is toward the h file, the rook can start on any file of its side as in Chess960
+pub const CASTLING_SIDES: [(char, PieceColor, usize, bool); 4] = [
+    ('K', PieceColor::White, 7, true),
+    ('Q', PieceColor::White, 7, false),
+    ('k', PieceColor::Black, 0, true),
+    ('q', PieceColor::Black, 0, false),
+];
+
+/// The moved piece entry of a rook of the editor board, with its owner at the bottom as the game board keeps them
+/// The black pieces are seen on the flipped board
+pub(crate) fn moved_rook(
+    color: PieceColor,
+    row: usize,
+    col: usize,
+) -> (PieceType, PieceColor, Coord) {
+    let cell = Coord::new(row as u8, col as u8);
+    let cell = match color {
+        PieceColor::White => cell,
+        PieceColor::Black => invert_position(&cell),
+    };
+    (PieceType::Rook, color, cell)
+}
+
+/// A position being set up by the player before starting a game from it
+/// The board is stored with the white pieces at the bottom
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoardEditor {
+    pub board: Board,
+    /// The player who moves first once the game starts
+    pub player_turn: PieceColor,
+    /// Castling rights in the FEN order: white king side, white queen side, black king side, black queen side
+    pub castling_rights: [bool; 4],
+    /// Why the position couldn't be played, shown until the next edit
+    pub error: Option<String>,
+}
+
+impl Default for BoardEditor {
+    fn default() -> Self {
+        Self {
+            board: init_board(),
+            player_turn: PieceColor::White,
+            castling_rights: [true; 4],
+            error: None,
+        }
+    }
+}
+
+impl BoardEditor {
+    /// Put the next piece of the cycle on a cell: empty, the white pieces, the black pieces, then empty again
+    pub fn cycle_piece(&mut self, coord: &Coord) {
+        let next_piece = match self.board[coord] {
+            None => Some((PIECE_CYCLE[0], PieceColor::White)),
+            Some((piece_type, color)) => {
+                let index = PIECE_CYCLE
+                    .iter()
+                    .position(|&cycle_piece| cycle_piece == piece_type)
+                    .unwrap_or(0);
+                match (PIECE_CYCLE.get(index + 1), color) {
+                    (Some(&next_type), _) => Some((next_type, color)),
+                    (None, PieceColor::White) => Some((PIECE_CYCLE[0], PieceColor::Black)),
+                    (None, PieceColor::Black) => None,
+                }
+            }
+        };
+        self.board[coord] = next_piece;
+        self.error = None;
+    }
+
+    pub fn clear_cell(&mut self, coord: &Coord) {
+        self.board[coord] = None;
+        self.error = None;
+    }
+
+    /// Put the pieces back on their starting cells, with every castling right
+    pub fn set_starting_position(&mut self) {
+        self.board = init_board();
+        self.castling_rights = [true; 4];
+        self.error = None;
+    }
+
+    /// Remove every piece to start from an empty board
+    pub fn clear_board(&mut self) {
+        self.board = [[None; 8]; 8];
+        self.error = None;
+    }
+
+    pub fn toggle_player_turn(&mut self) {
+        self.player_turn = self.player_turn.opposite();
+        self.error = None;
+    }
+
+    /// Toggle one of the castling rights, `index` follows the FEN order (KQkq)
+    pub fn toggle_castling_right(&mut self, index: usize) {
+        if let Some(castling_right) = self.castling_rights.get_mut(index) {
+            *castling_right = !*castling_right;
+            self.error = None;
+        }
+    }
+
+    /// The castling rights as written in a FEN
+    pub fn castling_fen(&self) -> String {
+        let castling: String = "KQkq"
+            .chars()
+            .zip(self.castling_rights)
+            .filter(|&(_, castling_right)| castling_right)
+            .map(|(letter, _)| letter)
+            .collect();
+        if castling.is_empty() {
+            "-".to_string()
+        } else {
+            castling
+        }
+    }
+
+    /// The position in the Forsyth-Edwards Notation
+    pub fn fen(&self) -> String {
+        let rows: Vec<String> = self
+            .board
+            .iter()
+            .map(|row| {
+                let mut fen_row = String::new();
+                let mut empty_cells = 0;
+                for cell in row {
+                    match cell {
+                        Some((piece_type, color)) => {
+                            if empty_cells > 0 {
+                                fen_row.push_str(&empty_cells.to_string());
+                                empty_cells = 0;
+                            }
+                            fen_row.push_str(PieceType::piece_to_fen_enum(
+                                Some(*piece_type),
+                                Some(*color),
+                            ));
+                        }
+                        None => empty_cells += 1,
+                    }
+                }
+                if empty_cells > 0 {
+                    fen_row.push_str(&empty_cells.to_string());
+                }
+                fen_row
+            })
+            .collect();
+
+        let player_turn = match self.player_turn {
+            PieceColor::White => "w",
+            PieceColor::Black => "b",
+        };
+        format!(
+            "{} {player_turn} {} - 0 1",
+            rows.join("/"),
+            self.castling_fen()
+        )
+    }
+
+    /// Check that a game can be played from the position
+    pub fn validate(&self) -> Result<(), String> {
+        for color in [PieceColor::White, PieceColor::Black] {
+            let kings = self
+                .board
+                .iter()
+                .flatten()
+                .filter(|&&cell| cell == Some((PieceType::King, color)))
+                .count();
+            if kings != 1 {
+                return Err(format!("{color:?} needs exactly one king"));
+            }
+        }
+
+        let pawn_on_last_rows = [0, 7].iter().any(|&row| {
+            self.board[row]
+                .iter()
+                .any(|cell| matches!(cell, Some((PieceType::Pawn, _))))
+        });
+        if pawn_on_last_rows {
+            return Err("Pawns can't stand on the first or the last rank".to_string());
+        }
+
+        // The check is computed with the checked player at the bottom, as during a game
+        let waiting_player = self.player_turn.opposite();
+        let mut game_board = GameBoard::new(self.board, vec![], vec![self.board]);
+        game_board.orient_for(waiting_player);
+        if game_board.is_getting_checked(game_board.board, waiting_player) {
+            return Err(format!(
+                "{waiting_player:?} is in check but it is {:?}'s turn",
+                self.player_turn
+            ));
+        }
+
+        for (&(letter, color, row, is_king_side), castling_right) in
+            CASTLING_SIDES.iter().zip(self.castling_rights)
+        {
+            if castling_right && self.side_rooks(color, row, is_king_side).is_empty() {
+                return Err(format!(
+                    "{letter} castling needs the king on its first rank and a rook on its side"
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The board of a game starting from the position, the castling rights not kept are seen as moved rooks
+    /// The board is stored with the player to move at the bottom, as in a solo game
+    pub fn game_board(&self) -> GameBoard {
+        let mut game_board = GameBoard::new(self.board, vec![], vec![self.board]);
+
+        // The corner and the rooks of each side without castling
+        for (&(_, color, row, is_king_side), castling_right) in
+            CASTLING_SIDES.iter().zip(self.castling_rights)
+        {
+            if castling_right {
+                continue;
+            }
+            let corner = if is_king_side { 7 } else { 0 };
+            let mut cols = self.side_rooks(color, row, is_king_side);
+            if !cols.contains(&corner) {
+                cols.push(corner);
+            }
+            for col in cols {
+                game_board.moved_pieces.push(moved_rook(color, row, col));
+            }
+        }
+
+        game_board.orient_for(self.player_turn);
+        game_board.board_history = vec![game_board.board];
+        game_board.board_history_flipped = vec![game_board.is_flipped];
+        game_board.first_player = self.player_turn;
+        game_board
+    }
+
+    /// The columns of the rooks of `color` on one side of its king, when the king stands on `row`
+    pub fn side_rooks(&self, color: PieceColor, row: usize, is_king_side: bool) -> Vec<usize> {
+        let Some(king_col) = self.board[row]
+            .iter()
+            .position(|&cell| cell == Some((PieceType::King, color)))
+        else {
+            return vec![];
+        };
+        (0..8)
+            .filter(|&col| col != king_col && (col > king_col) == is_king_side)
+            .filter(|&col| self.board[row][col] == Some((PieceType::Rook, color)))
+            .collect()
+    }
+}
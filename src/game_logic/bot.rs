@@ -1,15 +1,239 @@
+use std::{
+    env,
+    ffi::OsStr,
+    io::{self, BufRead, BufReader, Write},
+    ops::RangeInclusive,
+    path::PathBuf,
+    process::{Child, ChildStdin, Command, Stdio},
+    sync::{mpsc, Arc, Mutex},
+    thread,
+    time::{Duration, Instant},
+};
+use toml::Value;
 use uci::Engine;
 
+use super::board::Board;
 use crate::utils::convert_notation_into_position;
 
+/// Think time of the engine for each move, the same as the uci crate default
+const DEFAULT_MOVETIME_MS: u32 = 100;
+
+/// Accepted values of the engine options, the limits of Stockfish for the UCI ones
+const THREADS_RANGE: RangeInclusive<u32> = 1..=1024;
+const HASH_MB_RANGE: RangeInclusive<u32> = 1..=33_554_432;
+const MOVETIME_MS_RANGE: RangeInclusive<u32> = 10..=600_000;
+
+/// How long we wait for the engine to answer "uciok", some engines load big files on startup
+const UCI_HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Binary names of the usual chess engines, looked for in the PATH
+const ENGINE_NAMES: [&str; 3] = ["stockfish", "lc0", "komodo"];
+
+/// Look for the usual chess engines in the PATH, the first binary found for each name is kept
+pub fn detect_engines() -> Vec<PathBuf> {
+    match env::var_os("PATH") {
+        Some(path) => detect_engines_in(&path),
+        None => vec![],
+    }
+}
+
+/// Look for the usual chess engines in the directories of a PATH like value
+pub fn detect_engines_in(path: &OsStr) -> Vec<PathBuf> {
+    let directories: Vec<PathBuf> = env::split_paths(path).collect();
+
+    ENGINE_NAMES
+        .iter()
+        .filter_map(|name| {
+            let file_name = format!("{name}{}", env::consts::EXE_SUFFIX);
+            directories
+                .iter()
+                .map(|directory| directory.join(&file_name))
+                .find(|engine_path| engine_path.is_file())
+        })
+        .collect()
+}
+
+/// Search options of the engine set in the configuration file, the engine defaults are kept for the ones unset
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct EngineOptions {
+    /// The UCI `Threads` option
+    pub threads: Option<u32>,
+    /// The UCI `Hash` option, the size of the hash table in megabytes
+    pub hash_mb: Option<u32>,
+    /// Think time of each move in milliseconds
+    pub movetime_ms: Option<u32>,
+}
+
+impl EngineOptions {
+    /// Read `engine_threads`, `engine_hash_mb` and `engine_movetime_ms`, the values out of range are logged and ignored
+    pub fn from_config(config: &Value) -> Self {
+        Self {
+            threads: option_in_range(config, "engine_threads", THREADS_RANGE),
+            hash_mb: option_in_range(config, "engine_hash_mb", HASH_MB_RANGE),
+            movetime_ms: option_in_range(config, "engine_movetime_ms", MOVETIME_MS_RANGE),
+        }
+    }
+}
+
+fn option_in_range(config: &Value, key: &str, range: RangeInclusive<u32>) -> Option<u32> {
+    let value = config.get(key)?;
+    match value
+        .as_integer()
+        .and_then(|integer| u32::try_from(integer).ok())
+    {
+        Some(integer) if range.contains(&integer) => Some(integer),
+        _ => {
+            log::warn!(
+                "{key} has to be between {} and {}, {value} is ignored",
+                range.start(),
+                range.end()
+            );
+            None
+        }
+    }
+}
+
+/// A game where two engines play each other, the engine to move is the bot of the game and the other one waits here
+pub struct BotDemo {
+    pub waiting_bot: Bot,
+    /// Pause between two moves so the game can be followed
+    pub delay: Duration,
+    pub last_move_at: Instant,
+}
+
+/// A chess engine process we talk to ourselves, for the pondering commands the uci crate doesn't send
+/// (`go ponder`, `ponderhit` and `stop`) and the expected reply it drops from the best move
+struct PonderEngine {
+    child: Child,
+    stdin: ChildStdin,
+    /// The engine output, read by a thread so a long pondering can't fill the pipe
+    lines: mpsc::Receiver<String>,
+}
+
+impl PonderEngine {
+    /// Start the engine with its `Ponder` option set, once it answered the handshake
+    fn start(engine_path: &str) -> io::Result<PonderEngine> {
+        let mut child = Command::new(engine_path)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()?;
+        let (Some(stdin), Some(stdout)) = (child.stdin.take(), child.stdout.take()) else {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err(io::Error::other(
+                "The engine has no standard input or output",
+            ));
+        };
+
+        let (lines_tx, lines) = mpsc::channel();
+        thread::spawn(move || {
+            for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+                if lines_tx.send(line).is_err() {
+                    return;
+                }
+            }
+        });
+
+        let mut engine = PonderEngine {
+            child,
+            stdin,
+            lines,
+        };
+        engine.write("uci")?;
+        engine.read_until("uciok", Some(UCI_HANDSHAKE_TIMEOUT))?;
+        engine.write("setoption name Ponder value true")?;
+        engine.write("isready")?;
+        engine.read_until("readyok", Some(UCI_HANDSHAKE_TIMEOUT))?;
+        Ok(engine)
+    }
+
+    fn write(&mut self, command: &str) -> io::Result<()> {
+        log::debug!("Sending to the engine: {command}");
+        writeln!(self.stdin, "{command}")?;
+        self.stdin.flush()
+    }
+
+    // Read the engine output until a line starting with `prefix`, which is returned
+    fn read_until(&self, prefix: &str, timeout: Option<Duration>) -> io::Result<String> {
+        loop {
+            let line = match timeout {
+                Some(timeout) => self.lines.recv_timeout(timeout).map_err(|_| {
+                    io::Error::new(
+                        io::ErrorKind::TimedOut,
+                        format!("The engine didn't answer {prefix}"),
+                    )
+                })?,
+                None => self.lines.recv().map_err(|_| {
+                    io::Error::new(
+                        io::ErrorKind::UnexpectedEof,
+                        format!("The engine stopped before answering {prefix}"),
+                    )
+                })?,
+            };
+            if line.starts_with(prefix) {
+                return Ok(line);
+            }
+        }
+    }
+
+    fn send(&mut self, command: &str) {
+        if let Err(e) = self.write(command) {
+            panic!("Failed to send {command} to the engine: {:?}", e);
+        }
+    }
+
+    /// Start a search from a position, the result is read with `read_bestmove`
+    fn search(&mut self, position: &str, go_mode: &str) {
+        self.send(&format!("position fen {position}"));
+        self.send(&format!("go {go_mode}"));
+    }
+
+    /// Read the engine output until the best move, with the reply it expects if it gives one
+    fn read_bestmove(&self) -> (String, Option<String>) {
+        let line = match self.read_until("bestmove", None) {
+            Ok(line) => line,
+            Err(e) => panic!("Failed to read the engine move: {:?}", e),
+        };
+
+        let mut words = line.split_whitespace().skip(1);
+        let movement = words.next().unwrap_or_default().to_string();
+        let ponder_move = match (words.next(), words.next()) {
+            (Some("ponder"), Some(ponder_move)) => Some(ponder_move.to_string()),
+            _ => None,
+        };
+        (movement, ponder_move)
+    }
+}
+
+impl Drop for PonderEngine {
+    fn drop(&mut self) {
+        let _ = self.write("quit");
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
 #[derive(Clone)]
 pub struct Bot {
     // the chess engine
     pub engine: Engine,
+    /// Path of the chess engine, the pondering runs its own process of it
+    engine_path: String,
     /// Used to indicate if a bot move is following
     pub bot_will_move: bool,
     // if the bot is starting, meaning the player is black
     pub is_bot_starting: bool,
+    /// The engine thinking on the player's time, `None` when the pondering is off
+    ponder_engine: Option<Arc<Mutex<PonderEngine>>>,
+    /// The reply the engine expects from the player, given with its latest move
+    pub ponder_move: Option<String>,
+    /// The board the engine is pondering on, the position after the expected reply
+    pub pondered_board: Option<Board>,
+    /// Think time of the engine for each move
+    pub movetime_ms: u32,
+    /// The engine plays with the Chess960 castlings, the king then castles by taking its own rook
+    chess960: bool,
 }
 
 // Custom Default implementation
@@ -17,8 +241,14 @@ impl Default for Bot {
     fn default() -> Self {
         Bot {
             engine: Engine::new("path_to_engine").expect("Failed to load engine"), // Specify the default engine path
+            engine_path: "path_to_engine".to_string(),
             bot_will_move: false,
             is_bot_starting: false,
+            ponder_engine: None,
+            ponder_move: None,
+            pondered_board: None,
+            movetime_ms: DEFAULT_MOVETIME_MS,
+            chess960: false,
         }
     }
 }
@@ -29,14 +259,24 @@ impl Bot {
 
         Self {
             engine,
+            engine_path: engine_path.to_string(),
             bot_will_move: false,
             is_bot_starting,
+            ponder_engine: None,
+            ponder_move: None,
+            pondered_board: None,
+            movetime_ms: DEFAULT_MOVETIME_MS,
+            chess960: false,
         }
     }
 
     /// Allows you so set a
     pub fn set_engine(&mut self, engine_path: &str) {
-        self.engine = Bot::create_engine(engine_path)
+        self.engine = Bot::create_engine(engine_path);
+        self.engine_path = engine_path.to_string();
+        if self.ponder_engine.is_some() {
+            self.set_ponder(true);
+        }
     }
 
     pub fn create_engine(engine_path: &str) -> Engine {
@@ -50,15 +290,171 @@ impl Bot {
             }
         }
     }
+    /// Run `is_uci_engine` on its own thread, the interface keeps running while a slow engine starts
+    pub fn check_uci_engine(engine_path: &str) -> mpsc::Receiver<bool> {
+        let (is_uci_tx, is_uci_rx) = mpsc::channel();
+        let engine_path = engine_path.to_string();
+        thread::spawn(move || {
+            let _ = is_uci_tx.send(Bot::is_uci_engine(&engine_path));
+        });
+        is_uci_rx
+    }
+
+    /// Check that the binary speaks UCI: it has to answer "uciok" to "uci" before the timeout
+    pub fn is_uci_engine(engine_path: &str) -> bool {
+        let Ok(mut child) = Command::new(engine_path)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+        else {
+            log::error!("Failed to start the engine at {}", engine_path);
+            return false;
+        };
+
+        let (Some(mut stdin), Some(stdout)) = (child.stdin.take(), child.stdout.take()) else {
+            let _ = child.kill();
+            return false;
+        };
+
+        // The engine output is read in a thread so a silent binary can't block us
+        let (uciok_tx, uciok_rx) = mpsc::channel();
+        thread::spawn(move || {
+            for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+                if line.trim() == "uciok" {
+                    let _ = uciok_tx.send(());
+                    return;
+                }
+            }
+        });
+
+        let is_uci = stdin.write_all(b"uci\n").is_ok()
+            && uciok_rx.recv_timeout(UCI_HANDSHAKE_TIMEOUT).is_ok();
+        if !is_uci {
+            log::error!(
+                "{} didn't answer uciok, it is not a UCI engine",
+                engine_path
+            );
+        }
+
+        let _ = stdin.write_all(b"quit\n");
+        let _ = child.kill();
+        let _ = child.wait();
+
+        is_uci
+    }
+
+    /// Enable or disable the pondering, the moves are then searched by a process of the engine we drive ourselves
+    /// The pondering stays off when that process doesn't start
+    pub fn set_ponder(&mut self, ponder: bool) {
+        self.pondered_board = None;
+        self.ponder_move = None;
+        self.ponder_engine = None;
+        if !ponder {
+            return;
+        }
+        match PonderEngine::start(&self.engine_path) {
+            Ok(ponder_engine) => self.ponder_engine = Some(Arc::new(Mutex::new(ponder_engine))),
+            Err(e) => {
+                log::error!("Failed to start the engine to ponder, the pondering is off: {e}")
+            }
+        }
+    }
+
+    /// Send the configured options to the engine, the ones it doesn't support are logged and skipped
+    pub fn set_options(&mut self, options: &EngineOptions) {
+        for (name, value) in [("Threads", options.threads), ("Hash", options.hash_mb)] {
+            let Some(value) = value else {
+                continue;
+            };
+            match self.engine.set_option(name, &value.to_string()) {
+                Ok(()) => log::info!("Engine option {name} set to {value}"),
+                Err(e) => log::warn!("The engine doesn't support the {name} option: {:?}", e),
+            }
+            if let Some(ponder_engine) = &self.ponder_engine {
+                let mut ponder_engine =
+                    ponder_engine.lock().expect("The ponder engine lock failed");
+                if let Err(e) = ponder_engine.write(&format!("setoption name {name} value {value}"))
+                {
+                    log::warn!("Failed to set the {name} option of the ponder engine: {e}");
+                }
+            }
+        }
+
+        if let Some(movetime_ms) = options.movetime_ms {
+            self.movetime_ms = movetime_ms;
+            self.engine = self.engine.clone().movetime(movetime_ms);
+            log::info!("The engine thinks {movetime_ms} ms on each move");
+        }
+    }
+
+    /// Set the `UCI_Chess960` option of the engine when the game starts from a Chess960 position
+    pub fn set_chess960(&mut self, chess960: bool) {
+        if self.chess960 == chess960 {
+            return;
+        }
+        self.chess960 = chess960;
+        if let Err(e) = self
+            .engine
+            .set_option("UCI_Chess960", &chess960.to_string())
+        {
+            log::warn!(
+                "The engine doesn't support the UCI_Chess960 option: {:?}",
+                e
+            );
+        }
+        if let Some(ponder_engine) = &self.ponder_engine {
+            let mut ponder_engine = ponder_engine.lock().expect("The ponder engine lock failed");
+            if let Err(e) =
+                ponder_engine.write(&format!("setoption name UCI_Chess960 value {chess960}"))
+            {
+                log::warn!("Failed to set the UCI_Chess960 option of the ponder engine: {e}");
+            }
+        }
+    }
+
     /* Method to make a move for the bot
        We use the UCI protocol to communicate with the chess engine
     */
-    pub fn get_bot_move(&mut self, fen_position: String) -> String {
-        self.engine.set_position(&(fen_position as String)).unwrap();
-        let best_move = self.engine.bestmove();
-        let Ok(movement) = best_move else {
-            panic!("An error has occured")
+    pub fn get_bot_move(&mut self, fen_position: String, board: &Board) -> String {
+        let Some(ponder_engine) = self.ponder_engine.clone() else {
+            self.engine.set_position(&(fen_position as String)).unwrap();
+            let best_move = self.engine.bestmove();
+            let Ok(movement) = best_move else {
+                panic!("An error has occured")
+            };
+
+            return convert_notation_into_position(&movement);
         };
+        let mut ponder_engine = ponder_engine.lock().expect("The ponder engine lock failed");
+        let movetime = format!("movetime {}", self.movetime_ms);
+
+        match self.pondered_board.take() {
+            // The player played the expected move, the search started on the player's time goes on for the usual think time
+            Some(pondered_board) if pondered_board == *board => {
+                ponder_engine.send("ponderhit");
+                thread::sleep(Duration::from_millis(self.movetime_ms.into()));
+                ponder_engine.send("stop");
+            }
+            // The player played another move, we stop the pondering and search again
+            Some(_) => {
+                ponder_engine.send("stop");
+                ponder_engine.read_bestmove();
+                ponder_engine.search(&fen_position, &movetime);
+            }
+            None => ponder_engine.search(&fen_position, &movetime),
+        }
+
+        let (movement, ponder_move) = ponder_engine.read_bestmove();
+
+        // We start thinking on the expected reply while the player is thinking, until the player moves
+        if let Some(ponder_move) = &ponder_move {
+            ponder_engine.search(
+                &format!("{fen_position} moves {movement} {ponder_move}"),
+                "ponder infinite",
+            );
+        }
+        self.ponder_move = ponder_move;
 
         convert_notation_into_position(&movement)
     }
@@ -37,4 +37,12 @@ impl Coord {
     pub fn is_valid(&self) -> bool {
         (0..8).contains(&self.col) && (0..8).contains(&self.row)
     }
+    /// the same square once the board is turned upside down, an undefined position stays undefined
+    pub fn flipped(&self) -> Self {
+        if self.is_valid() {
+            Coord::new(7 - self.row, 7 - self.col)
+        } else {
+            *self
+        }
+    }
 }
@@ -0,0 +1,189 @@
+use std::{
+    io::{BufRead, BufReader, Write},
+    process::{Child, ChildStdin, Command, Stdio},
+    sync::mpsc,
+    thread,
+};
+
+use crate::pieces::PieceColor;
+
+/// Depth of the searches run to evaluate the positions, deep enough to be meaningful and short for most engines
+const EVALUATION_DEPTH: u8 = 16;
+
+/// Score of a position from white's side, as given by the engine
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Evaluation {
+    /// Advantage of white in hundredths of a pawn, negative when black is better
+    Centipawns(i32),
+    /// Moves until mate, negative when black mates
+    Mate(i32),
+}
+
+impl Evaluation {
+    /// Read the score of an `info` line of the engine, given from the side to move as in the UCI protocol
+    pub fn from_info_line(line: &str, side_to_move: PieceColor) -> Option<Evaluation> {
+        let mut words = line.split_whitespace();
+        if words.next() != Some("info") {
+            return None;
+        }
+        let mut words = words.skip_while(|&word| word != "score").skip(1);
+        let kind = words.next()?;
+        let value: i32 = words.next()?.parse().ok()?;
+        let value = match side_to_move {
+            PieceColor::White => value,
+            PieceColor::Black => -value,
+        };
+        match kind {
+            "cp" => Some(Evaluation::Centipawns(value)),
+            "mate" => Some(Evaluation::Mate(value)),
+            _ => None,
+        }
+    }
+
+    /// The score as written next to the bar: `+0.35`, `-1.20`, `#3` or `#-3`
+    pub fn label(&self) -> String {
+        match *self {
+            Evaluation::Centipawns(centipawns) => format!("{:+.2}", centipawns as f64 / 100.0),
+            Evaluation::Mate(moves) => format!("#{moves}"),
+        }
+    }
+
+    /// Part of the bar filled by white, between 0 and 1
+    /// The centipawns go through the winning chances curve so a few pawns already fill most of the bar
+    pub fn white_share(&self) -> f64 {
+        match *self {
+            Evaluation::Centipawns(centipawns) => {
+                let winning_chances = 2.0 / (1.0 + (-0.00368208 * centipawns as f64).exp()) - 1.0;
+                (1.0 + winning_chances) / 2.0
+            }
+            Evaluation::Mate(moves) if moves > 0 => 1.0,
+            Evaluation::Mate(moves) if moves < 0 => 0.0,
+            // Mate on the board, the side to move is the one mated
+            Evaluation::Mate(_) => 0.5,
+        }
+    }
+}
+
+// What the engine output tells the evaluator
+enum EngineLine {
+    Score(String),
+    BestMove,
+}
+
+/// A second engine process evaluating the positions of a game while it is played
+/// The searches run in the engine and its output is read in a thread, the interface only collects the scores
+pub struct Evaluator {
+    child: Child,
+    stdin: ChildStdin,
+    lines: mpsc::Receiver<EngineLine>,
+    searches_started: usize,
+    searches_done: usize,
+    side_to_move: PieceColor,
+    evaluated_fen: Option<String>,
+    /// The latest score of the position evaluated, kept until the next one arrives
+    pub evaluation: Option<Evaluation>,
+}
+
+impl Evaluator {
+    /// Start the engine, `None` when it can't be started
+    pub fn new(engine_path: &str) -> Option<Evaluator> {
+        let mut child = match Command::new(engine_path)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+        {
+            Ok(child) => child,
+            Err(e) => {
+                log::error!("Failed to start the evaluation engine at {engine_path}: {e}");
+                return None;
+            }
+        };
+        let (Some(mut stdin), Some(stdout)) = (child.stdin.take(), child.stdout.take()) else {
+            let _ = child.kill();
+            return None;
+        };
+
+        let (line_tx, line_rx) = mpsc::channel();
+        thread::spawn(move || {
+            for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+                let engine_line = if line.starts_with("bestmove") {
+                    EngineLine::BestMove
+                } else if line.starts_with("info") && line.contains(" score ") {
+                    EngineLine::Score(line)
+                } else {
+                    continue;
+                };
+                if line_tx.send(engine_line).is_err() {
+                    return;
+                }
+            }
+        });
+
+        if let Err(e) = stdin.write_all(b"uci\nucinewgame\n") {
+            log::error!("Failed to talk to the evaluation engine: {e}");
+            let _ = child.kill();
+            return None;
+        }
+
+        Some(Evaluator {
+            child,
+            stdin,
+            lines: line_rx,
+            searches_started: 0,
+            searches_done: 0,
+            side_to_move: PieceColor::White,
+            evaluated_fen: None,
+            evaluation: None,
+        })
+    }
+
+    /// Start evaluating a position, the search running on the previous position is stopped
+    /// Asking again for the position being evaluated does nothing
+    pub fn evaluate(&mut self, fen: String, side_to_move: PieceColor) {
+        if self.evaluated_fen.as_ref() == Some(&fen) {
+            return;
+        }
+
+        let mut commands = String::new();
+        if self.searches_done < self.searches_started {
+            commands.push_str("stop\n");
+        }
+        commands.push_str(&format!(
+            "position fen {fen}\ngo depth {EVALUATION_DEPTH}\n"
+        ));
+        log::debug!("Sending to the evaluation engine: {commands}");
+        if let Err(e) = self.stdin.write_all(commands.as_bytes()) {
+            log::error!("Failed to send the position to the evaluation engine: {e}");
+            return;
+        }
+
+        self.searches_started += 1;
+        self.side_to_move = side_to_move;
+        self.evaluated_fen = Some(fen);
+    }
+
+    /// Collect the scores given by the engine since the last call, without waiting for it
+    /// The scores of the stopped searches are ignored, they are about older positions
+    pub fn poll(&mut self) {
+        while let Ok(engine_line) = self.lines.try_recv() {
+            match engine_line {
+                EngineLine::BestMove => self.searches_done += 1,
+                EngineLine::Score(line) if self.searches_done + 1 == self.searches_started => {
+                    if let Some(evaluation) = Evaluation::from_info_line(&line, self.side_to_move) {
+                        self.evaluation = Some(evaluation);
+                    }
+                }
+                EngineLine::Score(_) => {}
+            }
+        }
+    }
+}
+
+impl Drop for Evaluator {
+    fn drop(&mut self) {
+        let _ = self.stdin.write_all(b"stop\nquit\n");
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
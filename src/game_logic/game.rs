@@ -1,8 +1,17 @@
-use super::{bot::Bot, coord::Coord, game_board::GameBoard, opponent::Opponent, ui::UI};
+use super::{
+    bot::Bot,
+    coord::Coord,
+    game_board::GameBoard,
+    opponent::{
+        Connection, Opponent, DRAW_ACCEPT, DRAW_DECLINE, DRAW_OFFER, REJOIN_PREFIX, RESEND_PREFIX,
+    },
+    ui::UI,
+};
 use crate::{
     pieces::{PieceColor, PieceMove, PieceType},
-    utils::get_int_from_char,
+    utils::{convert_notation_into_position, get_int_from_char, invert_position},
 };
+use std::hash::{BuildHasher, RandomState};
 
 #[derive(Clone, Debug, PartialEq, Eq, Copy)]
 pub enum GameState {
@@ -10,6 +19,8 @@ pub enum GameState {
     Draw,
     Playing,
     Promotion,
+    /// The player of this color gave up the game
+    Resigned(PieceColor),
 }
 
 pub struct Game {
@@ -25,6 +36,8 @@ pub struct Game {
     pub player_turn: PieceColor,
     /// The current state of the game (Playing, Draw, Checkmate. Promotion)
     pub game_state: GameState,
+    /// The boards before each move of a solo game, to take the moves back
+    pub takeback_boards: Vec<GameBoard>,
 }
 
 impl Clone for Game {
@@ -34,6 +47,10 @@ impl Clone for Game {
             opponent_will_move: p.opponent_will_move,
             color: p.color,
             game_started: p.game_started,
+            draw_offer_received: p.draw_offer_received,
+            draw_offered_at: p.draw_offered_at,
+            addr: p.addr.clone(),
+            connection: p.connection,
         });
 
         Game {
@@ -43,10 +60,38 @@ impl Clone for Game {
             opponent: opponent_clone,
             player_turn: self.player_turn,
             game_state: self.game_state,
+            takeback_boards: self.takeback_boards.clone(),
         }
     }
 }
 
+/// The choices of the player a new game keeps: how the board is drawn and how the draws are claimed
+/// The state of the interface only making sense in the current game (cursor, selection, animations) starts over
+#[derive(Clone)]
+pub struct Settings {
+    ui: UI,
+    auto_claim_draws: bool,
+    auto_claim_repetition: bool,
+}
+
+impl Settings {
+    pub fn of(game: &Game) -> Self {
+        let mut ui = game.ui.clone();
+        ui.reset();
+        Settings {
+            ui,
+            auto_claim_draws: game.game_board.auto_claim_draws,
+            auto_claim_repetition: game.game_board.auto_claim_repetition,
+        }
+    }
+
+    pub fn apply_to(self, game: &mut Game) {
+        game.ui = self.ui;
+        game.game_board.auto_claim_draws = self.auto_claim_draws;
+        game.game_board.auto_claim_repetition = self.auto_claim_repetition;
+    }
+}
+
 impl Default for Game {
     fn default() -> Self {
         Self {
@@ -56,6 +101,7 @@ impl Default for Game {
             opponent: None,
             player_turn: PieceColor::White,
             game_state: GameState::Playing,
+            takeback_boards: vec![],
         }
     }
 }
@@ -70,6 +116,7 @@ impl Game {
             opponent: None,
             player_turn,
             game_state: GameState::Playing,
+            takeback_boards: vec![],
         }
     }
 
@@ -83,6 +130,32 @@ impl Game {
         self.player_turn = player_turn;
     }
 
+    /// Set the multiplayer opponent, the local player's pieces are put at the bottom of the board
+    pub fn set_opponent(&mut self, opponent: Opponent) {
+        let was_flipped = self.game_board.is_flipped;
+        self.game_board.orient_for(opponent.color.opposite());
+        if self.game_board.is_flipped != was_flipped {
+            self.ui.flip_coordinates();
+        }
+        // Both players have to agree on the end of the game, there is no claim message
+        self.game_board.auto_claim_draws = true;
+        self.game_board.auto_claim_repetition = true;
+        self.opponent = Some(opponent);
+    }
+
+    /// Turn the board upside down, the cursor follows the squares it was on
+    pub fn flip_the_board(&mut self) {
+        self.game_board.flip_the_board();
+        self.ui.flip_coordinates();
+    }
+
+    /// The board is drawn upside down compared to how it is stored, to keep the white pieces at the bottom
+    /// or because the player turned it over
+    /// The cursor and the moves stay in the stored board coordinates
+    pub fn is_view_rotated(&self) -> bool {
+        (self.ui.white_perspective && self.game_board.is_flipped) != self.ui.view_flipped
+    }
+
     /// Switch the player turn
     pub fn switch_player_turn(&mut self) {
         match self.player_turn {
@@ -91,13 +164,44 @@ impl Game {
         }
     }
 
+    /// Check if the current player can play a move, without changing the game
+    /// A pawn reaching the last row needs a promotion piece, any other move must not have one
+    pub fn is_move_legal(&self, from: &Coord, to: &Coord, promotion: Option<PieceType>) -> bool {
+        if !from.is_valid() || !to.is_valid() {
+            return false;
+        }
+
+        if !self
+            .game_board
+            .get_authorized_positions(self.player_turn, *from)
+            .contains(to)
+        {
+            return false;
+        }
+
+        let is_promotion = self.game_board.get_piece_type(from) == Some(PieceType::Pawn)
+            && to.row == self.game_board.promotion_row(self.player_turn);
+        match promotion {
+            Some(PieceType::Queen | PieceType::Rook | PieceType::Bishop | PieceType::Knight) => {
+                is_promotion
+            }
+            Some(_) => false,
+            None => !is_promotion,
+        }
+    }
+
     // Methods to select a cell on the board
     pub fn handle_cell_click(&mut self) {
         // If we are doing a promotion the cursor is used for the popup
         if self.game_state == GameState::Promotion {
             self.handle_promotion();
+        } else if self.is_waiting_for_opponent() {
+            // Nothing is played until the opponent moved, the click prepares a premove instead
+            self.select_premove_cell();
+            return;
         } else if !(self.game_state == GameState::Checkmate)
             && !(self.game_state == GameState::Draw)
+            && !matches!(self.game_state, GameState::Resigned(_))
         {
             if self.ui.is_cell_selected() {
                 self.already_selected_cell_action();
@@ -109,6 +213,10 @@ impl Game {
     }
 
     fn update_game_state(&mut self) {
+        // A resignation can't be seen on the board, it lasts until the next game
+        if matches!(self.game_state, GameState::Resigned(_)) {
+            return;
+        }
         if self.game_board.is_checkmate(self.player_turn) {
             self.game_state = GameState::Checkmate;
         } else if self.game_board.is_draw(self.player_turn) {
@@ -118,6 +226,66 @@ impl Game {
         }
     }
 
+    /// Whether the multiplayer game waits for a message of the opponent, his move or the answer to a draw offer
+    pub fn is_waiting_for_opponent(&self) -> bool {
+        self.opponent
+            .as_ref()
+            .is_some_and(|opponent| opponent.opponent_will_move)
+    }
+
+    // Pick the piece of the premove then its destination, any cell can be chosen as the position will change
+    // Clicking the picked piece again cancels the premove, clicking another piece of the player picks it instead
+    fn select_premove_cell(&mut self) {
+        let Some(opponent) = self.opponent.as_ref() else {
+            return;
+        };
+        // The opponent is answering a draw offer, it is still the player's turn
+        if opponent.color != self.player_turn || self.game_state != GameState::Playing {
+            return;
+        }
+        let player_color = opponent.color.opposite();
+        let cell = self.ui.cursor_coordinates;
+        let is_player_piece = self.game_board.get_piece_color(&cell) == Some(player_color);
+
+        match self.ui.premove_source {
+            Some(source) if source == cell => self.ui.premove_source = None,
+            Some(source) if !is_player_piece => {
+                self.ui.premove = Some((source, cell));
+                self.ui.premove_source = None;
+            }
+            _ if is_player_piece => {
+                self.ui.premove_source = Some(cell);
+                self.ui.premove = None;
+            }
+            _ => self.ui.premove = None,
+        }
+    }
+
+    /// Play the premove once the opponent moved, it is dropped when the new position doesn't allow it
+    pub fn play_premove(&mut self) {
+        self.ui.premove_source = None;
+        let Some((from, to)) = self.ui.premove.take() else {
+            return;
+        };
+        if self.is_waiting_for_opponent()
+            || self.game_state != GameState::Playing
+            || self.game_board.get_piece_color(&from) != Some(self.player_turn)
+            || !self
+                .game_board
+                .get_authorized_positions(self.player_turn, from)
+                .contains(&to)
+        {
+            return;
+        }
+
+        // The move is played as the player would, the cursor stays where it is
+        self.ui.old_cursor_position = self.ui.cursor_coordinates;
+        self.ui.selected_coordinates = from;
+        self.ui.cursor_coordinates = to;
+        self.already_selected_cell_action();
+        self.update_game_state();
+    }
+
     pub fn handle_promotion(&mut self) {
         self.promote_piece();
 
@@ -148,7 +316,10 @@ impl Game {
                     || self.game_board.is_draw(self.player_turn)
                     || self.game_board.is_checkmate(self.player_turn))
             {
-                self.game_board.flip_the_board();
+                self.flip_the_board();
+                if self.bot.is_none() {
+                    self.ui.start_flip_animation();
+                }
             }
 
             // If we play against a bot we will play his move and switch the player turn again
@@ -235,13 +406,12 @@ impl Game {
             return;
         };
 
-        let fen_position = self
-            .game_board
-            .fen_position(is_bot_starting, self.player_turn);
+        let fen_position = self.game_board.fen_position(is_bot_starting);
 
         // Retrieve the bot move from the bot
         let bot_move = if let Some(bot) = self.bot.as_mut() {
-            bot.get_bot_move(fen_position)
+            bot.set_chess960(self.game_board.chess960);
+            bot.get_bot_move(fen_position, &self.game_board.board)
         } else {
             return;
         };
@@ -267,9 +437,27 @@ impl Game {
         if promotion_piece.is_some() {
             self.game_board.board[to_y as usize][to_x as usize] =
                 Some((promotion_piece.unwrap(), self.player_turn));
+            self.game_board.promote_last_san(promotion_piece.unwrap());
         }
+
+        // The engine is pondering on its expected reply, we keep the board it expects to compare it with the player move
+        if let Some(bot) = self.bot.as_mut() {
+            if let Some(ponder_move) = bot.ponder_move.take() {
+                let ponder_move = convert_notation_into_position(&ponder_move);
+                let from = Coord::new(
+                    get_int_from_char(ponder_move.chars().next()),
+                    get_int_from_char(ponder_move.chars().nth(1)),
+                );
+                let to = Coord::new(
+                    get_int_from_char(ponder_move.chars().nth(2)),
+                    get_int_from_char(ponder_move.chars().nth(3)),
+                );
+                bot.pondered_board = Some(self.game_board.board_after_move(from, to, None));
+            }
+        }
+
         if is_bot_starting {
-            self.game_board.flip_the_board();
+            self.flip_the_board();
         }
     }
 
@@ -298,6 +486,7 @@ impl Game {
             latest_move.piece_type = new_piece;
             self.game_board.board_history.pop();
             self.game_board.board_history.push(self.game_board.board);
+            self.game_board.promote_last_san(new_piece);
         }
         self.game_state = GameState::Playing;
         self.ui.promotion_cursor = 0;
@@ -306,8 +495,271 @@ impl Game {
             && self.opponent.is_none()
             && self.bot.is_none()
         {
-            self.game_board.flip_the_board();
+            self.flip_the_board();
+            self.ui.start_flip_animation();
+        }
+    }
+
+    /// End the game by a draw when the fifty moves rule or a repetition allows it
+    pub fn claim_draw(&mut self) -> bool {
+        if self.game_state != GameState::Playing || !self.game_board.is_draw_claimable() {
+            return false;
+        }
+        self.ui.unselect_cell();
+        self.game_state = GameState::Draw;
+        true
+    }
+
+    /// The cell of the mated king on the stored board, `None` unless the game ended by a checkmate
+    /// The cell is the one of the stored board whatever its orientation, as the cells drawn by `board_render`
+    pub fn mated_king_cell(&self) -> Option<Coord> {
+        if self.game_state != GameState::Checkmate {
+            return None;
+        }
+        // The mated player is the one to move
+        let king_cell = self
+            .game_board
+            .get_king_coordinates(self.game_board.board, self.player_turn);
+        king_cell.is_valid().then_some(king_cell)
+    }
+
+    /// Whether the game ended, its positions can then be replayed from the history
+    pub fn is_game_over(&self) -> bool {
+        matches!(
+            self.game_state,
+            GameState::Checkmate | GameState::Draw | GameState::Resigned(_)
+        )
+    }
+
+    /// Show the position before the one shown, starting from the live board
+    pub fn show_previous_position(&mut self) {
+        let shown_index = self
+            .ui
+            .history_index
+            .unwrap_or(self.game_board.board_history.len().saturating_sub(1));
+        if shown_index > 0 {
+            self.ui.history_index = Some(shown_index - 1);
+        }
+    }
+
+    /// Show the position after the one shown, the latest one being the live board
+    pub fn show_next_position(&mut self) {
+        if let Some(index) = self.ui.history_index {
+            self.ui.history_index = if index + 2 < self.game_board.board_history.len() {
+                Some(index + 1)
+            } else {
+                None
+            };
+        }
+    }
+
+    pub fn show_first_position(&mut self) {
+        if self.game_board.board_history.len() > 1 {
+            self.ui.history_index = Some(0);
+        }
+    }
+
+    pub fn show_last_position(&mut self) {
+        self.ui.history_index = None;
+    }
+
+    /// A copy of the game on the position shown from the history, None when the live board is shown
+    /// The board keeps the orientation of the live board and the player to move is the one of that position
+    pub fn history_view(&self) -> Option<Game> {
+        let index = self.ui.history_index?;
+        let (board, _) = self
+            .game_board
+            .history_position(index, self.game_board.is_flipped)?;
+        let mut game = self.clone();
+        game.game_board.board = board;
+        game.player_turn = self
+            .game_board
+            .move_history
+            .get(index)
+            .map_or(self.player_turn, |piece_move| piece_move.piece_color);
+        Some(game)
+    }
+
+    /// The cells the last moved piece can reach from the cell it stands on, in the stored board coordinates
+    /// While reviewing a finished game it is the piece moved last in the position shown
+    pub fn last_moved_piece_reach(&self) -> Vec<Coord> {
+        let index = self
+            .ui
+            .history_index
+            .unwrap_or(self.game_board.move_history.len());
+        let Some((board, Some((_, to)))) = self
+            .game_board
+            .history_position(index, self.game_board.is_flipped)
+        else {
+            return vec![];
+        };
+        let Some((_, color)) = board[&to] else {
+            return vec![];
+        };
+
+        // The moves are computed with the owner of the piece at the bottom, as during his turn
+        let mut reach_board = GameBoard::new(board, vec![], vec![board]);
+        reach_board.is_flipped = self.game_board.is_flipped;
+        reach_board.orient_for(color);
+        let is_reoriented = reach_board.is_flipped != self.game_board.is_flipped;
+        let cell = if is_reoriented {
+            invert_position(&to)
+        } else {
+            to
+        };
+        reach_board
+            .get_authorized_positions(color, cell)
+            .iter()
+            .map(|coord| {
+                if is_reoriented {
+                    invert_position(coord)
+                } else {
+                    *coord
+                }
+            })
+            .collect()
+    }
+
+    /// Give up the game for the local player, the one to move in a solo game
+    /// Multiplayer games can't be resigned as the protocol can't tell the opponent about it
+    pub fn resign(&mut self) {
+        if self.opponent.is_some() || self.game_state != GameState::Playing {
+            return;
+        }
+        let resigning_player = match &self.bot {
+            Some(bot) if bot.is_bot_starting => PieceColor::Black,
+            Some(_) => PieceColor::White,
+            None => self.player_turn,
+        };
+        self.ui.unselect_cell();
+        self.game_state = GameState::Resigned(resigning_player);
+    }
+
+    /// Put the cursor on the king of the player to move when it is checked, do nothing otherwise
+    pub fn move_cursor_to_checked_king(&mut self) {
+        let board = self.game_board.board;
+        if !self.game_board.is_getting_checked(board, self.player_turn) {
+            return;
+        }
+
+        self.ui.unselect_cell();
+        self.ui.mouse_used = false;
+        self.ui.cursor_coordinates = self
+            .game_board
+            .get_king_coordinates(board, self.player_turn);
+    }
+
+    /// Play random legal moves to reach a varied position, used for demos
+    /// Pawns are promoted to queens and it stops early when the game ends
+    /// Returns the number of moves played
+    pub fn play_random_moves(&mut self, moves: usize) -> usize {
+        let random_state = RandomState::new();
+
+        for played in 0..moves {
+            if self.game_board.is_checkmate(self.player_turn) {
+                self.game_state = GameState::Checkmate;
+                return played;
+            }
+            if self.game_board.is_draw(self.player_turn) {
+                self.game_state = GameState::Draw;
+                return played;
+            }
+
+            let mut legal_moves: Vec<(Coord, Coord)> = vec![];
+            for row in 0..8 {
+                for col in 0..8 {
+                    let from = Coord::new(row, col);
+                    if self.game_board.get_piece_color(&from) != Some(self.player_turn) {
+                        continue;
+                    }
+                    for to in self
+                        .game_board
+                        .get_authorized_positions(self.player_turn, from)
+                    {
+                        legal_moves.push((from, to));
+                    }
+                }
+            }
+
+            if legal_moves.is_empty() {
+                return played;
+            }
+            let index = random_state.hash_one(played) as usize % legal_moves.len();
+            let (from, to) = legal_moves[index];
+            self.execute_move(&from, &to);
+            self.switch_player_turn();
+
+            // The promotion flips the board, as it does after the promotion popup
+            if self.game_board.is_latest_move_promotion() {
+                self.ui.promotion_cursor = 0;
+                self.promote_piece();
+            } else {
+                self.flip_the_board();
+            }
         }
+
+        if self.game_board.is_checkmate(self.player_turn) {
+            self.game_state = GameState::Checkmate;
+        } else if self.game_board.is_draw(self.player_turn) {
+            self.game_state = GameState::Draw;
+        }
+        self.ui.animation = None;
+        self.ui.flip_frame = None;
+
+        moves
+    }
+
+    /// Play a move of a saved game, written with its cells and promotion piece (`e2e4`, `e7e8q`)
+    /// Returns false without changing the game when the move can't be played in the position
+    pub fn play_saved_move(&mut self, saved_move: &str) -> bool {
+        let cells = saved_move.as_bytes();
+        if !(4..=5).contains(&cells.len()) {
+            return false;
+        }
+        let cell = |col: u8, rank: u8| {
+            ((b'a'..=b'h').contains(&col) && (b'1'..=b'8').contains(&rank))
+                .then(|| Coord::new(b'8' - rank, col - b'a'))
+        };
+        let (Some(from), Some(to)) = (cell(cells[0], cells[1]), cell(cells[2], cells[3])) else {
+            return false;
+        };
+        // The saved cells have the white pieces at the bottom
+        let (from, to) = if self.game_board.is_flipped {
+            (invert_position(&from), invert_position(&to))
+        } else {
+            (from, to)
+        };
+        let promotion = match cells.get(4) {
+            None => None,
+            Some(b'q') => Some(PieceType::Queen),
+            Some(b'r') => Some(PieceType::Rook),
+            Some(b'b') => Some(PieceType::Bishop),
+            Some(b'n') => Some(PieceType::Knight),
+            Some(_) => return false,
+        };
+        if !self.is_move_legal(&from, &to, promotion) {
+            return false;
+        }
+
+        self.execute_move(&from, &to);
+        self.switch_player_turn();
+        // The promotion flips the board, as it does after the promotion popup
+        match promotion {
+            Some(piece_type) => {
+                self.ui.promotion_cursor = match piece_type {
+                    PieceType::Rook => 1,
+                    PieceType::Bishop => 2,
+                    PieceType::Knight => 3,
+                    _ => 0,
+                };
+                self.promote_piece();
+            }
+            None => self.flip_the_board(),
+        }
+        self.update_game_state();
+        self.ui.animation = None;
+        self.ui.flip_frame = None;
+        true
     }
 
     /// Move a piece from a cell to another
@@ -316,6 +768,12 @@ impl Game {
         if !from.is_valid() || !to.is_valid() {
             return;
         }
+        // A move played during a flip shows the board in its final orientation
+        self.ui.flip_frame = None;
+        // Only the solo games can take a move back, nobody else has to agree on it
+        if self.bot.is_none() && self.opponent.is_none() {
+            self.takeback_boards.push(self.game_board.clone());
+        }
 
         let piece_type_from = self.game_board.get_piece_type(from);
         let piece_type_to = self.game_board.get_piece_type(to);
@@ -325,6 +783,13 @@ impl Game {
             return;
         };
 
+        // The notation needs the board as it is before the move
+        let san = self.game_board.get_san(*from, *to, self.player_turn, None);
+
+        // A castling goes to the own rook, it is neither a capture nor a pawn move
+        let castling_rook = self.game_board.castling_rook(*from, *to);
+        let piece_type_to = piece_type_to.filter(|_| castling_rook.is_none());
+
         // We increment the consecutive_non_pawn_or_capture if the piece type is a pawn or if there is no capture
         self.game_board
             .increment_consecutive_non_pawn_or_capture(piece_type_from, piece_type_to);
@@ -341,47 +806,20 @@ impl Game {
         }
 
         // We check for castling as the latest move
-        if self.game_board.is_latest_move_castling(*from, *to) {
-            // we set the king 2 cells on where it came from
-            let from_x: i32 = from.col as i32;
-            let mut new_to = to;
-            let to_x: i32 = to.col as i32;
-
-            let distance = from_x - to_x;
-            // We set the direction of the rook > 0 meaning he went on the left else on the right
-            let direction_x = if distance > 0 { -1 } else { 1 };
-
-            let col_king = from_x + direction_x * 2;
-
-            // We put move the king 2 cells
-            self.game_board.board[to.row as usize][col_king as usize] = self.game_board.board[from];
-
-            // We put the rook 3 cells from it's position if it's a big castling else 2 cells
-            // If it is playing against a bot we will receive 4 -> 6  and 4 -> 2 for to_x instead of 4 -> 7 and 4 -> 0
-            if self.bot.is_some() && to_x == 6 && to.row == 0 {
-                new_to = &Coord { row: 0, col: 7 };
-            }
-            if self.bot.is_some() && to_x == 2 && to.row == 0 {
-                new_to = &Coord { row: 0, col: 0 };
-            }
-
-            let col_rook = if distance > 0 {
-                col_king + 1
-            } else {
-                col_king - 1
-            };
-
-            self.game_board.board[new_to.row as usize][col_rook as usize] =
-                Some((PieceType::Rook, self.player_turn));
+        if let Some(rook) = castling_rook {
+            // The king and the rook go to their castling cells, the Chess960 ones start anywhere on the row
+            let (king_to, _) = self.game_board.castling_destinations(*from, rook);
+            self.game_board.board = self.game_board.board_after_move(*from, *to, None);
 
-            // We remove the latest rook
-            self.game_board.board[new_to] = None;
+            self.ui
+                .start_move_animation(*from, king_to, self.game_board.is_flipped);
         } else {
             self.game_board.board[to] = self.game_board.board[from];
+            self.game_board.board[from] = None;
+            self.ui
+                .start_move_animation(*from, *to, self.game_board.is_flipped);
         }
 
-        self.game_board.board[from] = None;
-
         // We store it in the history
         self.game_board.move_history.push(PieceMove {
             piece_type: piece_type_from,
@@ -389,17 +827,61 @@ impl Game {
             from: *from,
             to: *to,
         });
+        self.game_board.san_history.push(san);
         // We store the current position of the board
         self.game_board.board_history.push(self.game_board.board);
+        self.game_board
+            .board_history_flipped
+            .push(self.game_board.is_flipped);
     }
 
-    pub fn execute_opponent_move(&mut self) {
+    /// Take back the last move of a solo game, the board is turned back toward the player who played it
+    pub fn take_back_move(&mut self) {
+        if self.bot.is_some()
+            || self.opponent.is_some()
+            || self.game_state == GameState::Promotion
+            || matches!(self.game_state, GameState::Resigned(_))
+        {
+            return;
+        }
+        let Some(game_board) = self.takeback_boards.pop() else {
+            return;
+        };
+        if let Some(last_move) = self.game_board.move_history.last() {
+            self.player_turn = last_move.piece_color;
+        }
+
+        // The board before the move is stored with the player who played it at the bottom
+        let was_flipped = self.game_board.is_flipped;
+        self.game_board = game_board;
+        if self.game_board.is_flipped != was_flipped {
+            self.ui.flip_coordinates();
+        }
+        self.game_state = GameState::Playing;
+        self.ui.unselect_cell();
+        self.ui.animation = None;
+        self.ui.flip_frame = None;
+        self.ui.history_index = None;
+    }
+
+    /// Play the move received from the opponent
+    /// Returns false when the message was part of a draw offer, the turn doesn't change then
+    pub fn execute_opponent_move(&mut self) -> bool {
         let opponent_move = self.opponent.as_mut().unwrap().read_stream();
-        self.game_board.flip_the_board();
+        // The move is read again once connected again
+        if self.opponent.as_ref().unwrap().connection != Connection::Connected {
+            return false;
+        }
+        if self.handle_draw_message(opponent_move.trim())
+            || self.handle_resync_message(opponent_move.trim())
+        {
+            return false;
+        }
+        self.flip_the_board();
         self.opponent.as_mut().unwrap().opponent_will_move = false;
 
         if opponent_move.is_empty() {
-            return;
+            return true;
         }
 
         let from_y = get_int_from_char(opponent_move.chars().next());
@@ -423,11 +905,134 @@ impl Game {
 
         self.execute_move(from, to);
 
-        if promotion_piece.is_some() {
+        if let Some(promotion_piece) = promotion_piece {
             self.game_board.board[to_y as usize][to_x as usize] =
-                Some((promotion_piece.unwrap(), self.player_turn));
+                Some((promotion_piece, self.player_turn));
+
+            // We keep the history in sync with the promoted piece, as promote_piece does for the local player
+            if let Some(latest_move) = self.game_board.move_history.last_mut() {
+                latest_move.piece_type = promotion_piece;
+            }
+            self.game_board.board_history.pop();
+            self.game_board.board_history.push(self.game_board.board);
+            self.game_board.promote_last_san(promotion_piece);
+        }
+        self.flip_the_board();
+        true
+    }
+
+    // Apply a draw offer message from the opponent, false when the message is a move
+    fn handle_draw_message(&mut self, message: &str) -> bool {
+        let opponent = self.opponent.as_mut().unwrap();
+        match message {
+            // The offer is answered on our side while the opponent keeps the move
+            DRAW_OFFER => opponent.draw_offer_received = true,
+            DRAW_ACCEPT => self.game_state = GameState::Draw,
+            DRAW_DECLINE => {
+                self.ui.status_message = Some("Your opponent declined the draw".to_string());
+            }
+            _ => return false,
+        }
+        opponent.opponent_will_move = false;
+        true
+    }
+
+    /// Offer a draw to the opponent instead of moving, once per turn so the offers can't be repeated
+    /// The answer is read as the next opponent message
+    pub fn offer_draw(&mut self) {
+        let moves_played = self.game_board.move_history.len();
+        let Some(opponent) = self.opponent.as_mut() else {
+            return;
+        };
+        if !opponent.game_started
+            || opponent.opponent_will_move
+            || opponent.color == self.player_turn
+            || self.game_state != GameState::Playing
+            || opponent.draw_offered_at == Some(moves_played)
+        {
+            return;
+        }
+
+        opponent.send_draw_message(DRAW_OFFER);
+        opponent.draw_offered_at = Some(moves_played);
+        opponent.opponent_will_move = true;
+        self.ui.unselect_cell();
+        self.ui.status_message = Some("Draw offered, waiting for the answer".to_string());
+    }
+
+    /// Answer the draw offered by the opponent, who still has to move when it is declined
+    pub fn answer_draw_offer(&mut self, accept: bool) {
+        let Some(opponent) = self.opponent.as_mut() else {
+            return;
+        };
+        if !opponent.draw_offer_received {
+            return;
+        }
+
+        opponent.draw_offer_received = false;
+        if accept {
+            opponent.send_draw_message(DRAW_ACCEPT);
+            self.game_state = GameState::Draw;
+        } else {
+            opponent.send_draw_message(DRAW_DECLINE);
+            opponent.opponent_will_move = true;
+        }
+    }
+
+    // Send again the moves lost while a player was connecting again, false when the message is something else
+    fn handle_resync_message(&mut self, message: &str) -> bool {
+        let mut chars = message.chars();
+        let (Some(prefix), Ok(opponent_moves)) = (chars.next(), chars.as_str().parse::<usize>())
+        else {
+            return false;
+        };
+        if prefix != REJOIN_PREFIX && prefix != RESEND_PREFIX {
+            return false;
+        }
+
+        let moves_played = self.game_board.move_history.len();
+        let opponent = self.opponent.as_mut().unwrap();
+        let is_last_move_ours = self
+            .game_board
+            .move_history
+            .last()
+            .is_some_and(|last_move| last_move.piece_color != opponent.color);
+        if moves_played == opponent_moves + 1 && is_last_move_ours {
+            // Our last move never reached the opponent
+            let last_move = self.game_board.move_history.last().unwrap();
+            let promotion = self
+                .game_board
+                .san_history
+                .last()
+                .is_some_and(|san| san.contains('='))
+                .then(|| self.game_board.get_last_move_piece_type_as_string());
+            opponent.send_move_to_server(last_move, promotion);
+        } else if prefix == REJOIN_PREFIX && opponent_moves == moves_played + 1 {
+            // The last move of the opponent never reached us, the opponent sends it again
+            opponent.send_resync_message(RESEND_PREFIX, moves_played);
+        }
+        true
+    }
+
+    /// Keep a multiplayer game going between the opponent moves, called on every tick
+    /// A dropped connection is tried again, and while the player is to move the messages of an opponent
+    /// who connected again are answered
+    pub fn update_opponent_connection(&mut self) {
+        let moves_played = self.game_board.move_history.len();
+        let Some(opponent) = self.opponent.as_mut() else {
+            return;
+        };
+        if !opponent.game_started {
+            return;
+        }
+        match opponent.connection {
+            Connection::Reconnecting { .. } => opponent.try_reconnect(moves_played),
+            Connection::Connected if !opponent.opponent_will_move => {
+                let message = opponent.poll_stream();
+                self.handle_resync_message(message.trim());
+            }
+            _ => {}
         }
-        self.game_board.flip_the_board();
     }
 
     pub fn handle_multiplayer_promotion(&mut self) {
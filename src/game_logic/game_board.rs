@@ -1,13 +1,18 @@
 use super::{
     board::{init_board, Board},
+    board_editor::{moved_rook, BoardEditor, CASTLING_SIDES},
     coord::Coord,
-    game::Game,
+    openings::{find_opening, Opening},
 };
 use crate::{
+    export::board_to_unicode_block,
     pieces::{pawn::Pawn, PieceColor, PieceMove, PieceType},
-    utils::col_to_letter,
+    utils::{col_to_letter, invert_position},
 };
 
+/// The fifty moves rule counts the moves of both players, the halfmoves of the FEN clock
+const FIFTY_MOVES_RULE_HALFMOVES: i32 = 100;
+
 /// ## visual representation
 ///
 /// ### how it's stored:
@@ -44,13 +49,39 @@ pub struct GameBoard {
     // historic of the past Moves of the board
     pub move_history: Vec<PieceMove>,
     // historic of the past gameboards states
+    // each board is stored as the board was when it was pushed, with the player who just moved at the bottom in solo
     pub board_history: Vec<Board>,
+    // whether each board of the history was stored with the black pieces at the bottom
+    // the history is replayed with the current orientation, so a flipped solo game doesn't turn at every step
+    pub board_history_flipped: Vec<bool>,
     // the number of consecutive non pawn or capture moves
     consecutive_non_pawn_or_capture: i32,
     // The white piece that got taken
     pub white_taken_pieces: Vec<PieceType>,
     // The black piece that got taken
     pub black_taken_pieces: Vec<PieceType>,
+    // true when the board is stored from black's point of view (black pieces at the bottom)
+    pub is_flipped: bool,
+    // the past moves in standard algebraic notation
+    pub san_history: Vec<String>,
+    // the fifty moves and the repetition draws end the game, they have to be claimed otherwise
+    pub auto_claim_draws: bool,
+    // the threefold repetition ends the game with the other automatic draws, the player is asked to claim it otherwise
+    pub auto_claim_repetition: bool,
+    // pieces that moved before the move history starts, as in a position set up without some castling rights
+    // the cells are the ones of the stored board with the piece's color at the bottom, as in the move history
+    pub moved_pieces: Vec<(PieceType, PieceColor, Coord)>,
+    // the player of the first move of the history, black in a position set up with black to move
+    pub first_player: PieceColor,
+    // the number of the first move of the history, the fullmove number of a position set up from a FEN
+    pub first_move_number: u32,
+    // the halfmove clock before the first move of the history, the one of a position set up from a FEN
+    pub first_halfmove_clock: i32,
+    // the en passant target before the first move of the history, the one of a position set up from a FEN
+    // the cell is the one of the board with the white pieces at the bottom
+    pub first_en_passant: Option<Coord>,
+    // the game started from a Chess960 starting position, the engine has to know it to castle
+    pub chess960: bool,
 }
 
 impl Default for GameBoard {
@@ -59,9 +90,20 @@ impl Default for GameBoard {
             board: init_board(),
             move_history: vec![],
             board_history: vec![init_board()],
+            board_history_flipped: vec![false],
             consecutive_non_pawn_or_capture: 0,
             white_taken_pieces: vec![],
             black_taken_pieces: vec![],
+            is_flipped: false,
+            san_history: vec![],
+            auto_claim_draws: true,
+            auto_claim_repetition: true,
+            moved_pieces: vec![],
+            first_player: PieceColor::White,
+            first_move_number: 1,
+            first_halfmove_clock: 0,
+            first_en_passant: None,
+            chess960: false,
         }
     }
 }
@@ -71,10 +113,21 @@ impl GameBoard {
         Self {
             board,
             move_history,
+            board_history_flipped: vec![false; board_history.len()],
             board_history,
             consecutive_non_pawn_or_capture: 0,
             white_taken_pieces: vec![],
             black_taken_pieces: vec![],
+            is_flipped: false,
+            san_history: vec![],
+            auto_claim_draws: true,
+            auto_claim_repetition: true,
+            moved_pieces: vec![],
+            first_player: PieceColor::White,
+            first_move_number: 1,
+            first_halfmove_clock: 0,
+            first_en_passant: None,
+            chess960: false,
         }
     }
 
@@ -139,12 +192,40 @@ impl GameBoard {
         }
     }
 
+    /// Points of the pieces taken by white minus the ones taken by black, negative when black is ahead
+    /// A promoted piece counts for its new value once taken
+    pub fn material_advantage(&self) -> i32 {
+        let value = |pieces: &[PieceType]| -> i32 {
+            pieces.iter().map(|piece| piece.material_value()).sum()
+        };
+        value(&self.white_taken_pieces) - value(&self.black_taken_pieces)
+    }
+
+    /// The opening of the moves played, only for the games started from the start position
+    pub fn opening(&self) -> Option<Opening> {
+        if self.board_history.first() != Some(&init_board())
+            || self.board_history_flipped.first() != Some(&false)
+        {
+            return None;
+        }
+        find_opening(&self.san_history)
+    }
+
     pub fn reset(&mut self) {
         self.board = init_board();
         self.move_history.clear();
         self.board_history.clear();
         self.board_history.push(init_board());
+        self.board_history_flipped = vec![false];
         self.consecutive_non_pawn_or_capture = 0;
+        self.is_flipped = false;
+        self.san_history.clear();
+        self.moved_pieces.clear();
+        self.first_player = PieceColor::White;
+        self.first_move_number = 1;
+        self.first_halfmove_clock = 0;
+        self.first_en_passant = None;
+        self.chess960 = false;
     }
 
     // Method to get the authorized positions for a piece
@@ -184,6 +265,28 @@ impl GameBoard {
             }
         }
         self.board = flipped_board;
+        self.is_flipped = !self.is_flipped;
+    }
+
+    /// Whether the pieces of `color` are at the bottom of the stored board, they move up
+    pub fn is_at_bottom(&self, color: PieceColor) -> bool {
+        (color == PieceColor::Black) == self.is_flipped
+    }
+
+    /// The row where the pawns of `color` get promoted
+    pub fn promotion_row(&self, color: PieceColor) -> u8 {
+        if self.is_at_bottom(color) {
+            0
+        } else {
+            7
+        }
+    }
+
+    /// Make sure the pieces of `color` are at the bottom of the board, whatever the current orientation
+    pub fn orient_for(&mut self, color: PieceColor) {
+        if self.is_flipped != (color == PieceColor::Black) {
+            self.flip_the_board();
+        }
     }
 
     // Check if the latest move is en passant
@@ -206,17 +309,144 @@ impl GameBoard {
 
     // Check if the latest move is castling
     pub fn is_latest_move_castling(&self, from: Coord, to: Coord) -> bool {
-        let piece_type_from = self.get_piece_type(&from);
-        let piece_type_to = self.get_piece_type(&to);
+        self.castling_rook(from, to).is_some()
+    }
 
-        let from_x: i32 = from.col as i32;
-        let to_x: i32 = to.col as i32;
-        let distance = (from_x - to_x).abs();
+    /// The rook of a castling played as the move from `from` to `to`, None when the move isn't a castling
+    /// A castling is played as the king going to its rook, the engines play the usual castlings as the king going two cells aside
+    pub fn castling_rook(&self, from: Coord, to: Coord) -> Option<Coord> {
+        let (PieceType::King, color) = self.board[&from]? else {
+            return None;
+        };
+        if from.row != to.row || from.col == to.col {
+            return None;
+        }
+        if self.board[&to] == Some((PieceType::Rook, color)) {
+            return Some(to);
+        }
+        if from.col.abs_diff(to.col) < 2 {
+            return None;
+        }
 
-        match (piece_type_from, piece_type_to) {
-            (Some(PieceType::King), _) => distance > 1,
-            _ => false,
+        // The outermost rook on the side the king went to
+        let cols: Vec<u8> = if to.col > from.col {
+            (from.col + 1..8).rev().collect()
+        } else {
+            (0..from.col).collect()
+        };
+        cols.into_iter()
+            .map(|col| Coord::new(from.row, col))
+            .find(|cell| self.board[cell] == Some((PieceType::Rook, color)))
+    }
+
+    /// The cells of the king and the rook once they castled, whatever the cells they start from
+    /// They go to the g and f files on the king side, the c and d files on the queen side
+    pub fn castling_destinations(&self, king: Coord, rook: Coord) -> (Coord, Coord) {
+        let (king_file, rook_file) = if self.is_king_side(king, rook) {
+            (6, 5)
+        } else {
+            (2, 3)
+        };
+        let to_cell =
+            |file: u8| Coord::new(king.row, if self.is_flipped { 7 - file } else { file });
+        (to_cell(king_file), to_cell(rook_file))
+    }
+
+    // The rook stands toward the h file, whatever the board orientation
+    fn is_king_side(&self, king: Coord, rook: Coord) -> bool {
+        (rook.col > king.col) != self.is_flipped
+    }
+
+    /// The rooks the king of `color` can still castle with, the king side one first
+    /// The king and the rook never moved and stand on the same row, the outermost rook of each side is kept
+    pub fn castling_rooks(&self, color: PieceColor) -> [Option<Coord>; 2] {
+        let king = self.get_king_coordinates(self.board, color);
+        let moved_cells = self.moved_cells();
+        // A castled king can stand on a cell its move didn't go to, so its moves are looked for too
+        let did_king_move = self.move_history.iter().any(|piece_move| {
+            (piece_move.piece_type, piece_move.piece_color) == (PieceType::King, color)
+        });
+        if !king.is_valid()
+            || (king.row != 0 && king.row != 7)
+            || did_king_move
+            || !self.is_unmoved(king, &moved_cells)
+        {
+            return [None, None];
+        }
+
+        let unmoved_rook = |col: u8| {
+            let cell = Coord::new(king.row, col);
+            (self.board[&cell] == Some((PieceType::Rook, color))
+                && self.is_unmoved(cell, &moved_cells))
+            .then_some(cell)
+        };
+        let right_rook = (king.col + 1..8).rev().find_map(unmoved_rook);
+        let left_rook = (0..king.col).find_map(unmoved_rook);
+        if self.is_flipped {
+            [left_rook, right_rook]
+        } else {
+            [right_rook, left_rook]
+        }
+    }
+
+    /// Whether the king at `king` can castle with the rook at `rook` now
+    /// Only the king and the rook can stand between their cells and their cells once castled,
+    /// and the king can't be checked on its way, the cell it leaves and the one it reaches included
+    pub fn can_castle(&self, king: Coord, rook: Coord, color: PieceColor) -> bool {
+        let (king_to, rook_to) = self.castling_destinations(king, rook);
+        let cols = [king.col, rook.col, king_to.col, rook_to.col];
+        let (min_col, max_col) = (*cols.iter().min().unwrap(), *cols.iter().max().unwrap());
+        let is_path_free = (min_col..=max_col)
+            .map(|col| Coord::new(king.row, col))
+            .all(|cell| cell == king || cell == rook || self.board[&cell].is_none());
+        if !is_path_free {
+            return false;
         }
+
+        let checked_cells = self.get_all_protected_cells(color);
+        let is_king_path_safe = (king.col.min(king_to.col)..=king.col.max(king_to.col))
+            .all(|col| !checked_cells.contains(&Coord::new(king.row, col)));
+        is_king_path_safe
+            && !self.is_getting_checked(self.board_after_move(king, rook, None), color)
+    }
+
+    // The cells left or reached by the moves of the history, in the current orientation
+    // each move is stored in the orientation of the board it reached, the last ones of the history
+    fn moved_cells(&self) -> Vec<Coord> {
+        let first_board =
+            self.board_history_flipped.len() as isize - self.move_history.len() as isize;
+        self.move_history
+            .iter()
+            .enumerate()
+            .flat_map(|(index, piece_move)| {
+                let was_flipped = usize::try_from(first_board + index as isize)
+                    .ok()
+                    .and_then(|board_index| self.board_history_flipped.get(board_index))
+                    .copied()
+                    .unwrap_or(self.is_flipped);
+                let cells = [piece_move.from, piece_move.to];
+                if was_flipped == self.is_flipped {
+                    cells
+                } else {
+                    cells.map(|cell| invert_position(&cell))
+                }
+            })
+            .collect()
+    }
+
+    // Whether the piece on the cell never moved, in the history or before it
+    fn is_unmoved(&self, cell: Coord, moved_cells: &[Coord]) -> bool {
+        let Some((piece_type, color)) = self.board[&cell] else {
+            return false;
+        };
+        // The pieces moved before the history are kept with their owner at the bottom
+        let owner_cell = if self.is_at_bottom(color) {
+            cell
+        } else {
+            invert_position(&cell)
+        };
+        !moved_cells.contains(&cell)
+            && !self.moved_pieces.contains(&(piece_type, color, owner_cell))
     }
 
     // Check if the latest move is a promotion
@@ -266,13 +496,60 @@ impl GameBoard {
         if self.move_history.is_empty() {
             self.board_history.clear();
             self.board_history.push(self.board);
+            self.board_history_flipped = vec![self.is_flipped];
+            return false;
+        }
+
+        self.is_position_repeated()
+    }
+
+    /// The board after `index` moves and the cells of the move leading to it, from the history
+    /// Both are turned to the `is_flipped` orientation, whatever the orientation the board had when it was stored
+    pub fn history_position(
+        &self,
+        index: usize,
+        is_flipped: bool,
+    ) -> Option<(Board, Option<(Coord, Coord)>)> {
+        let mut history_board = GameBoard::new(*self.board_history.get(index)?, vec![], vec![]);
+        let mut move_cells = index
+            .checked_sub(1)
+            .and_then(|move_index| self.move_history.get(move_index))
+            .map(|piece_move| (piece_move.from, piece_move.to));
+
+        let was_flipped = self
+            .board_history_flipped
+            .get(index)
+            .copied()
+            .unwrap_or(false);
+        if was_flipped != is_flipped {
+            history_board.flip_the_board();
+            move_cells =
+                move_cells.map(|(from, to)| (invert_position(&from), invert_position(&to)));
+        }
+        Some((history_board.board, move_cells))
+    }
+
+    // The boards of the history with the white pieces at the bottom, with the parity of their index for the player to move
+    // A solo game stores each board toward the player who just moved, so a position is stored both ways
+    fn history_positions(&self) -> Vec<(Board, bool)> {
+        (0..self.board_history.len())
+            .filter_map(|index| {
+                self.history_position(index, false)
+                    .map(|(board, _)| (board, index % 2 == 0))
+            })
+            .collect()
+    }
+
+    // Check if a position was reached three times
+    fn is_position_repeated(&self) -> bool {
+        if self.move_history.is_empty() {
             return false;
         }
 
         // Index mapping
         let mut position_counts = std::collections::HashMap::new();
-        for board in self.board_history.iter() {
-            let count = position_counts.entry(board).or_insert(0);
+        for position in self.history_positions() {
+            let count = position_counts.entry(position).or_insert(0);
             *count += 1;
 
             if *count >= 3 {
@@ -283,11 +560,67 @@ impl GameBoard {
         false
     }
 
-    // Check if the game is a draw
+    /// Check if the current position was just reached for the third time, or more, and the repetition waits for a claim
+    pub fn is_repetition_to_claim(&self) -> bool {
+        if self.auto_claim_repetition || self.move_history.is_empty() {
+            return false;
+        }
+        let positions = self.history_positions();
+        let Some(current_position) = positions.last() else {
+            return false;
+        };
+        positions
+            .iter()
+            .filter(|&position| position == current_position)
+            .count()
+            >= 3
+    }
+
+    /// Check if no sequence of legal moves can lead to a checkmate, from the material left (FIDE 5.2.2)
+    /// The dead positions are:
+    /// - only the kings
+    /// - a king and a single knight against a lone king
+    /// - kings and bishops only, all the bishops standing on the same cell color
+    ///
+    /// A knight on each side, two knights, or bishops on different cell colors can still mate
+    pub fn is_dead_position(&self) -> bool {
+        let mut knights = 0;
+        let mut bishop_cell_colors: Vec<u8> = vec![];
+
+        for i in 0..8u8 {
+            for j in 0..8u8 {
+                match self.get_piece_type(&Coord::new(i, j)) {
+                    Some(PieceType::King) | None => {}
+                    Some(PieceType::Knight) => knights += 1,
+                    // The cell color doesn't change when the board is flipped
+                    Some(PieceType::Bishop) => bishop_cell_colors.push((i + j) % 2),
+                    Some(PieceType::Pawn | PieceType::Rook | PieceType::Queen) => return false,
+                }
+            }
+        }
+
+        match (knights, bishop_cell_colors.as_slice()) {
+            (0, []) | (1, []) => true,
+            (0, [first, others @ ..]) => others.iter().all(|color| color == first),
+            _ => false,
+        }
+    }
+
+    /// Check if a player can claim a draw by the fifty moves rule or by repetition
+    pub fn is_draw_claimable(&self) -> bool {
+        self.consecutive_non_pawn_or_capture >= FIFTY_MOVES_RULE_HALFMOVES
+            || self.is_position_repeated()
+    }
+
+    // Check if the game is a draw, the claimable draws only count when they are claimed automatically
     pub fn is_draw(&mut self, player_turn: PieceColor) -> bool {
+        let is_repetition = self.is_draw_by_repetition();
+
         self.number_of_authorized_positions(player_turn) == 0
-            || self.consecutive_non_pawn_or_capture == 50
-            || self.is_draw_by_repetition()
+            || self.is_dead_position()
+            || (self.auto_claim_draws
+                && (self.consecutive_non_pawn_or_capture >= FIFTY_MOVES_RULE_HALFMOVES
+                    || (self.auto_claim_repetition && is_repetition)))
     }
 
     pub fn set_consecutive_non_pawn_or_capture(&mut self, value: i32) {
@@ -336,6 +669,15 @@ impl GameBoard {
         Coord::undefined()
     }
 
+    /// The cells the king can escape to when it is getting checked, empty when the king isn't checked
+    pub fn get_king_escape_positions(&self, player_turn: PieceColor) -> Vec<Coord> {
+        if !self.is_getting_checked(self.board, player_turn) {
+            return vec![];
+        }
+        let king_coordinates = self.get_king_coordinates(self.board, player_turn);
+        self.get_authorized_positions(player_turn, king_coordinates)
+    }
+
     /// Is getting checked
     /// Here we keep the board as one of the parameters because for the king position we need to simulate the board if he moves
     /// to make sure he will not be checked after the move
@@ -346,9 +688,20 @@ impl GameBoard {
             board,
             move_history: self.move_history.clone(),
             board_history: self.board_history.clone(),
+            board_history_flipped: self.board_history_flipped.clone(),
             consecutive_non_pawn_or_capture: self.consecutive_non_pawn_or_capture,
             white_taken_pieces: self.white_taken_pieces.clone(),
             black_taken_pieces: self.black_taken_pieces.clone(),
+            is_flipped: self.is_flipped,
+            san_history: vec![],
+            auto_claim_draws: self.auto_claim_draws,
+            auto_claim_repetition: self.auto_claim_repetition,
+            moved_pieces: self.moved_pieces.clone(),
+            first_player: self.first_player,
+            first_move_number: self.first_move_number,
+            first_halfmove_clock: self.first_halfmove_clock,
+            first_en_passant: self.first_en_passant,
+            chess960: self.chess960,
         };
 
         let checked_cells = fake_game_board.get_all_protected_cells(player_turn);
@@ -356,22 +709,6 @@ impl GameBoard {
         checked_cells.contains(&coordinates)
     }
 
-    /// Check if a piece already moved on the board
-    pub fn did_piece_already_move(
-        &self,
-        original_piece: (Option<PieceType>, Option<PieceColor>, Coord),
-    ) -> bool {
-        for entry in &self.move_history {
-            if Some(entry.piece_type) == original_piece.0
-                && Some(entry.piece_color) == original_piece.1
-                && entry.from == original_piece.2
-            {
-                return true;
-            }
-        }
-        false
-    }
-
     // Get all the positions where the king can't go because it's checked
     pub fn impossible_positions_king_checked(
         &self,
@@ -381,17 +718,11 @@ impl GameBoard {
     ) -> Vec<Coord> {
         let mut cleaned_position: Vec<Coord> = vec![];
         for position in positions {
-            let game = GameBoard::new(self.board, self.move_history.to_vec(), vec![]);
-
-            // We create a new board
-            let mut new_board = Game::new(game, color);
-
             // We simulate the move
-
-            Game::execute_move(&mut new_board, original_coordinates, &position);
+            let new_board = self.board_after_move(*original_coordinates, position, None);
 
             // We check if the board is still checked with this move meaning it didn't resolve the problem
-            if !self.is_getting_checked(new_board.game_board.board, new_board.player_turn) {
+            if !self.is_getting_checked(new_board, color) {
                 cleaned_position.push(position);
             };
         }
@@ -413,8 +744,255 @@ impl GameBoard {
         self.board[coordinates].map(|(piece_type, _)| piece_type)
     }
 
+    /// Convert a cell of the stored board into its square name (e.g "e4"), taking the board orientation into account
+    pub fn coord_to_square(&self, coordinates: &Coord) -> String {
+        let (rank, file) = if self.is_flipped {
+            (coordinates.row + 1, 7 - coordinates.col)
+        } else {
+            (8 - coordinates.row, coordinates.col)
+        };
+        format!("{}{rank}", col_to_letter(file))
+    }
+
+    /// Standard algebraic notation (e.g "Nf3", "exd5", "O-O") of a move that is about to be played
+    /// The promotion piece is only known once chosen, so it is only added when given
+    pub fn get_san(
+        &self,
+        from: Coord,
+        to: Coord,
+        player_turn: PieceColor,
+        promotion: Option<PieceType>,
+    ) -> String {
+        let Some(piece_type) = self.get_piece_type(&from) else {
+            return String::new();
+        };
+
+        let mut san = String::new();
+
+        if let Some(rook) = self.castling_rook(from, to) {
+            san.push_str(if self.is_king_side(from, rook) {
+                "O-O"
+            } else {
+                "O-O-O"
+            });
+        } else {
+            let is_capture =
+                self.board[&to].is_some() || self.is_latest_move_en_passant(&from, &to);
+            let from_square = self.coord_to_square(&from);
+
+            if piece_type == PieceType::Pawn {
+                if is_capture {
+                    san.push_str(&from_square[..1]);
+                }
+            } else {
+                san.push_str(PieceType::piece_to_fen_enum(
+                    Some(piece_type),
+                    Some(PieceColor::White),
+                ));
+
+                // We look for other pieces of the same type that could also go to this cell
+                let mut same_file = false;
+                let mut same_rank = false;
+                let mut is_ambiguous = false;
+                for i in 0..8u8 {
+                    for j in 0..8u8 {
+                        let other = Coord::new(i, j);
+                        if other == from
+                            || self.board[&other] != Some((piece_type, player_turn))
+                            || !self
+                                .get_authorized_positions(player_turn, other)
+                                .contains(&to)
+                        {
+                            continue;
+                        }
+                        is_ambiguous = true;
+                        same_file |= other.col == from.col;
+                        same_rank |= other.row == from.row;
+                    }
+                }
+
+                if is_ambiguous {
+                    if !same_file {
+                        san.push_str(&from_square[..1]);
+                    } else if !same_rank {
+                        san.push_str(&from_square[1..]);
+                    } else {
+                        san.push_str(&from_square);
+                    }
+                }
+            }
+
+            if is_capture {
+                san.push('x');
+            }
+            san.push_str(&self.coord_to_square(&to));
+
+            if let Some(promotion) = promotion {
+                san.push('=');
+                san.push_str(PieceType::piece_to_fen_enum(
+                    Some(promotion),
+                    Some(PieceColor::White),
+                ));
+            }
+        }
+
+        san.push_str(self.check_suffix(self.board_after_move(from, to, promotion), player_turn));
+
+        san
+    }
+
+    /// "#" or "+" when the board, after a move of `player_turn`, checkmates or checks the opponent
+    fn check_suffix(&self, board_after_move: Board, player_turn: PieceColor) -> &'static str {
+        // We look at the board from the opponent side to know if it is getting checked
+        let mut next_game_board = self.clone();
+        next_game_board.board = board_after_move;
+        next_game_board.flip_the_board();
+        let opponent = player_turn.opposite();
+
+        if next_game_board.is_checkmate(opponent) {
+            "#"
+        } else if next_game_board.is_getting_checked(next_game_board.board, opponent) {
+            "+"
+        } else {
+            ""
+        }
+    }
+
+    /// Add the promotion piece to the notation of the latest move once it is chosen
+    pub fn promote_last_san(&mut self, promotion: PieceType) {
+        let Some(last_move) = self.move_history.last() else {
+            return;
+        };
+        let suffix = self.check_suffix(self.board, last_move.piece_color);
+
+        if let Some(san) = self.san_history.last_mut() {
+            let notation = san.trim_end_matches(['+', '#']).to_string();
+            *san = format!(
+                "{notation}={}{suffix}",
+                PieceType::piece_to_fen_enum(Some(promotion), Some(PieceColor::White))
+            );
+        }
+    }
+
+    /// The board as it would be after a move, without touching the history
+    pub fn board_after_move(&self, from: Coord, to: Coord, promotion: Option<PieceType>) -> Board {
+        let mut board = self.board;
+
+        if let Some(rook) = self.castling_rook(from, to) {
+            // The king or the rook can stay on its cell, so both leave the row before coming back
+            let (king_to, rook_to) = self.castling_destinations(from, rook);
+            let (king_piece, rook_piece) = (board[&from], board[&rook]);
+            board[&from] = None;
+            board[&rook] = None;
+            board[&king_to] = king_piece;
+            board[&rook_to] = rook_piece;
+        } else {
+            if self.is_latest_move_en_passant(&from, &to) {
+                board[from.row as usize][to.col as usize] = None;
+            }
+            board[&to] = match (promotion, board[&from]) {
+                (Some(promotion), Some((_, piece_color))) => Some((promotion, piece_color)),
+                _ => board[&from],
+            };
+            board[&from] = None;
+        }
+
+        board
+    }
+
+    /// The castling rights of the FEN, written the X-FEN way as the Chess960 positions need it
+    /// K and Q stand for the outermost rook of the side, the file of the rook is written when another rook stands further
+    pub fn castling_fen(&self) -> String {
+        let mut board = self.clone();
+        board.orient_for(PieceColor::White);
+
+        let mut castling = String::new();
+        for color in [PieceColor::White, PieceColor::Black] {
+            let [king_side, queen_side] = board.castling_rooks(color);
+            for (rook, side_letter) in [(king_side, 'K'), (queen_side, 'Q')] {
+                let Some(rook) = rook else {
+                    continue;
+                };
+                let outer_cols = if side_letter == 'K' {
+                    rook.col + 1..8
+                } else {
+                    0..rook.col
+                };
+                let is_outermost = outer_cols.into_iter().all(|col| {
+                    board.board[rook.row as usize][col as usize] != Some((PieceType::Rook, color))
+                });
+                let letter = if is_outermost {
+                    side_letter
+                } else {
+                    (b'A' + rook.col) as char
+                };
+                castling.push(match color {
+                    PieceColor::White => letter,
+                    PieceColor::Black => letter.to_ascii_lowercase(),
+                });
+            }
+        }
+
+        if castling.is_empty() {
+            "-".to_string()
+        } else {
+            castling
+        }
+    }
+
+    /// The position in the Forsyth-Edwards Notation, whatever the orientation of the stored board
+    /// The castlings are kept while the king and the rook never moved, the en passant target is the cell behind a pawn that just moved two cells
+    pub fn position_fen(&self, player_turn: PieceColor) -> String {
+        let mut board = self.clone();
+        board.orient_for(PieceColor::White);
+
+        let fen = BoardEditor {
+            board: board.board,
+            player_turn,
+            castling_rights: [false; 4],
+            error: None,
+        }
+        .fen();
+        let fen = fen.strip_suffix(" - - 0 1").unwrap_or(&fen);
+
+        // The cells of the last move with the white pieces at the bottom, whoever played it
+        // before the first move the target is the one of the position the game started from
+        let en_passant = if self.move_history.is_empty() {
+            self.first_en_passant
+        } else {
+            self.history_position(self.move_history.len(), false)
+                .and_then(|(history_board, move_cells)| {
+                    let (from, to) = move_cells?;
+                    let is_pawn = matches!(history_board[&to], Some((PieceType::Pawn, _)));
+                    (is_pawn && from.row.abs_diff(to.row) == 2)
+                        .then(|| Coord::new((from.row + to.row) / 2, to.col))
+                })
+        }
+        .map(|target| format!("{}{}", col_to_letter(target.col), 8 - target.row))
+        .unwrap_or_else(|| "-".to_string());
+
+        let black_first = usize::from(self.first_player == PieceColor::Black);
+        let fullmove_number =
+            self.first_move_number as usize + (self.move_history.len() + black_first) / 2;
+
+        format!(
+            "{fen} {} {en_passant} {} {fullmove_number}",
+            self.castling_fen(),
+            self.consecutive_non_pawn_or_capture
+        )
+    }
+
+    /// The position to share as text: its FEN above the board drawn from white's side
+    pub fn ascii_diagram(&self, player_turn: PieceColor) -> String {
+        format!(
+            "{}\n\n{}\n",
+            self.position_fen(player_turn),
+            board_to_unicode_block(self, false)
+        )
+    }
+
     // Convert the history and game status to a FEN string
-    pub fn fen_position(&mut self, is_bot_starting: bool, player_turn: PieceColor) -> String {
+    pub fn fen_position(&mut self, is_bot_starting: bool) -> String {
         let mut result = String::new();
         let bot_color = if is_bot_starting {
             PieceColor::White
@@ -422,8 +1000,6 @@ impl GameBoard {
             PieceColor::Black
         };
 
-        let king_col = if bot_color == PieceColor::White { 4 } else { 3 };
-
         // We loop through the board and convert it to a FEN string
         for i in 0..8u8 {
             for j in 0..8u8 {
@@ -471,32 +1047,9 @@ impl GameBoard {
             " w"
         });
 
-        // We add the castles availabilities for black
-        if !self.did_piece_already_move((
-            Some(PieceType::King),
-            Some(player_turn),
-            Coord::new(7, king_col),
-        )) && !self.is_getting_checked(self.board, PieceColor::Black)
-        {
-            // king side black castle availability
-            if !self.did_piece_already_move((
-                Some(PieceType::Rook),
-                Some(player_turn),
-                Coord::new(7, 7),
-            )) {
-                result.push_str(" k");
-            }
-            // queen side black castle availability
-            if !self.did_piece_already_move((
-                Some(PieceType::Rook),
-                Some(player_turn),
-                Coord::new(7, 0),
-            )) {
-                result.push('q');
-            }
-        } else {
-            result.push_str(" -");
-        }
+        // We add the castles availabilities of both players
+        result.push(' ');
+        result.push_str(&self.castling_fen());
 
         // We check if the latest move is a pawn moving 2 cells, meaning the next move can be en passant
         if Pawn::did_pawn_move_two_cells(self.move_history.last()) {
@@ -524,4 +1077,163 @@ impl GameBoard {
 
         result
     }
+
+    /// Set up a board from a position in the Forsyth-Edwards Notation
+    /// The board is stored with the player to move at the bottom, as in a solo game
+    /// The en passant target is kept apart, as there is no move history to take it from
+    /// The castling rook of a file written right is the one of the file, the outermost rook of the side otherwise
+    pub fn from_fen(fen: &str) -> Result<GameBoard, String> {
+        let fields: Vec<&str> = fen.split_whitespace().collect();
+        if !(4..=6).contains(&fields.len()) {
+            return Err(format!(
+                "A FEN has 6 fields separated by spaces, {} were given",
+                fields.len()
+            ));
+        }
+
+        let rows: Vec<&str> = fields[0].split('/').collect();
+        if rows.len() != 8 {
+            return Err(format!(
+                "The board needs 8 ranks, {} were given",
+                rows.len()
+            ));
+        }
+        let mut board: Board = [[None; 8]; 8];
+        for (row, fen_row) in rows.iter().enumerate() {
+            let mut col = 0;
+            for letter in fen_row.chars() {
+                if let Some(empty_cells) = letter.to_digit(10).filter(|&n| (1..=8).contains(&n)) {
+                    col += empty_cells as usize;
+                    continue;
+                }
+                let piece_type = match letter.to_ascii_lowercase() {
+                    'p' => PieceType::Pawn,
+                    'n' => PieceType::Knight,
+                    'b' => PieceType::Bishop,
+                    'r' => PieceType::Rook,
+                    'q' => PieceType::Queen,
+                    'k' => PieceType::King,
+                    _ => return Err(format!("'{letter}' is not a piece")),
+                };
+                let color = if letter.is_ascii_uppercase() {
+                    PieceColor::White
+                } else {
+                    PieceColor::Black
+                };
+                if col < 8 {
+                    board[row][col] = Some((piece_type, color));
+                }
+                col += 1;
+            }
+            if col != 8 {
+                return Err(format!("The rank {} doesn't have 8 cells", 8 - row));
+            }
+        }
+
+        let player_turn = match fields[1] {
+            "w" => PieceColor::White,
+            "b" => PieceColor::Black,
+            turn => return Err(format!("'{turn}' is not a player, use w or b")),
+        };
+
+        // The castlings are written KQkq, or with the file of the rook as the Shredder-FEN and X-FEN do for Chess960
+        let mut castling_rights = [false; 4];
+        let mut rook_files: Vec<(usize, usize)> = vec![];
+        if fields[2] != "-" {
+            for letter in fields[2].chars() {
+                if let Some(index) = "KQkq".find(letter) {
+                    castling_rights[index] = true;
+                    continue;
+                }
+                let (color, row) = match letter {
+                    'A'..='H' => (PieceColor::White, 7),
+                    'a'..='h' => (PieceColor::Black, 0),
+                    _ => return Err(format!("'{letter}' is not a castling right")),
+                };
+                let col = (letter.to_ascii_lowercase() as u8 - b'a') as usize;
+                let king_col = board[row]
+                    .iter()
+                    .position(|&cell| cell == Some((PieceType::King, color)));
+                let index = match (color, king_col.is_some_and(|king_col| col > king_col)) {
+                    (PieceColor::White, true) => 0,
+                    (PieceColor::White, false) => 1,
+                    (PieceColor::Black, true) => 2,
+                    (PieceColor::Black, false) => 3,
+                };
+                if board[row][col] != Some((PieceType::Rook, color)) {
+                    return Err(format!("'{letter}' castling needs a rook on its file"));
+                }
+                castling_rights[index] = true;
+                rook_files.push((index, col));
+            }
+        }
+
+        // The target is the cell a pawn of the other player just went over, with the white pieces at the bottom
+        let en_passant = match fields[3].as_bytes() {
+            b"-" => None,
+            &[file @ b'a'..=b'h', rank @ (b'3' | b'6')] => {
+                Some(Coord::new(b'8' - rank, file - b'a'))
+            }
+            _ => return Err(format!("'{}' is not an en passant target", fields[3])),
+        };
+        if let Some(target) = en_passant {
+            let (target_row, pawn_row, start_row) = match player_turn {
+                PieceColor::White => (2, 3, 1),
+                PieceColor::Black => (5, 4, 6),
+            };
+            let col = target.col as usize;
+            let pawn = Some((PieceType::Pawn, player_turn.opposite()));
+            if target.row as usize != target_row
+                || board[pawn_row][col] != pawn
+                || board[target_row][col].is_some()
+                || board[start_row][col].is_some()
+            {
+                return Err(format!(
+                    "'{}' is not the cell a pawn just went over",
+                    fields[3]
+                ));
+            }
+        }
+
+        let halfmove_clock = match fields.get(4) {
+            Some(halfmove_clock) => halfmove_clock
+                .parse::<i32>()
+                .ok()
+                .filter(|&halfmove_clock| halfmove_clock >= 0)
+                .ok_or(format!("'{halfmove_clock}' is not a halfmove clock"))?,
+            None => 0,
+        };
+        let fullmove_number = match fields.get(5) {
+            Some(fullmove_number) => fullmove_number
+                .parse::<u32>()
+                .ok()
+                .filter(|&fullmove_number| fullmove_number > 0)
+                .ok_or(format!("'{fullmove_number}' is not a fullmove number"))?,
+            None => 1,
+        };
+
+        let editor = BoardEditor {
+            board,
+            player_turn,
+            castling_rights,
+            error: None,
+        };
+        editor.validate()?;
+        let mut game_board = editor.game_board();
+
+        // The rooks further than the one of a file castling moved, only the one on the file keeps the castling
+        for (index, rook_col) in rook_files {
+            let (_, color, row, is_king_side) = CASTLING_SIDES[index];
+            for col in editor.side_rooks(color, row, is_king_side) {
+                if (col > rook_col) == is_king_side && col != rook_col {
+                    game_board.moved_pieces.push(moved_rook(color, row, col));
+                }
+            }
+        }
+        game_board.set_consecutive_non_pawn_or_capture(halfmove_clock);
+        game_board.first_move_number = fullmove_number;
+        game_board.first_halfmove_clock = halfmove_clock;
+        game_board.first_en_passant = en_passant;
+        Ok(game_board)
+    }
 }
@@ -1,7 +1,10 @@
 pub mod board;
+pub mod board_editor;
 pub mod bot;
 pub mod coord;
+pub mod evaluation;
 pub mod game;
 pub mod game_board;
+pub mod openings;
 pub mod opponent;
 pub mod ui;
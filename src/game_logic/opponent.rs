@@ -1,11 +1,42 @@
+use crate::logging::log_protocol_message;
 use crate::pieces::{PieceColor, PieceMove};
 use log;
 use std::{
     io::{Read, Write},
-    net::TcpStream,
+    net::{TcpStream, ToSocketAddrs},
     panic,
+    time::{Duration, Instant},
 };
 
+/// Messages of a draw offer, sent by the player to move instead of a move and answered by the other one
+pub const DRAW_OFFER: &str = "offer";
+pub const DRAW_ACCEPT: &str = "agree";
+pub const DRAW_DECLINE: &str = "deny";
+
+/// Sent with the number of moves played by a player connecting again, the moves lost meanwhile are sent again
+pub const REJOIN_PREFIX: char = 'r';
+/// Sent with the number of moves played to ask the player who connected again for his last move
+pub const RESEND_PREFIX: char = 'k';
+
+/// Attempts to connect again before the game is given up, each one waiting twice as long as the previous one
+const RECONNECT_ATTEMPTS: u32 = 5;
+const RECONNECT_FIRST_DELAY: Duration = Duration::from_secs(1);
+/// Short enough for the interface to stay responsive during an attempt
+const RECONNECT_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// State of the connection to the game server
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Connection {
+    Connected,
+    /// The connection dropped during the game, the next attempt is made at the given time
+    Reconnecting {
+        attempts: u32,
+        next_attempt_at: Instant,
+    },
+    /// Every attempt failed, the game can't go on
+    Lost,
+}
+
 pub struct Opponent {
     // The stream to communicate with the engine
     pub stream: Option<TcpStream>,
@@ -15,6 +46,13 @@ pub struct Opponent {
     pub color: PieceColor,
     /// Is Game started
     pub game_started: bool,
+    /// The opponent offered a draw, the game waits for the player's answer
+    pub draw_offer_received: bool,
+    /// Number of moves played when the player last offered a draw, one offer per turn
+    pub draw_offered_at: Option<usize>,
+    /// Address of the game server, kept to connect again when the connection drops
+    pub addr: String,
+    pub connection: Connection,
 }
 
 // Custom Default implementation
@@ -25,6 +63,10 @@ impl Default for Opponent {
             opponent_will_move: false,
             color: PieceColor::Black,
             game_started: false,
+            draw_offer_received: false,
+            draw_offered_at: None,
+            addr: String::new(),
+            connection: Connection::Connected,
         }
     }
 }
@@ -36,6 +78,10 @@ impl Clone for Opponent {
             opponent_will_move: self.opponent_will_move,
             color: self.color,
             game_started: self.game_started,
+            draw_offer_received: self.draw_offer_received,
+            draw_offered_at: self.draw_offered_at,
+            addr: self.addr.clone(),
+            connection: self.connection,
         }
     }
 }
@@ -47,6 +93,10 @@ impl Opponent {
             opponent_will_move: self.opponent_will_move,
             color: self.color,
             game_started: self.game_started,
+            draw_offer_received: self.draw_offer_received,
+            draw_offered_at: self.draw_offered_at,
+            addr: self.addr.clone(),
+            connection: self.connection,
         }
     }
 
@@ -100,6 +150,10 @@ impl Opponent {
                 opponent_will_move,
                 color,
                 game_started: false,
+                draw_offer_received: false,
+                draw_offered_at: None,
+                addr,
+                connection: Connection::Connected,
             }
         } else {
             log::error!("Failed to connect after 5 attempts to {}", addr);
@@ -123,12 +177,33 @@ impl Opponent {
 
     pub fn send_end_game_to_server(&mut self) {
         if let Some(game_stream) = self.stream.as_mut() {
+            log_protocol_message("sent", "ended");
             if let Err(e) = game_stream.write_all("ended".as_bytes()) {
                 eprintln!("Failed to send end game: {}", e);
             }
         }
     }
 
+    /// Send one of the draw offer messages
+    pub fn send_draw_message(&mut self, message: &str) {
+        self.send_message(message);
+    }
+
+    /// Send the number of moves played after one of the prefixes resuming a game once connected again
+    pub fn send_resync_message(&mut self, prefix: char, moves_played: usize) {
+        self.send_message(&format!("{prefix}{moves_played}"));
+    }
+
+    fn send_message(&mut self, message: &str) {
+        if let Some(game_stream) = self.stream.as_mut() {
+            log_protocol_message("sent", message);
+            if let Err(e) = game_stream.write_all(message.as_bytes()) {
+                log::error!("Failed to send {}: {}", message, e);
+                self.connection_dropped();
+            }
+        }
+    }
+
     pub fn send_move_to_server(
         &mut self,
         move_to_send: &PieceMove,
@@ -146,8 +221,10 @@ impl Opponent {
                     None => "".to_string(),
                 }
             );
+            log_protocol_message("sent", &move_str);
             if let Err(e) = game_stream.write_all(move_str.as_bytes()) {
-                eprintln!("Failed to send move: {}", e);
+                log::error!("Failed to send move: {}", e);
+                self.connection_dropped();
             }
         }
     }
@@ -157,10 +234,13 @@ impl Opponent {
             let mut buffer = vec![0; 5];
             match game_stream.read(&mut buffer) {
                 Ok(bytes_read) => {
-                    if bytes_read == 0 {
+                    if bytes_read == 0 && self.game_started {
+                        log::error!("The game server closed the connection");
+                        self.connection_dropped();
                         return String::new();
                     }
                     let response = String::from_utf8_lossy(&buffer[..bytes_read]);
+                    log_protocol_message("received", &response);
                     if response.trim() == "ended" || response.trim() == "" {
                         log::error!("Game ended by the other opponent");
                         panic!("Game ended by the other opponent");
@@ -174,6 +254,7 @@ impl Opponent {
                 }
                 Err(e) => {
                     log::error!("Failed to read from stream: {}", e);
+                    self.connection_dropped();
                     String::new()
                 }
             }
@@ -181,12 +262,106 @@ impl Opponent {
             String::new()
         }
     }
+
+    /// Whether a message of the opponent is waiting, the keys are handled until it arrives
+    /// A dropped connection counts as a message, reading it starts connecting again
+    pub fn has_message(&self) -> bool {
+        let Some(game_stream) = self.stream.as_ref() else {
+            return false;
+        };
+        if game_stream.set_nonblocking(true).is_err() {
+            return true;
+        }
+        let mut buffer = [0; 1];
+        let has_message = !matches!(
+            game_stream.peek(&mut buffer),
+            Err(e) if e.kind() == std::io::ErrorKind::WouldBlock
+        );
+        let _ = game_stream.set_nonblocking(false);
+        has_message
+    }
+
+    /// Read a message without waiting when there is none, used while the player is to move
+    pub fn poll_stream(&mut self) -> String {
+        let Some(game_stream) = self.stream.as_ref() else {
+            return String::new();
+        };
+        if let Err(e) = game_stream.set_nonblocking(true) {
+            log::error!("Failed to poll the stream: {}", e);
+            return String::new();
+        }
+        let message = self.read_stream();
+        if let Some(game_stream) = self.stream.as_ref() {
+            let _ = game_stream.set_nonblocking(false);
+        }
+        message
+    }
+
+    /// Start connecting again when the connection drops during a game
+    /// Before the game starts there is nothing to resume, the connection stays as it is
+    fn connection_dropped(&mut self) {
+        if !self.game_started || self.connection != Connection::Connected {
+            return;
+        }
+        log::warn!("The connection to {} dropped, connecting again", self.addr);
+        self.stream = None;
+        self.connection = Connection::Reconnecting {
+            attempts: 0,
+            next_attempt_at: Instant::now(),
+        };
+    }
+
+    /// Make the next attempt to connect again once its time came, without blocking the interface for long
+    /// Once connected the number of moves played is sent so the opponent can send again the moves lost meanwhile
+    pub fn try_reconnect(&mut self, moves_played: usize) {
+        let Connection::Reconnecting {
+            attempts,
+            next_attempt_at,
+        } = self.connection
+        else {
+            return;
+        };
+        if Instant::now() < next_attempt_at {
+            return;
+        }
+
+        let stream = self
+            .addr
+            .to_socket_addrs()
+            .ok()
+            .and_then(|mut addrs| addrs.next())
+            .and_then(|addr| TcpStream::connect_timeout(&addr, RECONNECT_TIMEOUT).ok());
+        match stream {
+            Some(stream) => {
+                log::info!("Connected again to {}", self.addr);
+                self.stream = Some(stream);
+                self.connection = Connection::Connected;
+                self.send_resync_message(REJOIN_PREFIX, moves_played);
+            }
+            None if attempts + 1 >= RECONNECT_ATTEMPTS => {
+                log::error!(
+                    "Failed to connect again to {} after {} attempts",
+                    self.addr,
+                    RECONNECT_ATTEMPTS
+                );
+                self.connection = Connection::Lost;
+            }
+            None => {
+                log::warn!("Failed to connect again to {}", self.addr);
+                self.connection = Connection::Reconnecting {
+                    attempts: attempts + 1,
+                    next_attempt_at: Instant::now() + RECONNECT_FIRST_DELAY * 2u32.pow(attempts),
+                };
+            }
+        }
+    }
 }
 
 pub fn get_color_from_stream(mut stream: &TcpStream) -> PieceColor {
     let mut buffer = [0; 5];
     let bytes_read = stream.read(&mut buffer).unwrap(); // Number of bytes read
     let color = String::from_utf8_lossy(&buffer[..bytes_read]).to_string();
+    log_protocol_message("received", &color);
 
     match color.as_str() {
         "w" => PieceColor::White,
@@ -199,6 +374,7 @@ pub fn wait_for_game_start(mut stream: &TcpStream) {
     let mut buffer = [0; 5];
     let bytes_read = stream.read(&mut buffer).unwrap(); // Number of bytes read
     let response = String::from_utf8_lossy(&buffer[..bytes_read]).to_string();
+    log_protocol_message("received", &response);
 
     match response.as_str() {
         "s" => (),
@@ -1,18 +1,47 @@
-use super::{coord::Coord, game::Game};
+use super::{
+    coord::Coord,
+    game::{Game, GameState},
+    game_board::GameBoard,
+};
 use crate::{
-    constants::{DisplayMode, BLACK, UNDEFINED_POSITION, WHITE},
+    constants::{
+        CoordinatesDisplay, DisplayMode, HistoryNotation, BLACK, FLIP_ANIMATION_FRAMES,
+        MOVE_ANIMATION_FRAMES, UNDEFINED_POSITION, WHITE,
+    },
     pieces::{PieceColor, PieceType},
     ui::{main_ui::render_cell, prompt::Prompt},
-    utils::{convert_position_into_notation, get_cell_paragraph, invert_position},
+    utils::{col_to_letter, convert_position_into_notation, get_cell_paragraph, invert_position},
 };
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style, Stylize},
     text::{Line, Span},
-    widgets::{Block, BorderType, Borders, Padding, Paragraph},
+    widgets::{Block, BorderType, Borders, Padding, Paragraph, Wrap},
     Frame,
 };
 
+/// A piece sliding to its destination, the cells are given with the white pieces at the bottom
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MoveAnimation {
+    pub from: Coord,
+    pub to: Coord,
+    pub frame: u8,
+}
+
+impl MoveAnimation {
+    /// The cell the piece is drawn on for the current frame
+    pub fn current_cell(&self) -> Coord {
+        let interpolate = |from: u8, to: u8| {
+            let distance = to as i32 - from as i32;
+            (from as i32 + distance * self.frame as i32 / MOVE_ANIMATION_FRAMES as i32) as u8
+        };
+        Coord::new(
+            interpolate(self.from.row, self.to.row),
+            interpolate(self.from.col, self.to.col),
+        )
+    }
+}
+
 #[derive(Clone)]
 pub struct UI {
     /// The cursor position
@@ -35,8 +64,40 @@ pub struct UI {
     pub mouse_used: bool,
     /// The skin of the game
     pub display_mode: DisplayMode,
+    /// How the moves are written in the history panel
+    pub history_notation: HistoryNotation,
+    /// Which file and rank labels are drawn around the board
+    pub coordinates_display: CoordinatesDisplay,
+    /// Show the captured pieces panels, the history takes their space otherwise
+    pub show_captured: bool,
+    /// Write the files and the ranks in the cells along the bottom and the left of the board
+    pub show_coordinates: bool,
     // The prompt for the player
     pub prompt: Prompt,
+    /// Show the coordinates inside the empty cells (debug grid)
+    pub debug_coords: bool,
+    /// Tint the cells the last moved piece can reach from where it stands
+    pub show_last_move_reach: bool,
+    /// Always draw the board with the white pieces at the bottom
+    pub white_perspective: bool,
+    /// The player turned the drawn board over, only the view changes and never the side played
+    pub view_flipped: bool,
+    /// Short message shown in the status line until the next key press
+    pub status_message: Option<String>,
+    /// Slide the pieces to their destination instead of moving them instantly
+    pub move_animation: bool,
+    /// The move being animated
+    pub animation: Option<MoveAnimation>,
+    /// Turn the board over a few frames when it flips instead of at once
+    pub flip_animation: bool,
+    /// Frame of the running flip, the rows not reached yet are drawn in the previous orientation
+    pub flip_frame: Option<u8>,
+    /// Number of moves of the position shown from the history once the game is over, the live board otherwise
+    pub history_index: Option<usize>,
+    /// The piece picked during the opponent's turn, waiting for the cell of the premove
+    pub premove_source: Option<Coord>,
+    /// Move queued during the opponent's turn, played once the opponent moved if it is legal then
+    pub premove: Option<(Coord, Coord)>,
 }
 
 impl Default for UI {
@@ -53,7 +114,23 @@ impl Default for UI {
             height: 0,
             mouse_used: false,
             display_mode: DisplayMode::DEFAULT,
+            history_notation: HistoryNotation::Coordinate,
+            coordinates_display: CoordinatesDisplay::Always,
+            show_captured: true,
+            show_coordinates: true,
             prompt: Prompt::new(),
+            debug_coords: false,
+            show_last_move_reach: false,
+            white_perspective: false,
+            view_flipped: false,
+            status_message: None,
+            move_animation: false,
+            animation: None,
+            flip_animation: false,
+            flip_frame: None,
+            history_index: None,
+            premove_source: None,
+            premove: None,
         }
     }
 }
@@ -70,6 +147,85 @@ impl UI {
         self.width = 0;
         self.height = 0;
         self.mouse_used = false;
+        self.prompt = Prompt::new();
+        self.view_flipped = false;
+        self.status_message = None;
+        self.animation = None;
+        self.flip_frame = None;
+        self.history_index = None;
+        self.premove_source = None;
+        self.premove = None;
+    }
+
+    /// Start sliding the piece of a move, a running animation is dropped so its piece snaps in place
+    /// `is_flipped` tells if the given cells are the ones of a board with the black pieces at the bottom
+    pub fn start_move_animation(&mut self, from: Coord, to: Coord, is_flipped: bool) {
+        if !self.move_animation {
+            return;
+        }
+        let (from, to) = if is_flipped {
+            (invert_position(&from), invert_position(&to))
+        } else {
+            (from, to)
+        };
+        self.animation = Some(MoveAnimation { from, to, frame: 0 });
+    }
+
+    /// Start drawing the board flip row by row, when the flip turns the drawn board
+    /// The flip replaces the slide of the move that caused it, its piece is already in place
+    pub fn start_flip_animation(&mut self) {
+        if !self.flip_animation || self.white_perspective {
+            return;
+        }
+        self.animation = None;
+        self.flip_frame = Some(0);
+    }
+
+    /// Whether a drawn row still shows the board as it was before the flip
+    pub fn is_row_before_flip(&self, drawn_row: u8) -> bool {
+        self.flip_frame
+            .is_some_and(|frame| drawn_row >= frame * 8 / FLIP_ANIMATION_FRAMES)
+    }
+
+    /// Move the animated piece or the flip one frame further
+    pub fn tick_animation(&mut self) {
+        if let Some(animation) = self.animation.as_mut() {
+            animation.frame += 1;
+            if animation.frame >= MOVE_ANIMATION_FRAMES {
+                self.animation = None;
+            }
+        }
+        if let Some(frame) = self.flip_frame.as_mut() {
+            *frame += 1;
+            if *frame >= FLIP_ANIMATION_FRAMES {
+                self.flip_frame = None;
+            }
+        }
+    }
+
+    /// Show or hide the captured pieces panels
+    pub fn toggle_show_captured(&mut self) {
+        self.show_captured = !self.show_captured;
+    }
+
+    /// Show or hide the cells the last moved piece can reach
+    pub fn toggle_last_move_reach(&mut self) {
+        self.show_last_move_reach = !self.show_last_move_reach;
+    }
+
+    /// Show or hide the files and the ranks around the board
+    pub fn toggle_show_coordinates(&mut self) {
+        self.show_coordinates = !self.show_coordinates;
+    }
+
+    /// Turn the drawn board over, the keys and the mouse follow the drawn board
+    pub fn toggle_view_flip(&mut self) {
+        self.view_flipped = !self.view_flipped;
+    }
+
+    /// Show or hide the coordinates inside the empty cells
+    pub fn toggle_debug_coords(&mut self) {
+        self.debug_coords = !self.debug_coords;
     }
 
     /// Check if a cell has been selected
@@ -161,6 +317,13 @@ impl UI {
         self.promotion_cursor = (self.promotion_cursor + 1) % 4;
     }
 
+    /// Keep the cursor and the selection on the same squares when the board is turned upside down
+    pub fn flip_coordinates(&mut self) {
+        self.cursor_coordinates = self.cursor_coordinates.flipped();
+        self.selected_coordinates = self.selected_coordinates.flipped();
+        self.old_cursor_position = self.old_cursor_position.flipped();
+    }
+
     /// Method to unselect a cell
     pub fn unselect_cell(&mut self) {
         if self.is_cell_selected() {
@@ -170,6 +333,81 @@ impl UI {
         }
     }
 
+    /// The notation of the move the player is about to play, empty when the cursor is not on an available cell
+    pub fn hovered_move_san(&self, game: &Game) -> String {
+        if !self.is_cell_selected() {
+            return String::new();
+        }
+        let authorized_positions = game
+            .game_board
+            .get_authorized_positions(game.player_turn, self.selected_coordinates);
+        if !authorized_positions.contains(&self.cursor_coordinates) {
+            return String::new();
+        }
+        game.game_board.get_san(
+            self.selected_coordinates,
+            self.cursor_coordinates,
+            game.player_turn,
+            None,
+        )
+    }
+
+    /// The selected piece and its cell written as in the notation (e.g "Ng1", "e2" for a pawn), empty when nothing is selected
+    pub fn selected_piece_label(&self, game: &Game) -> String {
+        if !self.is_cell_selected() {
+            return String::new();
+        }
+        let Some(piece_type) = game.game_board.get_piece_type(&self.selected_coordinates) else {
+            return String::new();
+        };
+
+        let piece_letter = match piece_type {
+            PieceType::Pawn => "",
+            _ => PieceType::piece_to_fen_enum(Some(piece_type), Some(PieceColor::White)),
+        };
+        format!(
+            "{}{}",
+            piece_letter,
+            game.game_board.coord_to_square(&self.selected_coordinates)
+        )
+    }
+
+    /// Method to render the status line under the board
+    pub fn status_render(&self, area: Rect, frame: &mut Frame, game: &Game) {
+        let selected = self.selected_piece_label(game);
+        let san = self.hovered_move_san(game);
+        let status_line = if !selected.is_empty() {
+            let mut spans = vec![
+                Span::raw("Selected: "),
+                Span::styled(selected, Style::default().add_modifier(Modifier::BOLD)),
+            ];
+            if !san.is_empty() {
+                spans.push(Span::raw("  Move: "));
+                spans.push(Span::styled(
+                    san,
+                    Style::default().add_modifier(Modifier::BOLD),
+                ));
+            }
+            Line::from(spans)
+        } else if let Some(status_message) = &self.status_message {
+            Line::from(status_message.as_str())
+        } else if !(game.game_board.auto_claim_draws && game.game_board.auto_claim_repetition)
+            && game.game_state == GameState::Playing
+            && game.game_board.is_draw_claimable()
+        {
+            Line::from(vec![
+                Span::raw("You may claim a draw, press "),
+                Span::styled("x", Style::default().add_modifier(Modifier::BOLD)),
+            ])
+        } else {
+            return;
+        };
+
+        let status_paragraph = Paragraph::new(status_line).alignment(Alignment::Center);
+
+        frame.render_widget(status_paragraph, area);
+    }
+
     /// Method to render the right panel history
     pub fn history_render(&self, area: Rect, frame: &mut Frame, game: &Game) {
         // We write the history board on the side
@@ -182,56 +420,92 @@ impl UI {
 
         let mut lines: Vec<Line> = vec![];
 
-        for i in (0..game.game_board.move_history.len()).step_by(2) {
-            let piece_type_from = game.game_board.move_history[i].piece_type;
-
-            let utf_icon_white =
-                PieceType::piece_to_utf_enum(&piece_type_from, Some(PieceColor::White));
-            let move_white = convert_position_into_notation(&format!(
-                "{}{}{}{}",
-                game.game_board.move_history[i].from.row,
-                game.game_board.move_history[i].from.col,
-                game.game_board.move_history[i].to.row,
-                game.game_board.move_history[i].to.col
-            ));
-
-            let mut utf_icon_black = "   ";
-            let mut move_black: String = "   ".to_string();
-
-            // If there is something for black
-            if i + 1 < game.game_board.move_history.len() {
-                let piece_type_to = game.game_board.move_history[i + 1].piece_type;
-                let black_move = &game.game_board.move_history[i + 1];
-
-                // Invert black moves if not playing against bot
-                let (from, to) = if game.bot.is_none() {
-                    (
-                        invert_position(&black_move.from),
-                        invert_position(&black_move.to),
-                    )
-                } else {
-                    (black_move.from, black_move.to)
-                };
+        if let Some(opening) = game.game_board.opening() {
+            lines.push(Line::from(Span::styled(
+                format!("{} {}", opening.eco, opening.name),
+                Style::default().add_modifier(Modifier::ITALIC),
+            )));
+            lines.push(Line::raw(""));
+        }
 
-                move_black = convert_position_into_notation(&format!(
-                    "{}{}{}{}",
-                    from.row, from.col, to.row, to.col
-                ));
-                utf_icon_black =
-                    PieceType::piece_to_utf_enum(&piece_type_to, Some(PieceColor::Black));
+        let game_board = &game.game_board;
+        if self.history_notation == HistoryNotation::San {
+            let san = |index: Option<usize>, missing| {
+                index
+                    .and_then(|i| game_board.san_history.get(i))
+                    .map_or(missing, String::as_str)
+            };
+            for (number, white_index, black_index) in history_lines(game_board) {
+                let white_move = san(white_index, "...");
+                let black_move = san(black_index, "");
+                lines.push(Line::from(vec![
+                    Span::raw(format!("{number}.  ")),     // line number
+                    Span::raw(format!("{white_move:<8}")), // white move
+                    Span::raw(format!("{black_move:<8}")), // black move
+                ]));
             }
+        } else {
+            for (number, white_index, black_index) in history_lines(game_board) {
+                let mut utf_icon_white = "   ";
+                let mut move_white = "...".to_string();
+
+                if let Some(i) = white_index {
+                    let white_move = &game_board.move_history[i];
+                    utf_icon_white = PieceType::piece_to_utf_enum(
+                        &white_move.piece_type,
+                        Some(PieceColor::White),
+                    );
+                    move_white = convert_position_into_notation(&format!(
+                        "{}{}{}{}",
+                        white_move.from.row,
+                        white_move.from.col,
+                        white_move.to.row,
+                        white_move.to.col
+                    ));
+                }
+
+                let mut utf_icon_black = "   ";
+                let mut move_black: String = "   ".to_string();
+
+                // If there is something for black
+                if let Some(i) = black_index {
+                    let black_move = &game_board.move_history[i];
+
+                    // Invert black moves if not playing against bot
+                    let (from, to) = if game.bot.is_none() {
+                        (
+                            invert_position(&black_move.from),
+                            invert_position(&black_move.to),
+                        )
+                    } else {
+                        (black_move.from, black_move.to)
+                    };
+
+                    move_black = convert_position_into_notation(&format!(
+                        "{}{}{}{}",
+                        from.row, from.col, to.row, to.col
+                    ));
+                    utf_icon_black = PieceType::piece_to_utf_enum(
+                        &black_move.piece_type,
+                        Some(PieceColor::Black),
+                    );
+                }
 
-            lines.push(Line::from(vec![
-                Span::raw(format!("{}.  ", i / 2 + 1)), // line number
-                Span::styled(format!("{utf_icon_white} "), Style::default().fg(WHITE)), // white symbol
-                Span::raw(move_white.to_string()), // white move
-                Span::raw("     "),                // separator
-                Span::styled(format!("{utf_icon_black} "), Style::default().fg(WHITE)), // black symbol
-                Span::raw(move_black.to_string()), // black move
-            ]));
+                lines.push(Line::from(vec![
+                    Span::raw(format!("{number}.  ")), // line number
+                    Span::styled(format!("{utf_icon_white} "), Style::default().fg(WHITE)), // white symbol
+                    Span::raw(move_white), // white move
+                    Span::raw("     "),    // separator
+                    Span::styled(format!("{utf_icon_black} "), Style::default().fg(WHITE)), // black symbol
+                    Span::raw(move_black), // black move
+                ]));
+            }
         }
 
-        let history_paragraph = Paragraph::new(lines).alignment(Alignment::Center);
+        // The long opening names go on several lines
+        let history_paragraph = Paragraph::new(lines)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true });
 
         let height = area.height;
 
@@ -253,6 +527,8 @@ impl UI {
         area: Rect,
         frame: &mut Frame,
         white_taken_pieces: &[PieceType],
+        material_advantage: i32,
+        help_key: char,
     ) {
         let white_block = Block::default()
             .title("White material")
@@ -267,6 +543,10 @@ impl UI {
 
             pieces.push_str(&format!("{utf_icon_white} "));
         }
+        // The points white is ahead by, written only in the panel of the side ahead
+        if material_advantage > 0 {
+            pieces.push_str(&format!("+{material_advantage}"));
+        }
         let white_material_paragraph = Paragraph::new(pieces)
             .alignment(Alignment::Center)
             .add_modifier(Modifier::BOLD);
@@ -283,7 +563,8 @@ impl UI {
             white_block.inner(right_panel_layout[0]),
         );
         // Bottom paragraph help text
-        let text = vec![Line::from("Press ? for help").alignment(Alignment::Center)];
+        let text =
+            vec![Line::from(format!("Press {help_key} for help")).alignment(Alignment::Center)];
 
         let help_paragraph = Paragraph::new(text)
             .block(Block::new())
@@ -297,6 +578,7 @@ impl UI {
         area: Rect,
         frame: &mut Frame,
         black_taken_pieces: &Vec<PieceType>,
+        material_advantage: i32,
     ) {
         let black_block = Block::default()
             .title("Black material")
@@ -311,6 +593,9 @@ impl UI {
 
             pieces.push_str(&format!("{utf_icon_black} "));
         }
+        if material_advantage < 0 {
+            pieces.push_str(&format!("+{}", -material_advantage));
+        }
 
         let black_material_paragraph = Paragraph::new(pieces)
             .alignment(Alignment::Center)
@@ -363,6 +648,39 @@ impl UI {
             )
             .split(area);
 
+        // When the king is checked we show where it can escape, unless a piece is already selected
+        let king_escape_positions = if self.is_cell_selected() {
+            vec![]
+        } else {
+            game.game_board.get_king_escape_positions(game.player_turn)
+        };
+
+        let is_view_rotated = game.is_view_rotated();
+
+        // The mated king is marked on the live board only, not on the positions replayed from the history
+        let mated_king_cell = game
+            .mated_king_cell()
+            .filter(|_| self.history_index.is_none());
+
+        let last_move_reach = if self.show_last_move_reach {
+            game.last_moved_piece_reach()
+        } else {
+            vec![]
+        };
+
+        // Cells of the stored board where the animated piece is drawn and where it is going
+        let animation_cells = self.animation.map(|animation| {
+            if game.game_board.is_flipped {
+                (
+                    invert_position(&animation.current_cell()),
+                    invert_position(&animation.to),
+                )
+            } else {
+                (animation.current_cell(), animation.to)
+            }
+        });
+        let mut animation_square = None;
+
         // For each line we set 8 layout
         for i in 0..8u8 {
             let lines = Layout::default()
@@ -384,13 +702,32 @@ impl UI {
                 )
                 .split(columns[i as usize + 1]);
             for j in 0..8u8 {
+                let square = lines[j as usize + 1];
+                let (drawn_row, drawn_col) = (i, j);
+                // When the view is rotated, this square shows the opposite cell of the stored board
+                // The rows the flip didn't reach yet are rotated back to the previous orientation
+                let (i, j) = if is_view_rotated != self.is_row_before_flip(i) {
+                    (7 - i, 7 - j)
+                } else {
+                    (i, j)
+                };
+
                 // Color of the cell to draw the board
                 let cell_color: Color = if (i + j) % 2 == 0 { WHITE } else { BLACK };
 
                 let last_move;
                 let mut last_move_from = Coord::undefined();
                 let mut last_move_to = Coord::undefined();
-                if !game.game_board.move_history.is_empty() {
+                if let Some(index) = self.history_index {
+                    // The position from the history shows the move leading to it
+                    if let Some((_, Some((from, to)))) = game
+                        .game_board
+                        .history_position(index, game.game_board.is_flipped)
+                    {
+                        last_move_from = from;
+                        last_move_to = to;
+                    }
+                } else if !game.game_board.move_history.is_empty() {
                     last_move = game.game_board.move_history.last();
                     if game.bot.is_some()
                         && !game.bot.as_ref().is_some_and(|bot| bot.is_bot_starting)
@@ -437,12 +774,15 @@ impl UI {
                     }
                 }
 
-                let square = lines[j as usize + 1];
                 // Here we have all the possibilities for a cell:
                 // - selected cell: green
                 // - cursor cell: blue
                 // - available move cell: grey
+                // - cell reached by the last moved piece: red
+                // - mated king cell: red
                 // - checked king cell: magenta
+                // - checked king escape cell: yellow
+                // - premove cells: cyan
                 // - last move cell: green
                 // - default cell: white or black
                 // Draw the cell blue if this is the current cursor cell
@@ -452,10 +792,15 @@ impl UI {
                 {
                     render_cell(frame, square, Color::LightBlue, None);
                 }
+                // Draw the cell red if the king is mated, without blinking as the game is over
+                else if mated_king_cell == Some(Coord::new(i, j)) {
+                    render_cell(frame, square, Color::Red, None);
+                }
                 // Draw the cell magenta if the king is getting checked
-                else if game
-                    .game_board
-                    .is_getting_checked(game.game_board.board, game.player_turn)
+                else if game.game_state != GameState::Checkmate
+                    && game
+                        .game_board
+                        .is_getting_checked(game.game_board.board, game.player_turn)
                     && Coord::new(i, j)
                         == game
                             .game_board
@@ -463,6 +808,18 @@ impl UI {
                 {
                     render_cell(frame, square, Color::Magenta, Some(Modifier::SLOW_BLINK));
                 }
+                // Draw the cell yellow if the checked king can escape there
+                else if is_cell_in_positions(&king_escape_positions, i, j) {
+                    render_cell(frame, square, Color::LightYellow, None);
+                }
+                // Draw the cells of the premove cyan, they are played once the opponent moved
+                else if self.premove_source == Some(Coord::new(i, j))
+                    || self.premove.is_some_and(|(from, to)| {
+                        Coord::new(i, j) == from || Coord::new(i, j) == to
+                    })
+                {
+                    render_cell(frame, square, Color::LightCyan, None);
+                }
                 // Draw the cell green if this is the selected cell or if the cell is part of the last move
                 else if (i == self.selected_coordinates.row && j == self.selected_coordinates.col)
                     || (last_move_from == Coord::new(i, j) // If the last move from
@@ -473,6 +830,8 @@ impl UI {
                     render_cell(frame, square, Color::LightGreen, None);
                 } else if is_cell_in_positions(&positions, i, j) {
                     render_cell(frame, square, Color::Rgb(100, 100, 100), None);
+                } else if is_cell_in_positions(&last_move_reach, i, j) {
+                    render_cell(frame, square, Color::LightRed, None);
                 }
                 // else as a last resort we draw the cell with the default color either white or black
                 else {
@@ -490,10 +849,84 @@ impl UI {
 
                 // Get piece and color
                 let coord = Coord::new(i, j);
+                if let Some((current_cell, destination)) = animation_cells {
+                    if coord == current_cell {
+                        animation_square = Some(square);
+                    }
+                    // The moved piece is drawn on top of the board until it arrives
+                    if coord == destination {
+                        continue;
+                    }
+                }
                 let paragraph = get_cell_paragraph(game, &coord, square);
 
                 frame.render_widget(paragraph, square);
+
+                // The labels are read from the drawn board, the stored one has black at the bottom once flipped
+                // They take the right corners, the top left one is the marker of the selected cell
+                // A cell of a single line has no room for both the rank and the file
+                if self.show_coordinates && square.width > 0 && square.height > 1 {
+                    let label_color = match (self.display_mode, cell_color) {
+                        (DisplayMode::DEFAULT, WHITE) => BLACK,
+                        (DisplayMode::DEFAULT, _) => WHITE,
+                        (DisplayMode::ASCII, WHITE) => Color::Black,
+                        (DisplayMode::ASCII, _) => Color::White,
+                    };
+                    let (rank, file) = if game.game_board.is_flipped {
+                        (i + 1, 7 - j)
+                    } else {
+                        (8 - i, j)
+                    };
+                    if drawn_col == 0 {
+                        let rank_area = Rect::new(square.x + square.width - 1, square.y, 1, 1);
+                        frame.render_widget(
+                            Paragraph::new(rank.to_string()).fg(label_color),
+                            rank_area,
+                        );
+                    }
+                    if drawn_row == 7 {
+                        let file_area = Rect::new(
+                            square.x + square.width - 1,
+                            square.y + square.height - 1,
+                            1,
+                            1,
+                        );
+                        frame.render_widget(
+                            Paragraph::new(col_to_letter(file)).fg(label_color),
+                            file_area,
+                        );
+                    }
+                }
+
+                // Mark the corner of the selected cell, the green alone is easy to lose
+                if coord == self.selected_coordinates && square.width > 0 && square.height > 0 {
+                    let marker_area = Rect::new(square.x, square.y, 1, 1);
+                    frame.render_widget(Paragraph::new("◆").fg(Color::Black).bold(), marker_area);
+                }
             }
         }
+
+        if let (Some((_, destination)), Some(square)) = (animation_cells, animation_square) {
+            let paragraph = get_cell_paragraph(game, &destination, square);
+            frame.render_widget(paragraph, square);
+        }
     }
 }
+
+/// The lines of the history: the move number with the indexes of white's and black's moves in the history
+/// A position set up with black to move starts with black's move, white's one is left empty
+fn history_lines(game_board: &GameBoard) -> Vec<(u32, Option<usize>, Option<usize>)> {
+    let black_first = usize::from(game_board.first_player == PieceColor::Black);
+    let move_count = game_board.move_history.len();
+    (0..(move_count + black_first).div_ceil(2))
+        .map(|line| {
+            let white_index = (2 * line).checked_sub(black_first);
+            let black_index = Some(2 * line + 1 - black_first).filter(|&i| i < move_count);
+            (
+                game_board.first_move_number + line as u32,
+                white_index,
+                black_index,
+            )
+        })
+        .collect()
+}
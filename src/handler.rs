@@ -1,6 +1,7 @@
 use crate::constants::Popups;
 use crate::game_logic::coord::Coord;
 use crate::game_logic::game::GameState;
+use crate::utils::invert_position;
 use crate::{
     app::{App, AppResult},
     constants::Pages,
@@ -15,6 +16,8 @@ pub fn handle_key_events(key_event: KeyEvent, app: &mut App) -> AppResult<()> {
         // crossterm on Windows sends Release and Repeat events as well, which we ignore.
         return Ok(());
     }
+    // The status message is only shown until the next key press
+    app.game.ui.status_message = None;
     if app.game.ui.mouse_used {
         app.game.ui.mouse_used = false;
         if app.game.ui.selected_coordinates != Coord::undefined() {
@@ -26,43 +29,136 @@ pub fn handle_key_events(key_event: KeyEvent, app: &mut App) -> AppResult<()> {
         }
     }
 
+    // The help is toggled by the same key on every page, before a page can use the key for something else
+    // It doesn't replace the other popups, and the prompt keeps every typed character
+    if key_event.code == KeyCode::Char(app.help_key)
+        && (app.current_popup.is_none() || app.current_popup == Some(Popups::Help))
+    {
+        app.toggle_help_popup();
+        return Ok(());
+    }
+
+    // A draw offer has to be answered before the game goes on
+    if app.current_popup == Some(Popups::DrawOffer) {
+        match key_event.code {
+            KeyCode::Enter | KeyCode::Char('y') => app.game.answer_draw_offer(true),
+            KeyCode::Esc | KeyCode::Char('n') => app.game.answer_draw_offer(false),
+            _ => return Ok(()),
+        }
+        app.current_popup = None;
+        return Ok(());
+    }
+
+    // The repetition draw is claimed or the game goes on
+    if app.current_popup == Some(Popups::ClaimDraw) {
+        match key_event.code {
+            KeyCode::Enter | KeyCode::Char('y') => {
+                app.game.claim_draw();
+            }
+            KeyCode::Esc | KeyCode::Char('n') => {}
+            _ => return Ok(()),
+        }
+        app.current_popup = None;
+        return Ok(());
+    }
+
+    // The game waits for the connection to come back, it can only be left
+    if matches!(
+        app.current_popup,
+        Some(Popups::Reconnecting | Popups::ConnectionLost)
+    ) {
+        match key_event.code {
+            KeyCode::Esc => {
+                go_back_home(app);
+                return Ok(());
+            }
+            KeyCode::Enter | KeyCode::Char('q') | KeyCode::Char('c') => {}
+            _ => return Ok(()),
+        }
+    }
+
+    // The board editor keys edit the position, the other ones work as in a game
+    if app.current_page == Pages::BoardEditor
+        && app.current_popup.is_none()
+        && handle_board_editor_key(key_event.code, app)
+    {
+        return Ok(());
+    }
+
+    // The configured keys are handled as the default ones, the control shortcuts are never bound
+    let key_code = if key_event.modifiers.contains(KeyModifiers::CONTROL) {
+        key_event.code
+    } else {
+        app.keybindings.to_default_key(key_event.code)
+    };
+
+    // Once the game is over the arrows replay its positions
+    if matches!(
+        app.current_page,
+        Pages::Solo | Pages::Bot | Pages::Multiplayer
+    ) && app.current_popup.is_none()
+        && app.game.is_game_over()
+        && handle_history_key(key_code, app)
+    {
+        return Ok(());
+    }
+
     if app.current_popup == Some(Popups::EnterHostIP) {
         if key_event.kind == KeyEventKind::Press {
             match key_event.code {
                 KeyCode::Enter => {
                     app.game.ui.prompt.submit_message();
                     if app.current_page == Pages::Multiplayer {
-                        app.host_ip = Some(app.game.ui.prompt.message.clone());
+                        let message = app.game.ui.prompt.message.clone();
+                        // Without address the highlighted game of the local network is joined
+                        let discovered_host = app
+                            .host_discovery
+                            .as_ref()
+                            .and_then(|host_discovery| host_discovery.selected())
+                            .filter(|_| message.trim().is_empty())
+                            .map(|host| host.addr.to_string());
+                        app.host_ip = Some(discovered_host.unwrap_or(message));
+                        app.host_discovery = None;
                     }
                     app.current_popup = None;
                 }
+                KeyCode::Up => {
+                    if let Some(host_discovery) = app.host_discovery.as_mut() {
+                        host_discovery.cursor_up();
+                    }
+                }
+                KeyCode::Down => {
+                    if let Some(host_discovery) = app.host_discovery.as_mut() {
+                        host_discovery.cursor_down();
+                    }
+                }
                 KeyCode::Char(to_insert) => app.game.ui.prompt.enter_char(to_insert),
                 KeyCode::Backspace => app.game.ui.prompt.delete_char(),
                 KeyCode::Left => app.game.ui.prompt.move_cursor_left(),
                 KeyCode::Right => app.game.ui.prompt.move_cursor_right(),
                 KeyCode::Esc => {
                     app.current_popup = None;
+                    app.host_discovery = None;
                     if app.current_page == Pages::Multiplayer {
                         app.hosting = None;
                         app.selected_color = None;
                         app.menu_cursor = 0;
                     }
                     app.current_page = Pages::Home;
+                    app.restore_display_mode();
                 }
                 _ => {}
             }
         }
     } else {
-        match key_event.code {
+        match key_code {
             // Exit application on `q`
             KeyCode::Char('q') => {
                 app.quit();
             }
             // Exit application on `Ctrl-C`
-            KeyCode::Char('c' | 'C') => {
-                if key_event.modifiers == KeyModifiers::CONTROL {
-                    app.quit();
-                }
+            KeyCode::Char('c' | 'C') if key_event.modifiers == KeyModifiers::CONTROL => {
+                app.quit();
             }
             // Counter handlers
             // Counter handlers
@@ -81,7 +177,12 @@ pub fn handle_key_events(key_event: KeyEvent, app: &mut App) -> AppResult<()> {
                         app.game.player_turn,
                         app.game.ui.selected_coordinates,
                     );
-                    app.game.ui.cursor_right(authorized_positions);
+                    // The keys follow the drawn board, which can be rotated
+                    if app.game.is_view_rotated() {
+                        app.game.ui.cursor_left(authorized_positions);
+                    } else {
+                        app.game.ui.cursor_right(authorized_positions);
+                    }
                 }
             }
 
@@ -101,12 +202,20 @@ pub fn handle_key_events(key_event: KeyEvent, app: &mut App) -> AppResult<()> {
                         app.game.ui.selected_coordinates,
                     );
 
-                    app.game.ui.cursor_left(authorized_positions);
+                    if app.game.is_view_rotated() {
+                        app.game.ui.cursor_right(authorized_positions);
+                    } else {
+                        app.game.ui.cursor_left(authorized_positions);
+                    }
                 }
             }
             KeyCode::Up | KeyCode::Char('k') => {
                 if app.current_page == Pages::Home {
-                    app.menu_cursor_up(Pages::variant_count() as u8);
+                    app.menu_cursor_up(app.menu_len());
+                } else if app.current_popup == Some(Popups::EnginePathError) {
+                    if !app.detected_engines.is_empty() {
+                        app.menu_cursor_up(app.detected_engines.len() as u8);
+                    }
                 } else if !(app.game.game_state == GameState::Checkmate)
                     && !(app.game.game_state == GameState::Draw)
                     && !(app.game.game_state == GameState::Promotion)
@@ -115,12 +224,20 @@ pub fn handle_key_events(key_event: KeyEvent, app: &mut App) -> AppResult<()> {
                         app.game.player_turn,
                         app.game.ui.selected_coordinates,
                     );
-                    app.game.ui.cursor_up(authorized_positions);
+                    if app.game.is_view_rotated() {
+                        app.game.ui.cursor_down(authorized_positions);
+                    } else {
+                        app.game.ui.cursor_up(authorized_positions);
+                    }
                 }
             }
             KeyCode::Down | KeyCode::Char('j') => {
                 if app.current_page == Pages::Home {
-                    app.menu_cursor_down(Pages::variant_count() as u8);
+                    app.menu_cursor_down(app.menu_len());
+                } else if app.current_popup == Some(Popups::EnginePathError) {
+                    if !app.detected_engines.is_empty() {
+                        app.menu_cursor_down(app.detected_engines.len() as u8);
+                    }
                 } else if !(app.game.game_state == GameState::Checkmate)
                     && !(app.game.game_state == GameState::Draw)
                     && !(app.game.game_state == GameState::Promotion)
@@ -130,42 +247,119 @@ pub fn handle_key_events(key_event: KeyEvent, app: &mut App) -> AppResult<()> {
                         app.game.ui.selected_coordinates,
                     );
 
-                    app.game.ui.cursor_down(authorized_positions);
+                    if app.game.is_view_rotated() {
+                        app.game.ui.cursor_up(authorized_positions);
+                    } else {
+                        app.game.ui.cursor_down(authorized_positions);
+                    }
                 }
             }
-            KeyCode::Char(' ') | KeyCode::Enter => match app.current_page {
-                Pages::Home => {
-                    app.menu_select();
+            KeyCode::Char(' ') | KeyCode::Enter => handle_select(app),
+            KeyCode::Char('p') => {
+                // Presentation mode only makes sense when a board is displayed
+                if app.current_page == Pages::Solo
+                    || app.current_page == Pages::Bot
+                    || app.current_page == Pages::Multiplayer
+                {
+                    app.toggle_presentation_mode();
                 }
-                Pages::Bot => {
-                    if app.selected_color.is_none() {
-                        app.color_selection();
-                        app.bot_setup();
-                    } else {
-                        app.game.handle_cell_click();
-                    }
+            }
+            KeyCode::Char('y') => {
+                app.copy_last_move();
+            }
+            KeyCode::Char('u') => {
+                // Only the game pages show a board
+                if app.current_page == Pages::Solo
+                    || app.current_page == Pages::Bot
+                    || app.current_page == Pages::Multiplayer
+                {
+                    app.copy_board();
                 }
-                Pages::Multiplayer => {
-                    if app.hosting.is_none() {
-                        app.hosting_selection();
-                    } else if app.selected_color.is_none() {
-                        if app.hosting.is_some() && app.hosting.unwrap() {
-                            app.color_selection();
-                        }
-                    } else {
-                        app.game.handle_cell_click();
-                    }
+            }
+            KeyCode::Char('s') => {
+                if app.current_page == Pages::Solo
+                    || app.current_page == Pages::Bot
+                    || app.current_page == Pages::Multiplayer
+                {
+                    app.save_board();
                 }
-                Pages::Credit => {
-                    app.current_page = Pages::Home;
+            }
+            KeyCode::Char('d') => {
+                app.game.ui.toggle_debug_coords();
+            }
+            KeyCode::Char('a') => {
+                app.game.ui.toggle_last_move_reach();
+            }
+            KeyCode::Char('m') => {
+                if app.current_page == Pages::Solo
+                    || app.current_page == Pages::Bot
+                    || app.current_page == Pages::Multiplayer
+                {
+                    app.game.ui.toggle_show_captured();
+                    app.update_config();
                 }
-                _ => {
-                    app.game.handle_cell_click();
+            }
+            KeyCode::Char('c') => {
+                // The reconnecting popup lets the key through for ctrl-c, it doesn't toggle the labels behind it
+                if (app.current_page == Pages::Solo
+                    || app.current_page == Pages::Bot
+                    || app.current_page == Pages::Multiplayer)
+                    && app.current_popup.is_none()
+                {
+                    app.game.ui.toggle_show_coordinates();
+                    app.update_config();
                 }
-            },
-            KeyCode::Char('?') => {
-                if app.current_page != Pages::Credit {
-                    app.toggle_help_popup();
+            }
+            KeyCode::Char('x') => {
+                // The draws are claimed by the players, in multiplayer they are always automatic
+                if app.current_popup.is_none() && app.game.opponent.is_none() {
+                    app.game.claim_draw();
+                }
+            }
+            KeyCode::Char('z') => {
+                // Only the solo games can take a move back
+                if app.current_page == Pages::Solo && app.current_popup.is_none() {
+                    app.game.take_back_move();
+                }
+            }
+            KeyCode::Char('o') => {
+                // The other games claim their draws with x, nobody has to agree on them
+                if app.current_page == Pages::Multiplayer && app.current_popup.is_none() {
+                    app.game.offer_draw();
+                }
+            }
+            KeyCode::Char('f') => {
+                // `r` already restarts the game, so the resignation uses another key
+                if (app.current_page == Pages::Solo
+                    || (app.current_page == Pages::Bot
+                        && app.game.bot.is_some()
+                        && app.bot_demo.is_none()))
+                    && app.current_popup.is_none()
+                {
+                    app.game.resign();
+                }
+            }
+            KeyCode::Char('F') => {
+                if app.current_page == Pages::Solo
+                    || app.current_page == Pages::Bot
+                    || app.current_page == Pages::Multiplayer
+                {
+                    app.game.ui.toggle_view_flip();
+                }
+            }
+            KeyCode::Char('K') => {
+                if app.current_popup.is_none() && app.game.game_state == GameState::Playing {
+                    app.game.move_cursor_to_checked_king();
+                }
+            }
+            KeyCode::Char('D') => {
+                // Hidden demo shortcut, only in solo games as nobody else has to agree on the moves
+                if app.current_page == Pages::Solo
+                    && app.current_popup.is_none()
+                    && app.game.game_state == GameState::Playing
+                {
+                    app.game.ui.unselect_cell();
+                    app.game.play_random_moves(app.demo_random_moves);
                 }
             }
             KeyCode::Char('r') => {
@@ -200,6 +394,22 @@ pub fn handle_key_events(key_event: KeyEvent, app: &mut App) -> AppResult<()> {
                     Some(Popups::Help) => {
                         app.current_popup = None;
                     }
+                    Some(Popups::ExportedBoard) => {
+                        app.current_popup = None;
+                        app.exported_board = None;
+                    }
+                    Some(Popups::StartupPageUnavailable) => {
+                        app.current_popup = None;
+                        app.unavailable_startup_page = None;
+                    }
+                    Some(Popups::CheckingEngine)
+                    | Some(Popups::EngineNotUci)
+                    | Some(Popups::EnginePathError) => {
+                        app.engine_check = None;
+                        app.current_popup = None;
+                        app.current_page = Pages::Home;
+                        app.menu_cursor = 0;
+                    }
                     _ => {}
                 }
 
@@ -207,30 +417,13 @@ pub fn handle_key_events(key_event: KeyEvent, app: &mut App) -> AppResult<()> {
                     app.current_page = Pages::Home;
                 }
 
-                app.game.ui.unselect_cell();
-            }
-            KeyCode::Char('b') => {
-                let display_mode = app.game.ui.display_mode;
-                app.selected_color = None;
-                if app.game.bot.is_some() {
-                    app.game.bot = None;
-                }
-                if app.game.opponent.is_some() {
-                    app.game
-                        .opponent
-                        .as_mut()
-                        .unwrap()
-                        .send_end_game_to_server();
-                    app.game.opponent = None;
-                    app.hosting = None;
-                    app.host_ip = None;
+                if app.current_page == Pages::Home {
+                    app.restore_display_mode();
                 }
 
-                app.go_to_home();
-                app.game.game_board.reset();
-                app.game.ui.reset();
-                app.game.ui.display_mode = display_mode;
+                app.game.ui.unselect_cell();
             }
+            KeyCode::Char('b') => go_back_home(app),
             // Other handlers you could add here.
             _ => {}
         }
@@ -239,17 +432,118 @@ pub fn handle_key_events(key_event: KeyEvent, app: &mut App) -> AppResult<()> {
     Ok(())
 }
 
+/// Handles the keys replaying the positions of a finished game, returns false for the keys it doesn't use
+/// - `Left` and `Right` step one move back or forward, the last position being the live board
+/// - `Home` and `End` jump to the starting position and back to the live board
+pub fn handle_history_key(key_code: KeyCode, app: &mut App) -> bool {
+    match key_code {
+        KeyCode::Left | KeyCode::Char('h') => app.game.show_previous_position(),
+        KeyCode::Right | KeyCode::Char('l') => app.game.show_next_position(),
+        KeyCode::Home => app.game.show_first_position(),
+        KeyCode::End => app.game.show_last_position(),
+        _ => return false,
+    }
+    true
+}
+
+/// Handles the keys editing the position of the board editor, returns false for the keys it doesn't use
+/// - `Enter` and `Space` put the next piece on the cursor cell, `Backspace` and `Delete` empty it
+/// - `t` changes the player to move, `1` to `4` toggle the KQkq castling rights
+/// - `e` empties the board, `i` sets the starting position back
+/// - `s` starts a game from the position, `Esc` goes back to the menu
+pub fn handle_board_editor_key(key_code: KeyCode, app: &mut App) -> bool {
+    let cursor = app.game.ui.cursor_coordinates;
+    match key_code {
+        KeyCode::Char(' ') | KeyCode::Enter => app.board_editor.cycle_piece(&cursor),
+        KeyCode::Backspace | KeyCode::Delete => app.board_editor.clear_cell(&cursor),
+        KeyCode::Char('t') => app.board_editor.toggle_player_turn(),
+        KeyCode::Char(digit @ '1'..='4') => {
+            app.board_editor
+                .toggle_castling_right(digit as usize - '1' as usize);
+        }
+        KeyCode::Char('e') => app.board_editor.clear_board(),
+        KeyCode::Char('i') => app.board_editor.set_starting_position(),
+        KeyCode::Char('s') => app.start_board_editor_game(),
+        KeyCode::Esc => app.go_to_home(),
+        _ => return false,
+    }
+    true
+}
+
+/// Handles the select keys (`Enter` and `Space`), an open popup takes them before the page:
+/// - a popup with choices confirms the highlighted one
+/// - an information popup is closed, a draw offer is accepted, a lost connection goes back to the menu
+/// - the error and waiting popups ignore them, only `Esc` leaves them, except for picking a detected engine
+/// - on the menu the highlighted entry is selected, on the credits we go back to the menu
+/// - in a game a piece is selected or the selected piece is moved
+pub fn handle_select(app: &mut App) {
+    match app.current_popup {
+        Some(Popups::ColorSelection) => {
+            app.color_selection();
+            if app.current_page == Pages::Bot {
+                app.bot_setup();
+            }
+        }
+        Some(Popups::MultiplayerSelection) => app.hosting_selection(),
+        Some(Popups::DrawOffer) => {
+            app.game.answer_draw_offer(true);
+            app.current_popup = None;
+        }
+        Some(Popups::ClaimDraw) => {
+            app.game.claim_draw();
+            app.current_popup = None;
+        }
+        Some(Popups::Help) => app.current_popup = None,
+        Some(Popups::ConnectionLost) => go_back_home(app),
+        Some(Popups::ExportedBoard) => {
+            app.current_popup = None;
+            app.exported_board = None;
+        }
+        Some(Popups::StartupPageUnavailable) => {
+            app.current_popup = None;
+            app.unavailable_startup_page = None;
+        }
+        // The prompt handles its own keys
+        Some(Popups::EnterHostIP) => {}
+        // Without an engine path, one of the engines found on the system can be picked
+        Some(Popups::EnginePathError) => app.select_detected_engine(),
+        Some(Popups::WaitingForOpponentToJoin)
+        | Some(Popups::CheckingEngine)
+        | Some(Popups::EngineNotUci)
+        | Some(Popups::Reconnecting) => {}
+        None => match app.current_page {
+            Pages::Home => app.menu_select(),
+            Pages::Credit => app.current_page = Pages::Home,
+            Pages::Solo => app.game.handle_cell_click(),
+            // The board is only playable once the game is set up
+            Pages::Bot if app.game.bot.is_some() && app.bot_demo.is_none() => {
+                app.game.handle_cell_click()
+            }
+            Pages::Multiplayer if app.game.opponent.is_some() => app.game.handle_cell_click(),
+            Pages::Bot | Pages::Multiplayer => {}
+            Pages::BoardEditor => {
+                let cursor = app.game.ui.cursor_coordinates;
+                app.board_editor.cycle_piece(&cursor);
+            }
+        },
+    }
+}
+
 pub fn handle_mouse_events(mouse_event: MouseEvent, app: &mut App) -> AppResult<()> {
     // Mouse control only implemented for actual game
     if app.current_page == Pages::Home || app.current_page == Pages::Credit {
         return Ok(());
     }
     if mouse_event.kind == MouseEventKind::Down(MouseButton::Left) {
-        if app.game.game_state == GameState::Checkmate || app.game.game_state == GameState::Draw {
+        if app.game.game_state == GameState::Checkmate
+            || app.game.game_state == GameState::Draw
+            || matches!(app.game.game_state, GameState::Resigned(_))
+        {
             return Ok(());
         }
 
-        if app.current_popup.is_some() {
+        // Nobody plays for the engines of a bot versus bot game
+        if app.current_popup.is_some() || app.bot_demo.is_some() {
             return Ok(());
         }
 
@@ -266,6 +560,8 @@ pub fn handle_mouse_events(mouse_event: MouseEvent, app: &mut App) -> AppResult<
             if app.game.opponent.is_some() {
                 app.game.handle_multiplayer_promotion();
             }
+            // The click was used to choose the promotion piece, not to select a cell
+            return Ok(());
         }
         if mouse_event.column < app.game.ui.top_x || mouse_event.row < app.game.ui.top_y {
             return Ok(());
@@ -275,8 +571,25 @@ pub fn handle_mouse_events(mouse_event: MouseEvent, app: &mut App) -> AppResult<
         if x > 7 || y > 7 {
             return Ok(());
         }
-        app.game.ui.mouse_used = true;
         let coords: Coord = Coord::new(y as u8, x as u8);
+        // In the board editor a click only moves the cursor, the keys edit the cell
+        if app.current_page == Pages::BoardEditor {
+            app.game.ui.cursor_coordinates = coords;
+            return Ok(());
+        }
+        app.game.ui.mouse_used = true;
+        // The clicked square shows the opposite cell when the view is rotated
+        let coords = if app.game.is_view_rotated() {
+            invert_position(&coords)
+        } else {
+            coords
+        };
+        // During the opponent's turn the clicks prepare a premove
+        if app.game.is_waiting_for_opponent() {
+            app.game.ui.cursor_coordinates = coords;
+            app.game.handle_cell_click();
+            return Ok(());
+        }
 
         let authorized_positions = app
             .game
@@ -302,3 +615,25 @@ pub fn handle_mouse_events(mouse_event: MouseEvent, app: &mut App) -> AppResult<
     }
     Ok(())
 }
+
+// Leave the game for the menu, the multiplayer opponent is told the game ended
+fn go_back_home(app: &mut App) {
+    app.selected_color = None;
+    if app.game.bot.is_some() {
+        app.game.bot = None;
+    }
+    if app.game.opponent.is_some() {
+        app.game
+            .opponent
+            .as_mut()
+            .unwrap()
+            .send_end_game_to_server();
+        app.game.opponent = None;
+        app.hosting = None;
+        app.host_ip = None;
+    }
+
+    app.go_to_home();
+    app.game.game_board.reset();
+    app.game.ui.reset();
+}
@@ -0,0 +1,625 @@
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// Languages of the interface, set with the `language` option of the configuration file
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Language {
+    English,
+    French,
+}
+
+impl Language {
+    /// The language of a configuration value, `None` when it isn't translated
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code.to_lowercase().as_str() {
+            "en" | "english" => Some(Language::English),
+            "fr" | "french" | "français" => Some(Language::French),
+            _ => None,
+        }
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::French => "fr",
+        }
+    }
+}
+
+// The language used by `t`, kept global as the strings are looked up by every render function
+static LANGUAGE: AtomicU8 = AtomicU8::new(0);
+
+pub fn set_language(language: Language) {
+    LANGUAGE.store(language as u8, Ordering::Relaxed);
+}
+
+pub fn language() -> Language {
+    match LANGUAGE.load(Ordering::Relaxed) {
+        1 => Language::French,
+        _ => Language::English,
+    }
+}
+
+/// The translated string of `key` in the current language
+/// A key missing from the language falls back to English, and to the key itself when English misses it too
+pub fn t(key: &'static str) -> &'static str {
+    lookup(catalog(language()), key)
+        .or_else(|| lookup(ENGLISH, key))
+        .unwrap_or(key)
+}
+
+/// Every key and its string in a language
+pub fn catalog(language: Language) -> &'static [(&'static str, &'static str)] {
+    match language {
+        Language::English => ENGLISH,
+        Language::French => FRENCH,
+    }
+}
+
+fn lookup(catalog: &[(&'static str, &'static str)], key: &str) -> Option<&'static str> {
+    catalog
+        .iter()
+        .find(|(catalog_key, _)| *catalog_key == key)
+        .map(|(_, string)| *string)
+}
+
+// The strings with a `{}` get their value with `replace` where they are used
+const ENGLISH: &[(&str, &str)] = &[
+    // Menu
+    ("menu.subtitle", "A chess game made in 🦀"),
+    ("menu.solo", "Normal game"),
+    ("menu.multiplayer", "Multiplayer"),
+    ("menu.bot", "Play against a bot"),
+    ("menu.board_editor", "Board editor"),
+    ("menu.display_mode", "Display mode: {}"),
+    ("menu.display_mode.default", "Default"),
+    ("menu.help", "Help"),
+    ("menu.credits", "Credits"),
+    ("menu.resume", "Resume last game"),
+    // Shared by the popups
+    ("popup.error", "Error"),
+    ("popup.close", "Press `Esc` to close the popup."),
+    ("popup.back_to_menu", "Press `Esc` to go back to the menu"),
+    // Engine popups
+    ("engine.missing", "You didn't specify the chess engine path"),
+    (
+        "engine.use_argument",
+        "To do so use the -e argument when running chess-tui to store an engine path",
+    ),
+    ("engine.example", "Example: "),
+    (
+        "engine.detected",
+        "Or pick one of the engines found on your system:",
+    ),
+    (
+        "engine.use_selected",
+        "Press `Enter` to use the selected engine",
+    ),
+    ("engine.checking_title", "Chess engine"),
+    (
+        "engine.checking",
+        "Waiting for {} to answer the uci command...",
+    ),
+    (
+        "engine.not_uci",
+        "This binary does not appear to be a UCI engine",
+    ),
+    (
+        "engine.no_uciok",
+        "{} didn't answer uciok to the uci command",
+    ),
+    (
+        "engine.use_other",
+        "Use the -e argument when running chess-tui to store another engine path",
+    ),
+    // Exported board popup
+    ("export.title", "Board"),
+    (
+        "export.no_clipboard",
+        "The clipboard is not available, copy the board below",
+    ),
+    // Startup page popup
+    ("startup.title", "Startup page"),
+    ("startup.unavailable", "The {} page can't be opened at startup"),
+    (
+        "startup.accepted",
+        "The startup_page option accepts home, solo and bot. Lichess and puzzles are not available in this version.",
+    ),
+    // Draw offer popup
+    ("draw_offer.title", "Draw offer"),
+    ("draw_offer.offered", "Your opponent offers a draw"),
+    (
+        "draw_offer.answer",
+        "Press `Enter` or y to accept, `Esc` or n to decline",
+    ),
+    // Repetition draw popup
+    ("claim_draw.title", "Claim draw?"),
+    (
+        "claim_draw.repeated",
+        "The position was reached three times",
+    ),
+    (
+        "claim_draw.answer",
+        "Press `Enter` or y to claim the draw, `Esc` or n to play on",
+    ),
+    // Dropped multiplayer connection popup
+    ("connection.title", "Connection lost"),
+    ("connection.reconnecting", "Reconnecting…"),
+    ("connection.reconnecting.leave", "Press `Esc` to leave the game"),
+    (
+        "connection.lost",
+        "The game server can't be reached anymore",
+    ),
+    (
+        "connection.lost.leave",
+        "Press `Enter` or `Esc` to go back to the menu",
+    ),
+    // End of the game popup
+    ("end.title", "Game ended"),
+    ("end.white_won", "White Won !!!"),
+    ("end.black_won", "Black Won !!!"),
+    ("end.draw", "That's a draw"),
+    ("end.white_resigned", "White resigned, Black Won !!!"),
+    ("end.black_resigned", "Black resigned, White Won !!!"),
+    ("end.back", "Press `B` to go back to the menu"),
+    ("end.restart", "Press `R` to restart a new game"),
+    // Promotion popup
+    ("promotion.title", "Pawn promotion"),
+    ("promotion.choose", "-- Choose your pawn promotion --"),
+    // Credits popup
+    ("credits.title", "Credits"),
+    (
+        "credits.hello",
+        "Hi 👋, I'm Thomas, a 22 years old French computer science student.",
+    ),
+    (
+        "credits.thanks",
+        "Thank you for playing Chess-tui! This project started as a personal journey to improve my algorithmic skills and learn Rust.",
+    ),
+    (
+        "credits.source",
+        "The entire source code is available on GitHub at https://github.com/thomas-mauran/chess-tui",
+    ),
+    (
+        "credits.contribute",
+        "Feel free to contribute by picking an issue or creating a new one.",
+    ),
+    (
+        "credits.classmates",
+        "Special thanks to my classmates for their support and inspiration!",
+    ),
+    // Help popup
+    ("help.title", "Help menu"),
+    ("help.controls", "Game controls:"),
+    (
+        "help.cursor",
+        "←/h ↑/k ↓/j →/l: Use these keys or the mouse to move the ",
+    ),
+    ("help.cursor.blue", "blue"),
+    ("help.cursor.end", " cursor"),
+    (
+        "help.zoom",
+        "`Ctrl` '+' or '-': Zoom in or out to adjust pieces sizes",
+    ),
+    ("help.zoom.terminals", "(Might differ in certain terminals)"),
+    ("help.select", "`Space`: Select a piece"),
+    ("help.deselect", "`Esc`: Deselect a piece / hide popups"),
+    ("help.quit", "q: Quit the game"),
+    ("help.home", "b: Go to the home menu / reset the game"),
+    (
+        "help.presentation",
+        "p: Toggle the presentation mode (board only)",
+    ),
+    ("help.coordinates", "d: Show the coordinates in the empty cells"),
+    (
+        "help.last_move_reach",
+        "a: Show the cells the last moved piece can reach",
+    ),
+    ("help.copy_move", "y: Copy the last move to the clipboard"),
+    (
+        "help.copy_board",
+        "u: Copy the board as unicode text to the clipboard",
+    ),
+    (
+        "help.save_board",
+        "s: Save the board and its FEN in a text file of the configuration folder",
+    ),
+    ("help.captured", "m: Show or hide the captured pieces"),
+    ("help.show_coordinates", "c: Show or hide the files and ranks"),
+    (
+        "help.claim_draw",
+        "x: Claim a draw (fifty moves or repetition)",
+    ),
+    (
+        "help.checked_king",
+        "K: Move the cursor to your king when it is checked",
+    ),
+    ("help.take_back", "z: Take back the last move (solo games)"),
+    ("help.offer_draw", "o: Offer a draw to your opponent (multiplayer)"),
+    ("help.resign", "f: Resign the game (solo and bot games)"),
+    ("help.flip_board", "F: Turn the board over, the side played doesn't change"),
+    (
+        "help.replay",
+        "←/h →/l Home End: Replay the positions once the game is over",
+    ),
+    ("help.toggle", "{}: Show or hide this help"),
+    ("help.back", "`Esc`: Go back to the menu"),
+    ("help.menu", "Menu controls:"),
+    ("help.menu.move", "↑/k ↓/j: Move in the menu"),
+    (
+        "help.menu.select",
+        "`Enter` or `Space`: Open the highlighted entry",
+    ),
+    ("help.editor", "Board editor controls:"),
+    ("help.colors", "Color codes:"),
+    ("help.colors.blue", "Blue cell"),
+    ("help.colors.cursor", ": Your cursor "),
+    ("help.colors.green", "Green cell"),
+    ("help.colors.selected", ": Selected Piece, marked with ◆ "),
+    ("help.colors.purple", "Purple cell"),
+    ("help.colors.checked", ": The king is getting checked "),
+    ("help.colors.yellow", "Yellow cell"),
+    ("help.colors.escape", ": The checked king can escape there "),
+    ("help.colors.red", "Red cell"),
+    (
+        "help.colors.reach",
+        ": The last moved piece can go there (shown with a) ",
+    ),
+    (
+        "help.colors.grey",
+        "Grey cell: Available cells for the selected piece",
+    ),
+    // Board editor
+    ("editor.title", "Board editor"),
+    ("editor.to_move", "To move: {}"),
+    ("editor.white", "White"),
+    ("editor.black", "Black"),
+    ("editor.castling", "Castling: {}"),
+    ("editor.cycle", "`Space`: Next piece on the cell"),
+    ("editor.clear_cell", "`Backspace`: Empty the cell"),
+    ("editor.turn", "t: Change the player to move"),
+    ("editor.castlings", "1 2 3 4: Toggle the K Q k q castlings"),
+    ("editor.empty", "e: Empty the board"),
+    ("editor.start_position", "i: Starting position"),
+    ("editor.play", "s: Play from this position"),
+    // Color selection popup
+    ("color.title", "Color selection"),
+    ("color.choose", "-- Choose your color --"),
+    // Multiplayer popups
+    (
+        "multiplayer.host_or_join",
+        "-- Are you hosting or joining a game ? --",
+    ),
+    ("multiplayer.hosting", "HOSTING"),
+    ("multiplayer.joining", "JOINING"),
+    ("multiplayer.waiting.title", "Waiting ..."),
+    ("multiplayer.waiting", "Waiting for other player"),
+    ("multiplayer.host_address", "Host IP address and port: {}"),
+    ("multiplayer.host_address.ipv6", "Over IPv6: {}"),
+    ("multiplayer.join.title", "Join a game"),
+    (
+        "multiplayer.join.enter",
+        "Enter the ip address and port of the host:",
+    ),
+    ("multiplayer.join.example", "Example: 10.111.6.50:2308 or [2001:db8::1]:2308;"),
+    ("multiplayer.join.documentation", "Documentation: "),
+    (
+        "multiplayer.discovery.searching",
+        "Searching for games on your network...",
+    ),
+    ("multiplayer.discovery.none", "No game found on your network"),
+    (
+        "multiplayer.discovery.found",
+        "Games on your network, choose with Up/Down and press Enter:",
+    ),
+    ("multiplayer.discovery.play_white", "{} (you play white)"),
+    ("multiplayer.discovery.play_black", "{} (you play black)"),
+];
+
+const FRENCH: &[(&str, &str)] = &[
+    // Menu
+    ("menu.subtitle", "Un jeu d'échecs fait en 🦀"),
+    ("menu.solo", "Partie normale"),
+    ("menu.multiplayer", "Multijoueur"),
+    ("menu.bot", "Jouer contre un bot"),
+    ("menu.board_editor", "Éditeur de position"),
+    ("menu.display_mode", "Mode d'affichage : {}"),
+    ("menu.display_mode.default", "Par défaut"),
+    ("menu.help", "Aide"),
+    ("menu.credits", "Crédits"),
+    ("menu.resume", "Reprendre la dernière partie"),
+    // Shared by the popups
+    ("popup.error", "Erreur"),
+    ("popup.close", "Appuyez sur `Esc` pour fermer la fenêtre."),
+    ("popup.back_to_menu", "Appuyez sur `Esc` pour revenir au menu"),
+    // Engine popups
+    (
+        "engine.missing",
+        "Vous n'avez pas indiqué le chemin du moteur d'échecs",
+    ),
+    (
+        "engine.use_argument",
+        "Pour cela, lancez chess-tui avec l'argument -e pour enregistrer le chemin d'un moteur",
+    ),
+    ("engine.example", "Exemple : "),
+    (
+        "engine.detected",
+        "Ou choisissez un des moteurs trouvés sur votre système :",
+    ),
+    (
+        "engine.use_selected",
+        "Appuyez sur `Entrée` pour utiliser le moteur sélectionné",
+    ),
+    ("engine.checking_title", "Moteur d'échecs"),
+    (
+        "engine.checking",
+        "En attente de la réponse de {} à la commande uci...",
+    ),
+    (
+        "engine.not_uci",
+        "Ce programme ne semble pas être un moteur UCI",
+    ),
+    (
+        "engine.no_uciok",
+        "{} n'a pas répondu uciok à la commande uci",
+    ),
+    (
+        "engine.use_other",
+        "Lancez chess-tui avec l'argument -e pour enregistrer le chemin d'un autre moteur",
+    ),
+    // Exported board popup
+    ("export.title", "Plateau"),
+    (
+        "export.no_clipboard",
+        "Le presse-papiers n'est pas disponible, copiez le plateau ci-dessous",
+    ),
+    // Startup page popup
+    ("startup.title", "Page de démarrage"),
+    (
+        "startup.unavailable",
+        "La page {} ne peut pas être ouverte au démarrage",
+    ),
+    (
+        "startup.accepted",
+        "L'option startup_page accepte home, solo et bot. Lichess et les puzzles ne sont pas disponibles dans cette version.",
+    ),
+    // Draw offer popup
+    ("draw_offer.title", "Proposition de nulle"),
+    ("draw_offer.offered", "Votre adversaire propose la nulle"),
+    (
+        "draw_offer.answer",
+        "`Entrée` ou y pour accepter, `Esc` ou n pour refuser",
+    ),
+    // Repetition draw popup
+    ("claim_draw.title", "Réclamer la nulle ?"),
+    (
+        "claim_draw.repeated",
+        "La position a été atteinte trois fois",
+    ),
+    (
+        "claim_draw.answer",
+        "`Entrée` ou y pour réclamer la nulle, `Esc` ou n pour continuer",
+    ),
+    // Dropped multiplayer connection popup
+    ("connection.title", "Connexion perdue"),
+    ("connection.reconnecting", "Reconnexion…"),
+    ("connection.reconnecting.leave", "`Esc` pour quitter la partie"),
+    (
+        "connection.lost",
+        "Le serveur de la partie ne répond plus",
+    ),
+    (
+        "connection.lost.leave",
+        "`Entrée` ou `Esc` pour revenir au menu",
+    ),
+    // End of the game popup
+    ("end.title", "Partie terminée"),
+    ("end.white_won", "Les blancs ont gagné !!!"),
+    ("end.black_won", "Les noirs ont gagné !!!"),
+    ("end.draw", "Match nul"),
+    (
+        "end.white_resigned",
+        "Les blancs abandonnent, les noirs ont gagné !!!",
+    ),
+    (
+        "end.black_resigned",
+        "Les noirs abandonnent, les blancs ont gagné !!!",
+    ),
+    ("end.back", "Appuyez sur `B` pour revenir au menu"),
+    ("end.restart", "Appuyez sur `R` pour recommencer une partie"),
+    // Promotion popup
+    ("promotion.title", "Promotion du pion"),
+    ("promotion.choose", "-- Choisissez la promotion du pion --"),
+    // Credits popup
+    ("credits.title", "Crédits"),
+    (
+        "credits.hello",
+        "Salut 👋, je suis Thomas, un étudiant français en informatique de 22 ans.",
+    ),
+    (
+        "credits.thanks",
+        "Merci de jouer à Chess-tui ! Ce projet a commencé comme un défi personnel pour progresser en algorithmique et apprendre Rust.",
+    ),
+    (
+        "credits.source",
+        "Tout le code source est disponible sur GitHub à l'adresse https://github.com/thomas-mauran/chess-tui",
+    ),
+    (
+        "credits.contribute",
+        "N'hésitez pas à contribuer en choisissant une issue ou en en créant une nouvelle.",
+    ),
+    (
+        "credits.classmates",
+        "Un grand merci à mes camarades de classe pour leur soutien et leur inspiration !",
+    ),
+    // Help popup
+    ("help.title", "Aide"),
+    ("help.controls", "Commandes :"),
+    (
+        "help.cursor",
+        "←/h ↑/k ↓/j →/l : Utilisez ces touches ou la souris pour déplacer le curseur ",
+    ),
+    ("help.cursor.blue", "bleu"),
+    ("help.cursor.end", ""),
+    (
+        "help.zoom",
+        "`Ctrl` '+' ou '-' : Zoomez ou dézoomez pour ajuster la taille des pièces",
+    ),
+    (
+        "help.zoom.terminals",
+        "(Peut varier selon les terminaux)",
+    ),
+    ("help.select", "`Espace` : Sélectionner une pièce"),
+    (
+        "help.deselect",
+        "`Esc` : Désélectionner une pièce / fermer les fenêtres",
+    ),
+    ("help.quit", "q : Quitter le jeu"),
+    (
+        "help.home",
+        "b : Revenir au menu / recommencer la partie",
+    ),
+    (
+        "help.presentation",
+        "p : Basculer le mode présentation (plateau seul)",
+    ),
+    (
+        "help.coordinates",
+        "d : Afficher les coordonnées dans les cases vides",
+    ),
+    (
+        "help.last_move_reach",
+        "a : Afficher les cases que la dernière pièce jouée peut atteindre",
+    ),
+    (
+        "help.copy_move",
+        "y : Copier le dernier coup dans le presse-papiers",
+    ),
+    (
+        "help.copy_board",
+        "u : Copier le plateau en texte unicode dans le presse-papiers",
+    ),
+    (
+        "help.save_board",
+        "s : Enregistrer le plateau et sa FEN dans un fichier texte du dossier de configuration",
+    ),
+    ("help.captured", "m : Afficher ou masquer les pièces prises"),
+    (
+        "help.show_coordinates",
+        "c : Afficher ou masquer les colonnes et les rangées",
+    ),
+    (
+        "help.claim_draw",
+        "x : Réclamer la nulle (cinquante coups ou répétition)",
+    ),
+    (
+        "help.checked_king",
+        "K : Placer le curseur sur votre roi quand il est en échec",
+    ),
+    (
+        "help.take_back",
+        "z : Reprendre le dernier coup (parties solo)",
+    ),
+    (
+        "help.offer_draw",
+        "o : Proposer la nulle à votre adversaire (multijoueur)",
+    ),
+    (
+        "help.resign",
+        "f : Abandonner la partie (parties solo et contre le bot)",
+    ),
+    (
+        "help.flip_board",
+        "F : Retourner le plateau, sans changer de camp",
+    ),
+    (
+        "help.replay",
+        "←/h →/l Début Fin : Rejouer les positions une fois la partie terminée",
+    ),
+    ("help.toggle", "{} : Afficher ou masquer cette aide"),
+    ("help.back", "`Esc` : Revenir au menu"),
+    ("help.menu", "Commandes du menu :"),
+    ("help.menu.move", "↑/k ↓/j : Se déplacer dans le menu"),
+    (
+        "help.menu.select",
+        "`Entrée` ou `Espace` : Ouvrir l'entrée sélectionnée",
+    ),
+    ("help.editor", "Commandes de l'éditeur :"),
+    ("help.colors", "Codes couleur :"),
+    ("help.colors.blue", "Case bleue"),
+    ("help.colors.cursor", " : Votre curseur "),
+    ("help.colors.green", "Case verte"),
+    (
+        "help.colors.selected",
+        " : Pièce sélectionnée, marquée par ◆ ",
+    ),
+    ("help.colors.purple", "Case violette"),
+    ("help.colors.checked", " : Le roi est en échec "),
+    ("help.colors.yellow", "Case jaune"),
+    (
+        "help.colors.escape",
+        " : Le roi en échec peut s'y réfugier ",
+    ),
+    ("help.colors.red", "Case rouge"),
+    (
+        "help.colors.reach",
+        " : La dernière pièce jouée peut y aller (affichée avec a) ",
+    ),
+    (
+        "help.colors.grey",
+        "Case grise : Cases accessibles à la pièce sélectionnée",
+    ),
+    // Board editor
+    ("editor.title", "Éditeur de position"),
+    ("editor.to_move", "Au trait : {}"),
+    ("editor.white", "Blancs"),
+    ("editor.black", "Noirs"),
+    ("editor.castling", "Roques : {}"),
+    ("editor.cycle", "`Espace` : Pièce suivante sur la case"),
+    ("editor.clear_cell", "`Retour arrière` : Vider la case"),
+    ("editor.turn", "t : Changer le joueur au trait"),
+    ("editor.castlings", "1 2 3 4 : Activer ou non les roques K Q k q"),
+    ("editor.empty", "e : Vider le plateau"),
+    ("editor.start_position", "i : Position de départ"),
+    ("editor.play", "s : Jouer depuis cette position"),
+    // Color selection popup
+    ("color.title", "Choix de la couleur"),
+    ("color.choose", "-- Choisissez votre couleur --"),
+    // Multiplayer popups
+    (
+        "multiplayer.host_or_join",
+        "-- Hébergez-vous ou rejoignez-vous une partie ? --",
+    ),
+    ("multiplayer.hosting", "HÉBERGER"),
+    ("multiplayer.joining", "REJOINDRE"),
+    ("multiplayer.waiting.title", "En attente ..."),
+    ("multiplayer.waiting", "En attente de l'autre joueur"),
+    (
+        "multiplayer.host_address",
+        "Adresse IP et port de l'hôte : {}",
+    ),
+    ("multiplayer.host_address.ipv6", "En IPv6 : {}"),
+    ("multiplayer.join.title", "Rejoindre une partie"),
+    (
+        "multiplayer.join.enter",
+        "Entrez l'adresse ip et le port de l'hôte :",
+    ),
+    (
+        "multiplayer.join.example",
+        "Exemple : 10.111.6.50:2308 ou [2001:db8::1]:2308;",
+    ),
+    ("multiplayer.join.documentation", "Documentation : "),
+    (
+        "multiplayer.discovery.searching",
+        "Recherche de parties sur votre réseau...",
+    ),
+    (
+        "multiplayer.discovery.none",
+        "Aucune partie trouvée sur votre réseau",
+    ),
+    (
+        "multiplayer.discovery.found",
+        "Parties sur votre réseau, choisissez avec Haut/Bas et appuyez sur Entrée :",
+    ),
+    ("multiplayer.discovery.play_white", "{} (vous jouez les blancs)"),
+    ("multiplayer.discovery.play_black", "{} (vous jouez les noirs)"),
+];
@@ -0,0 +1,168 @@
+use ratatui::crossterm::event::KeyCode;
+use toml::Value;
+
+/// The actions that can be bound to another key with the `[keybindings]` table, and their default key
+/// The arrows, `Enter` and `Esc` always keep their meaning, the help key is the `help_key` option
+pub const ACTIONS: [(&str, char); 22] = [
+    ("quit", 'q'),
+    ("move_up", 'k'),
+    ("move_down", 'j'),
+    ("move_left", 'h'),
+    ("move_right", 'l'),
+    ("select", ' '),
+    ("presentation_mode", 'p'),
+    ("copy_move", 'y'),
+    ("copy_board", 'u'),
+    ("save_board", 's'),
+    ("debug_coordinates", 'd'),
+    ("last_move_reach", 'a'),
+    ("show_captured", 'm'),
+    ("show_coordinates", 'c'),
+    ("claim_draw", 'x'),
+    ("take_back", 'z'),
+    ("offer_draw", 'o'),
+    ("resign", 'f'),
+    ("checked_king", 'K'),
+    ("flip_board", 'F'),
+    ("restart", 'r'),
+    ("home", 'b'),
+];
+
+/// The keys kept by the game whatever the bindings: the hidden demo key and the keys of the board editor
+const FIXED_KEYS: [char; 10] = ['D', ' ', 't', '1', '2', '3', '4', 'e', 'i', 's'];
+
+/// The key of each action
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyBindings {
+    // in the order of `ACTIONS`
+    keys: [char; ACTIONS.len()],
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        Self {
+            keys: ACTIONS.map(|(_, key)| key),
+        }
+    }
+}
+
+impl KeyBindings {
+    /// Read the `[keybindings]` table of the configuration, the actions it doesn't set keep their default key
+    /// The unknown actions and the keys that aren't a single character are logged and ignored
+    /// A key already used by another action, the help key or a fixed key is logged and the action keeps its default key
+    pub fn from_config(table: &Value, help_key: char) -> Self {
+        let mut keybindings = Self::default();
+        let Some(table) = table.as_table() else {
+            log::warn!("keybindings has to be a table of action names and keys, it is ignored");
+            return keybindings;
+        };
+
+        for (action, key) in table {
+            let Some(key) = key.as_str().and_then(parse_key) else {
+                log::warn!(
+                    "The key of {action} has to be a single character or \"space\", it is ignored"
+                );
+                continue;
+            };
+            if !keybindings.set(action, key) {
+                log::warn!("{action} is not an action that can be bound to a key, it is ignored");
+            }
+        }
+        keybindings.reset_conflicting_keys(help_key);
+        keybindings
+    }
+
+    // Give back its default key to a configured action whose key does something else, the last one of two actions sharing a key
+    // The default keys never conflict with each other, so it ends once every conflicting action has its default key
+    fn reset_conflicting_keys(&mut self, help_key: char) {
+        while let Some(index) = (0..ACTIONS.len()).rev().find(|&index| {
+            let key = self.keys[index];
+            key != ACTIONS[index].1
+                && (key == help_key
+                    || FIXED_KEYS.contains(&key)
+                    || self
+                        .keys
+                        .iter()
+                        .filter(|&&other_key| other_key == key)
+                        .count()
+                        > 1)
+        }) {
+            let (action, default_key) = ACTIONS[index];
+            log::warn!(
+                "The key {} of {action} is already used, {action} keeps its default key {}",
+                key_name(self.keys[index]),
+                key_name(default_key)
+            );
+            self.keys[index] = default_key;
+        }
+    }
+
+    /// Bind an action to a key, false when the action doesn't exist
+    pub fn set(&mut self, action: &str, key: char) -> bool {
+        match ACTIONS.iter().position(|&(name, _)| name == action) {
+            Some(index) => {
+                self.keys[index] = key;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Whether a key already does something, as the key of an action or one of the fixed keys
+    pub fn is_used(&self, key: char) -> bool {
+        self.keys.contains(&key) || FIXED_KEYS.contains(&key)
+    }
+
+    pub fn key(&self, action: &str) -> Option<char> {
+        ACTIONS
+            .iter()
+            .position(|&(name, _)| name == action)
+            .map(|index| self.keys[index])
+    }
+
+    /// The key doing the same action with the default bindings, so the key handler only knows the default keys
+    /// A default key bound to nothing anymore does nothing, the other keys are kept
+    pub fn to_default_key(&self, key_code: KeyCode) -> KeyCode {
+        let KeyCode::Char(key) = key_code else {
+            return key_code;
+        };
+        if let Some(index) = self.keys.iter().position(|&bound_key| bound_key == key) {
+            return KeyCode::Char(ACTIONS[index].1);
+        }
+        if ACTIONS.iter().any(|&(_, default_key)| default_key == key) {
+            return KeyCode::Null;
+        }
+        key_code
+    }
+
+    /// The table written in the configuration file
+    pub fn to_config(&self) -> Value {
+        Value::Table(
+            ACTIONS
+                .iter()
+                .zip(self.keys)
+                .map(|(&(action, _), key)| (action.to_string(), Value::String(key_name(key))))
+                .collect(),
+        )
+    }
+}
+
+// A key of the configuration file, the space is written in full as a lone space is easy to miss
+fn parse_key(key: &str) -> Option<char> {
+    if key.eq_ignore_ascii_case("space") {
+        return Some(' ');
+    }
+    let mut chars = key.chars();
+    match (chars.next(), chars.next()) {
+        (Some(key), None) => Some(key),
+        _ => None,
+    }
+}
+
+fn key_name(key: char) -> String {
+    if key == ' ' {
+        "space".to_string()
+    } else {
+        key.to_string()
+    }
+}
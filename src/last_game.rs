@@ -0,0 +1,137 @@
+use std::{fs, path::Path};
+
+use toml::{value::Table, Value};
+
+use crate::{
+    game_logic::{game::Game, game::GameState},
+    pieces::PieceColor,
+    utils::col_to_letter,
+};
+
+/// Name of the file keeping the unfinished game, in the configuration folder
+pub const LAST_GAME_FILE: &str = "last_game.toml";
+
+/// An unfinished game saved on exit to be resumed from the menu
+/// The moves are written with their cells and promotion piece (`e2e4`, `e7e8q`), whatever the orientation of the board
+/// A castling is the king going to the rook cell (`e1h1`), as it is played on the board
+#[derive(Debug, Clone, PartialEq)]
+pub struct SavedGame {
+    /// The game mode, only the solo games are saved, the network ones can't be resumed
+    pub mode: String,
+    /// The position the game started from, as the board editor and `--fen` can start from another one
+    pub start_fen: String,
+    pub moves: Vec<String>,
+}
+
+impl SavedGame {
+    /// The saved game of a solo game, `None` when there is nothing to resume
+    pub fn from_game(game: &Game) -> Option<SavedGame> {
+        let game_board = &game.game_board;
+        if game_board.move_history.is_empty()
+            || game.game_state != GameState::Playing
+            || game.opponent.is_some()
+            || game.bot.is_some()
+        {
+            return None;
+        }
+
+        // The position before the first move, with the player to move at the bottom
+        let mut start_board = game_board.clone();
+        start_board.board = *game_board.board_history.first()?;
+        start_board.is_flipped = *game_board.board_history_flipped.first()?;
+        start_board.move_history.clear();
+        start_board.set_consecutive_non_pawn_or_capture(game_board.first_halfmove_clock);
+        let first_player = if start_board.is_flipped {
+            PieceColor::Black
+        } else {
+            PieceColor::White
+        };
+
+        let mut moves = vec![];
+        for index in 0..game_board.move_history.len() {
+            let (_, Some((from, to))) = game_board.history_position(index + 1, false)? else {
+                return None;
+            };
+            let promotion = game_board
+                .san_history
+                .get(index)
+                .and_then(|san| san.split_once('='))
+                .and_then(|(_, piece)| piece.chars().next())
+                .map(|piece| piece.to_ascii_lowercase().to_string())
+                .unwrap_or_default();
+            moves.push(format!(
+                "{}{}{}{}{promotion}",
+                col_to_letter(from.col),
+                8 - from.row,
+                col_to_letter(to.col),
+                8 - to.row
+            ));
+        }
+
+        Some(SavedGame {
+            mode: "solo".to_string(),
+            start_fen: start_board.position_fen(first_player),
+            moves,
+        })
+    }
+
+    /// The saved game as the TOML of the file
+    pub fn to_toml(&self) -> String {
+        let mut table = Table::new();
+        table.insert("mode".to_string(), Value::String(self.mode.clone()));
+        table.insert(
+            "start_fen".to_string(),
+            Value::String(self.start_fen.clone()),
+        );
+        table.insert(
+            "moves".to_string(),
+            Value::Array(self.moves.iter().cloned().map(Value::String).collect()),
+        );
+        Value::Table(table).to_string()
+    }
+
+    /// Read the file written by `to_toml`, `None` when a value is missing or of another type
+    pub fn from_toml(content: &str) -> Option<SavedGame> {
+        let saved_game = content.parse::<Value>().ok()?;
+        let string = |key: &str| Some(saved_game.get(key)?.as_str()?.to_string());
+        let moves = saved_game
+            .get("moves")?
+            .as_array()?
+            .iter()
+            .map(|saved_move| saved_move.as_str().map(str::to_string))
+            .collect::<Option<Vec<String>>>()?;
+
+        Some(SavedGame {
+            mode: string("mode")?,
+            start_fen: string("start_fen")?,
+            moves,
+        })
+    }
+
+    /// The saved game of the configuration folder, if a solo game was left unfinished
+    pub fn load(folder_path: &Path) -> Option<SavedGame> {
+        let content = fs::read_to_string(folder_path.join(LAST_GAME_FILE)).ok()?;
+        match SavedGame::from_toml(&content) {
+            Some(saved_game) if saved_game.mode == "solo" => Some(saved_game),
+            _ => {
+                log::warn!("The saved game can't be read, it is ignored");
+                None
+            }
+        }
+    }
+
+    /// Keep the game to resume it on the next start, without a game to resume the previous one is removed
+    pub fn save(saved_game: Option<&SavedGame>, folder_path: &Path) {
+        let path = folder_path.join(LAST_GAME_FILE);
+        match saved_game {
+            Some(saved_game) => {
+                if let Err(e) = fs::write(&path, saved_game.to_toml()) {
+                    log::error!("Failed to save the game: {e}");
+                }
+            }
+            None => {
+                let _ = fs::remove_file(path);
+            }
+        }
+    }
+}
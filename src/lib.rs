@@ -26,3 +26,18 @@ pub mod utils;
 
 // Logging
 pub mod logging;
+
+// Copy to the system clipboard
+pub mod clipboard;
+
+// Export the game in text formats
+pub mod export;
+
+// Translations of the interface
+pub mod i18n;
+
+// Keys of the game actions
+pub mod keybindings;
+
+// Save the unfinished solo game to resume it
+pub mod last_game;
@@ -1,8 +1,12 @@
 use chrono::Local;
 use log::LevelFilter;
-use simplelog::{CombinedLogger, Config, WriteLogger};
+use simplelog::{CombinedLogger, Config, SimpleLogger, WriteLogger};
 use std::fs;
 use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+// Whether the multiplayer protocol messages should be written to the logs
+static LOG_PROTOCOL: AtomicBool = AtomicBool::new(false);
 
 pub fn setup_logging(
     config_dir: &Path,
@@ -30,3 +34,21 @@ pub fn setup_logging(
         }
     }
 }
+
+/// Log to the standard output, for the headless server which has no interface to hide
+pub fn setup_headless_logging() -> Result<(), Box<dyn std::error::Error>> {
+    SimpleLogger::init(LevelFilter::Info, Config::default())?;
+    Ok(())
+}
+
+/// Enable or disable the logging of the multiplayer protocol messages
+pub fn set_protocol_logging(enabled: bool) {
+    LOG_PROTOCOL.store(enabled, Ordering::SeqCst);
+}
+
+/// Log a multiplayer protocol message at info level, only when protocol logging is enabled
+pub fn log_protocol_message(direction: &str, message: &str) {
+    if LOG_PROTOCOL.load(Ordering::SeqCst) {
+        log::info!("[protocol] {} {:?}", direction, message);
+    }
+}
@@ -2,19 +2,32 @@
 extern crate chess_tui;
 
 use chess_tui::app::{App, AppResult};
-use chess_tui::constants::{home_dir, DisplayMode};
+use chess_tui::constants::{
+    config_folder, CoordinatesDisplay, DisplayMode, HistoryNotation, Pages, Popups,
+    DEMO_RANDOM_MOVES, MIN_NETWORK_PORT, MOVE_ANIMATION_TICK_RATE, NETWORK_PORT,
+};
 use chess_tui::event::{Event, EventHandler};
+use chess_tui::game_logic::board::random_chess960_position;
+use chess_tui::game_logic::bot::EngineOptions;
 use chess_tui::game_logic::game::GameState;
-use chess_tui::game_logic::opponent::wait_for_game_start;
+use chess_tui::game_logic::game_board::GameBoard;
+use chess_tui::game_logic::opponent::{wait_for_game_start, Connection};
 use chess_tui::handler::{handle_key_events, handle_mouse_events};
+use chess_tui::i18n::{self, Language};
+use chess_tui::keybindings::KeyBindings;
+use chess_tui::last_game::SavedGame;
 use chess_tui::logging;
+#[cfg(feature = "headless")]
+use chess_tui::server::headless::run_headless_host;
 use chess_tui::ui::tui::Tui;
+use chess_tui::utils::network_port;
 use clap::Parser;
 use log::LevelFilter;
 use std::fs::{self, File};
 use std::io::Write;
 use std::panic;
 use std::path::Path;
+use std::time::Duration;
 use toml::Value;
 
 /// Simple program to greet a person
@@ -24,26 +37,72 @@ struct Args {
     /// Path for the chess engine
     #[arg(short, long, default_value = "")]
     engine_path: String,
+    /// Start a solo game from a position in the Forsyth-Edwards Notation
+    #[arg(long, conflicts_with = "chess960")]
+    fen: Option<String>,
+    /// Start a solo game from a Chess960 starting position (0 to 959), a random one without a number
+    #[arg(long, value_parser = clap::value_parser!(u16).range(0..960))]
+    chess960: Option<Option<u16>>,
+    /// Watch two instances of the engine play each other
+    #[arg(long)]
+    bot_vs_bot: bool,
+    /// Pause between the moves of a bot versus bot game, in milliseconds
+    #[arg(long, default_value_t = 1000)]
+    bot_vs_bot_delay: u64,
+    /// Log every multiplayer protocol message (needs log_level to be at least INFO)
+    #[arg(long)]
+    log_protocol: bool,
+    /// Port to host the multiplayer games on, from 1024 (2308 by default)
+    #[arg(long, value_parser = clap::value_parser!(u16).range(MIN_NETWORK_PORT as i64..))]
+    port: Option<u16>,
+    /// Only relay a multiplayer game between two joining players, without interface
+    #[cfg(feature = "headless")]
+    #[arg(long)]
+    headless_host: bool,
 }
 
 fn main() -> AppResult<()> {
+    // Parse the cli arguments
+    let args = Args::parse();
+
+    // The headless server never touches the terminal
+    #[cfg(feature = "headless")]
+    if args.headless_host {
+        logging::setup_headless_logging()?;
+        logging::set_protocol_logging(args.log_protocol);
+        run_headless_host(args.port.unwrap_or(NETWORK_PORT));
+        return Ok(());
+    }
+
+    // A wrong position is reported before the terminal is taken over
+    let fen_board = match args.fen.as_deref().map(GameBoard::from_fen).transpose() {
+        Ok(fen_board) => fen_board,
+        Err(error) => {
+            eprintln!("Invalid FEN: {error}");
+            std::process::exit(1);
+        }
+    };
+
     // Used to enable mouse capture
     ratatui::crossterm::execute!(
         std::io::stdout(),
         ratatui::crossterm::event::EnableMouseCapture
     )?;
-    // Parse the cli arguments
-    let args = Args::parse();
 
-    let home_dir = home_dir()?;
-    let folder_path = home_dir.join(".config/chess-tui");
-    let config_path = home_dir.join(".config/chess-tui/config.toml");
+    let folder_path = config_folder()?;
+    let config_path = folder_path.join("config.toml");
 
     // Create the configuration file
     config_create(&args, &folder_path, &config_path)?;
 
     // Create an application.
     let mut app = App::default();
+    let mut startup_page = None;
+    let mut keybindings = None;
+    let mut engine_options = None;
+    let mut config_port = None;
+    // The --log-protocol flag turns it on whatever the configuration says
+    let mut log_protocol = args.log_protocol;
 
     // We store the chess engine path if there is one
     if let Ok(content) = fs::read_to_string(config_path) {
@@ -54,13 +113,108 @@ fn main() -> AppResult<()> {
             if let Some(engine_path) = config.get("engine_path") {
                 app.chess_engine_path = Some(engine_path.as_str().unwrap().to_string());
             }
+            // Keep white's point of view when playing black against the bot
+            if let Some(bot_white_perspective) = config.get("bot_white_perspective") {
+                app.bot_white_perspective = bot_white_perspective.as_bool().unwrap_or(false);
+            }
+            // Turn the board toward the player to move in solo games
+            if let Some(solo_auto_flip) = config.get("solo_auto_flip") {
+                app.solo_auto_flip = solo_auto_flip.as_bool().unwrap_or(true);
+            }
+            // Random moves played at once by the demo key of the solo games
+            if let Some(demo_random_moves) = config.get("demo_random_moves") {
+                app.demo_random_moves = demo_random_moves
+                    .as_integer()
+                    .and_then(|moves| usize::try_from(moves).ok())
+                    .filter(|&moves| moves > 0)
+                    .unwrap_or(DEMO_RANDOM_MOVES);
+            }
+            // Language of the menu and the popups, English when it isn't translated
+            if let Some(language) = config.get("language") {
+                i18n::set_language(
+                    language
+                        .as_str()
+                        .and_then(Language::from_code)
+                        .unwrap_or(Language::English),
+                );
+            }
+            // Key opening the help popup, a single character
+            if let Some(help_key) = config.get("help_key") {
+                let mut chars = help_key.as_str().unwrap_or_default().chars();
+                if let (Some(key), None) = (chars.next(), chars.next()) {
+                    app.help_key = key;
+                }
+            }
+            // The action keys, the engine search options, the multiplayer port and the startup page
+            // are applied once the logging is set up, so the logs can tell about the wrong ones
+            keybindings = config.get("keybindings").cloned();
+            engine_options = Some(config.clone());
+            config_port = config.get("port").cloned();
+            startup_page = config
+                .get("startup_page")
+                .and_then(|startup_page| startup_page.as_str())
+                .map(str::to_string);
+            // Announce the hosted games on the local network and list them when joining
+            if let Some(lan_discovery) = config.get("lan_discovery") {
+                app.lan_discovery = lan_discovery.as_bool().unwrap_or(false);
+            }
+            // Let the engine think while the player is thinking
+            if let Some(engine_ponder) = config.get("engine_ponder") {
+                app.engine_ponder = engine_ponder.as_bool().unwrap_or(false);
+            }
+            // Evaluate the positions of the games against the bot with a second engine
+            if let Some(engine_evaluation) = config.get("engine_evaluation") {
+                app.engine_evaluation = engine_evaluation.as_bool().unwrap_or(false);
+            }
             // Set the display mode based on the configuration file
             if let Some(display_mode) = config.get("display_mode") {
-                app.game.ui.display_mode = match display_mode.as_str() {
-                    Some("ASCII") => DisplayMode::ASCII,
-                    _ => DisplayMode::DEFAULT,
+                app.game.ui.display_mode = parse_display_mode(display_mode);
+            }
+            // Slide the pieces when they move
+            if let Some(move_animation) = config.get("move_animation") {
+                app.game.ui.move_animation = move_animation.as_bool().unwrap_or(false);
+            }
+            // Turn the board over a few frames when it flips
+            if let Some(flip_animation) = config.get("flip_animation") {
+                app.game.ui.flip_animation = flip_animation.as_bool().unwrap_or(false);
+            }
+            // End the game as soon as a draw can be claimed
+            if let Some(auto_claim_draws) = config.get("auto_claim_draws") {
+                app.game.game_board.auto_claim_draws = auto_claim_draws.as_bool().unwrap_or(true);
+            }
+            // End the game on a threefold repetition, the player is asked to claim it otherwise
+            if let Some(auto_claim_repetition) = config.get("auto_claim_repetition") {
+                app.game.game_board.auto_claim_repetition =
+                    auto_claim_repetition.as_bool().unwrap_or(true);
+            }
+            // Show the captured pieces panels
+            if let Some(show_captured) = config.get("show_captured") {
+                app.game.ui.show_captured = show_captured.as_bool().unwrap_or(true);
+            }
+            // Files and ranks written along the board
+            if let Some(show_coordinates) = config.get("show_coordinates") {
+                app.game.ui.show_coordinates = show_coordinates.as_bool().unwrap_or(true);
+            }
+            // File and rank labels drawn around the board in presentation mode
+            if let Some(coordinates_display) = config.get("coordinates_display") {
+                app.game.ui.coordinates_display = match coordinates_display.as_str() {
+                    Some("hover") => CoordinatesDisplay::Hover,
+                    _ => CoordinatesDisplay::Always,
+                };
+            }
+            // Notation used in the history panel
+            if let Some(history_notation) = config.get("history_notation") {
+                app.game.ui.history_notation = match history_notation.as_str() {
+                    Some("san") => HistoryNotation::San,
+                    _ => HistoryNotation::Coordinate,
                 };
             }
+            // Display modes used in a specific game mode, the global one is used when they are not set
+            app.solo_display_mode = config.get("solo_display_mode").map(parse_display_mode);
+            app.bot_display_mode = config.get("bot_display_mode").map(parse_display_mode);
+            app.multiplayer_display_mode = config
+                .get("multiplayer_display_mode")
+                .map(parse_display_mode);
             // Add log level handling
             if let Some(log_level) = config.get("log_level") {
                 app.log_level = log_level
@@ -68,6 +222,10 @@ fn main() -> AppResult<()> {
                     .and_then(|s| s.parse().ok())
                     .unwrap_or(LevelFilter::Off);
             }
+            // Protocol logging is kept apart from the log level so the normal logs stay clean
+            if let Some(config_log_protocol) = config.get("log_protocol") {
+                log_protocol |= config_log_protocol.as_bool().unwrap_or(false);
+            }
         }
     } else {
         println!("Error reading the file or the file does not exist");
@@ -77,10 +235,65 @@ fn main() -> AppResult<()> {
     if let Err(e) = logging::setup_logging(&folder_path, &app.log_level) {
         eprintln!("Failed to initialize logging: {}", e);
     }
+    logging::set_protocol_logging(log_protocol);
+
+    if let Some(keybindings) = &keybindings {
+        app.keybindings = KeyBindings::from_config(keybindings, app.help_key);
+    }
+    // The help key is handled before the actions, it can't take the key of one of them
+    if app.keybindings.is_used(app.help_key) {
+        log::warn!(
+            "The help key {} is already used, the help is opened with ?",
+            app.help_key
+        );
+        app.help_key = '?';
+        if let Some(keybindings) = &keybindings {
+            app.keybindings = KeyBindings::from_config(keybindings, app.help_key);
+        }
+    }
+
+    if let Some(engine_options) = engine_options {
+        app.engine_options = EngineOptions::from_config(&engine_options);
+    }
+
+    // The port given on the command line is used instead of the configured one
+    if let Some(port) = args.port {
+        app.network_port = port;
+    } else if let Some(port) = config_port {
+        match port.as_integer().and_then(network_port) {
+            Some(port) => app.network_port = port,
+            None => log::warn!(
+                "The port {port} isn't between {MIN_NETWORK_PORT} and 65535, {NETWORK_PORT} is used"
+            ),
+        }
+    }
+
+    // The solo game left unfinished on the last exit is offered in the menu
+    app.last_game = SavedGame::load(&folder_path);
+
+    // Open the configured startup page instead of the menu
+    if let Some(startup_page) = startup_page {
+        app.open_startup_page(&startup_page);
+    }
+
+    // The position given on the command line is played instead of the startup page
+    if let Some(fen_board) = fen_board {
+        app.start_position_game(fen_board);
+    } else if let Some(position) = args.chess960 {
+        app.start_chess960_game(position.unwrap_or_else(random_chess960_position));
+    } else if args.bot_vs_bot {
+        app.start_bot_vs_bot(Duration::from_millis(args.bot_vs_bot_delay));
+    }
 
     // Initialize the terminal user interface.
     let terminal = ratatui::try_init()?;
-    let events = EventHandler::new(250);
+    // The animations need more frames than the rest of the interface
+    let tick_rate = if app.game.ui.move_animation || app.game.ui.flip_animation {
+        MOVE_ANIMATION_TICK_RATE
+    } else {
+        250
+    };
+    let events = EventHandler::new(tick_rate);
     let mut tui = Tui::new(terminal, events);
 
     let default_panic = std::panic::take_hook();
@@ -133,21 +346,34 @@ fn main() -> AppResult<()> {
             app.current_popup = None;
         }
 
-        // If it's the opponent turn, wait for the opponent to move
+        // If it's the opponent turn, play his move once it arrived, the keys prepare a premove until then
+        // The ticks connect again when the connection dropped
         if app.game.opponent.is_some()
-            && app
-                .game
-                .opponent
-                .as_ref()
-                .is_some_and(|opponent| opponent.opponent_will_move)
+            && app.game.opponent.as_ref().is_some_and(|opponent| {
+                opponent.opponent_will_move
+                    && opponent.connection == Connection::Connected
+                    && opponent.has_message()
+            })
         {
             tui.draw(&mut app)?;
 
+            // A draw offer message is not a move, the turn stays the same
             if !app.game.game_board.is_checkmate(app.game.player_turn)
                 && !app.game.game_board.is_draw(app.game.player_turn)
+                && app.game.execute_opponent_move()
             {
-                app.game.execute_opponent_move();
                 app.game.switch_player_turn();
+                app.game.play_premove();
+            }
+
+            // A draw offer has to be answered before the game goes on
+            if app
+                .game
+                .opponent
+                .as_ref()
+                .is_some_and(|opponent| opponent.draw_offer_received)
+            {
+                app.current_popup = Some(Popups::DrawOffer);
             }
 
             // need to be centralised
@@ -160,6 +386,18 @@ fn main() -> AppResult<()> {
         }
     }
 
+    // Quitting ends the multiplayer game, the opponent doesn't wait for us to join again
+    if let Some(opponent) = app.game.opponent.as_mut() {
+        opponent.send_end_game_to_server();
+    }
+
+    // Keep the unfinished solo game to resume it, the network and bot games can't be resumed
+    // A solo game left for the menu was kept when it was left
+    if app.current_page == Pages::Solo {
+        app.last_game = SavedGame::from_game(&app.game);
+    }
+    SavedGame::save(app.last_game.as_ref(), &folder_path);
+
     // Exit the user interface.
     ratatui::try_restore()?;
     // Free up the mouse, otherwise it will remain linked to the terminal
@@ -203,12 +441,69 @@ fn config_create(args: &Args, folder_path: &Path, config_path: &Path) -> AppResu
                 Value::String(args.engine_path.clone()),
             );
         }
+        table
+            .entry("language".to_string())
+            .or_insert(Value::String(Language::English.code().to_string()));
+        table
+            .entry("help_key".to_string())
+            .or_insert(Value::String("?".to_string()));
+        table
+            .entry("startup_page".to_string())
+            .or_insert(Value::String("home".to_string()));
+        table
+            .entry("engine_ponder".to_string())
+            .or_insert(Value::Boolean(false));
+        table
+            .entry("engine_evaluation".to_string())
+            .or_insert(Value::Boolean(false));
+        table
+            .entry("bot_white_perspective".to_string())
+            .or_insert(Value::Boolean(false));
+        table
+            .entry("solo_auto_flip".to_string())
+            .or_insert(Value::Boolean(true));
         table
             .entry("display_mode".to_string())
             .or_insert(Value::String("DEFAULT".to_string()));
+        table
+            .entry("auto_claim_draws".to_string())
+            .or_insert(Value::Boolean(true));
+        table
+            .entry("auto_claim_repetition".to_string())
+            .or_insert(Value::Boolean(true));
+        table
+            .entry("show_captured".to_string())
+            .or_insert(Value::Boolean(true));
+        table
+            .entry("show_coordinates".to_string())
+            .or_insert(Value::Boolean(true));
+        table
+            .entry("coordinates_display".to_string())
+            .or_insert(Value::String(CoordinatesDisplay::Always.to_string()));
+        table
+            .entry("move_animation".to_string())
+            .or_insert(Value::Boolean(false));
+        table
+            .entry("flip_animation".to_string())
+            .or_insert(Value::Boolean(false));
+        table
+            .entry("history_notation".to_string())
+            .or_insert(Value::String(HistoryNotation::Coordinate.to_string()));
         table
             .entry("log_level".to_string())
             .or_insert(Value::String(LevelFilter::Off.to_string()));
+        table
+            .entry("log_protocol".to_string())
+            .or_insert(Value::Boolean(false));
+        table
+            .entry("port".to_string())
+            .or_insert(Value::Integer(NETWORK_PORT.into()));
+        table
+            .entry("lan_discovery".to_string())
+            .or_insert(Value::Boolean(false));
+        table
+            .entry("keybindings".to_string())
+            .or_insert(KeyBindings::default().to_config());
     }
 
     let mut file = File::create(config_path)?;
@@ -217,9 +512,18 @@ fn config_create(args: &Args, folder_path: &Path, config_path: &Path) -> AppResu
     Ok(())
 }
 
+// Read a display mode from the configuration file, anything else than "ASCII" is the default one
+fn parse_display_mode(value: &Value) -> DisplayMode {
+    match value.as_str() {
+        Some("ASCII") => DisplayMode::ASCII,
+        _ => DisplayMode::DEFAULT,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chess_tui::constants::home_dir;
     use std::fs;
     use toml::Value;
 
@@ -227,6 +531,14 @@ mod tests {
     fn test_config_create() {
         let args = Args {
             engine_path: "test_engine_path".to_string(),
+            fen: None,
+            chess960: None,
+            bot_vs_bot: false,
+            bot_vs_bot_delay: 1000,
+            log_protocol: false,
+            port: None,
+            #[cfg(feature = "headless")]
+            headless_host: false,
         };
 
         let home_dir = home_dir().expect("Failed to get home directory");
@@ -250,6 +562,10 @@ mod tests {
             table.get("display_mode").unwrap().as_str().unwrap(),
             "DEFAULT"
         );
+        assert_eq!(
+            KeyBindings::from_config(table.get("keybindings").unwrap(), '?'),
+            KeyBindings::default()
+        );
         let removed = fs::remove_dir_all(home_dir.join(".test"));
         assert!(removed.is_ok());
     }
@@ -1,4 +1,4 @@
-use super::{Movable, PieceColor, PieceType, Position};
+use super::{Movable, PieceColor, Position};
 use crate::constants::DisplayMode;
 use crate::game_logic::coord::Coord;
 use crate::game_logic::game_board::GameBoard;
@@ -48,46 +48,12 @@ impl Position for King {
         let mut positions: Vec<Coord> = vec![];
         let checked_cells = game_board.get_all_protected_cells(color);
 
-        let rook_big_castle_x = 0;
-        let rook_small_castle_x = 7;
-        let king_row = 7;
-        let king_col = if color == PieceColor::White { 4 } else { 3 };
-
-        // We check the condition for small and big castling
-        if !game_board.did_piece_already_move((
-            Some(PieceType::King),
-            Some(color),
-            Coord::new(king_row, king_col),
-        )) && !is_king_checked
-        {
-            // We check if there is no pieces between tower and king
-            // Big castle check
-            if !game_board.did_piece_already_move((
-                Some(PieceType::Rook),
-                Some(color),
-                Coord::new(king_row, rook_big_castle_x),
-            )) && King::check_castling_condition(
-                game_board,
-                color,
-                0,
-                king_col as i8 - 1,
-                &checked_cells,
-            ) {
-                positions.push(Coord::new(king_row, 0));
-            }
-            // Small castle check
-            if !game_board.did_piece_already_move((
-                Some(PieceType::Rook),
-                Some(color),
-                Coord::new(king_row, rook_small_castle_x),
-            )) && King::check_castling_condition(
-                game_board,
-                color,
-                king_col as i8 + 1,
-                7,
-                &checked_cells,
-            ) {
-                positions.push(Coord::new(king_row, 7));
+        // The castlings are played as the king going to the rook, with the Chess960 rules the usual castlings follow too
+        if !is_king_checked {
+            for rook in game_board.castling_rooks(color).into_iter().flatten() {
+                if rook.row == coordinates.row && game_board.can_castle(*coordinates, rook, color) {
+                    positions.push(rook);
+                }
             }
         }
 
@@ -128,34 +94,4 @@ impl King {
             DisplayMode::ASCII => "K",
         }
     }
-
-    // Check if nothing is in between the king and a rook and if none of those cells are getting checked
-    pub fn check_castling_condition(
-        game_board: &GameBoard,
-        color: PieceColor,
-        start: i8,
-        end: i8,
-        checked_cells: &[Coord],
-    ) -> bool {
-        let king_row = 7;
-
-        let mut valid_for_castling = true;
-
-        for i in start..=end {
-            let new_coordinates = Coord::new(king_row, i as u8);
-
-            if checked_cells.contains(&new_coordinates) {
-                valid_for_castling = false;
-            }
-            if (i == 7 || i == 0)
-                && (game_board.get_piece_type(&new_coordinates) != Some(PieceType::Rook)
-                    || !is_cell_color_ally(game_board, &new_coordinates, color))
-                || (i != 7 && i != 0 && game_board.get_piece_type(&new_coordinates).is_some())
-            {
-                valid_for_castling = false;
-            }
-        }
-
-        valid_for_castling
-    }
 }
@@ -119,6 +119,17 @@ impl PieceType {
         }
     }
 
+    /// The usual value of the piece in pawns, the king can't be taken so it is worth nothing
+    pub fn material_value(self) -> i32 {
+        match self {
+            PieceType::Pawn => 1,
+            PieceType::Knight | PieceType::Bishop => 3,
+            PieceType::Rook => 5,
+            PieceType::Queen => 9,
+            PieceType::King => 0,
+        }
+    }
+
     pub fn piece_type_to_string_enum(
         piece_type: Option<PieceType>,
         display_mode: &DisplayMode,
@@ -14,8 +14,10 @@ impl Movable for Pawn {
         allow_move_on_ally_positions: bool,
     ) -> Vec<Coord> {
         // Pawns can only move in one direction depending of their color
-        // -1 we go up
-        let direction: i8 = if allow_move_on_ally_positions { 1 } else { -1 };
+        // -1 we go up, for the pawns at the bottom of the board
+        let is_at_bottom = game_board.is_at_bottom(color);
+        let direction: i8 = if is_at_bottom { -1 } else { 1 };
+        let start_row = if is_at_bottom { 6 } else { 1 };
 
         let mut positions: Vec<Coord> = vec![];
 
@@ -44,7 +46,7 @@ impl Movable for Pawn {
                 && game_board
                     .get_piece_color(&new_coordinates_front_two)
                     .is_none()
-                && (y == 6)
+                && (y == start_row)
             {
                 positions.push(new_coordinates_front_two);
             }
@@ -94,7 +96,10 @@ impl Movable for Pawn {
             }
         }
 
-        // We check for en passant
+        // We check for en passant, the history cells are the ones of the player at the bottom
+        if !is_at_bottom {
+            return cleaned_positions(&positions);
+        }
         if let Some(latest_move) = game_board.move_history.last() {
             let number_of_cells_move = latest_move.to.row as i8 - latest_move.from.row as i8;
 
@@ -111,6 +116,20 @@ impl Movable for Pawn {
                 let new_x = last_coords.col;
                 positions.push(Coord::new(new_y, new_x));
             }
+        } else if let Some(target) = game_board.first_en_passant {
+            // The position was set up with an en passant target, its cell has the white pieces at the bottom
+            let target = if game_board.is_flipped {
+                invert_position(&target)
+            } else {
+                target
+            };
+            let passed_pawn = Coord::new(target.row + 1, target.col);
+            if y == passed_pawn.row
+                && x.abs_diff(target.col) == 1
+                && game_board.board[&passed_pawn] == Some((PieceType::Pawn, color.opposite()))
+            {
+                positions.push(target);
+            }
         }
         cleaned_positions(&positions)
     }
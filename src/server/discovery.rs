@@ -0,0 +1,173 @@
+use std::{
+    collections::hash_map::RandomState,
+    hash::BuildHasher,
+    net::{IpAddr, Ipv4Addr, SocketAddr, UdpSocket},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc, Arc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::pieces::PieceColor;
+
+/// UDP port the hosts announce their games on, next to the game port
+pub const DISCOVERY_PORT: u16 = 2309;
+/// Time the join popup listens to the announces, a few of them are sent meanwhile
+pub const DISCOVERY_TIMEOUT: Duration = Duration::from_secs(2);
+/// Time between two announces of a hosted game
+const ANNOUNCE_INTERVAL: Duration = Duration::from_millis(500);
+/// First word of the announces, the other datagrams received on the port are ignored
+const BEACON_PREFIX: &str = "chess-tui";
+
+/// A game hosted on the local network, waiting for a player to join
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiscoveredHost {
+    /// Address of the game server, the one of the announce with the game port
+    pub addr: SocketAddr,
+    /// Random number telling the games of a same machine apart
+    pub game_id: String,
+    /// Color of the host, the joining player gets the other one
+    pub host_color: PieceColor,
+}
+
+impl DiscoveredHost {
+    /// The announce of a hosted game: `chess-tui <game id> <w|b> <port>`
+    pub fn beacon(game_id: &str, host_color: PieceColor, port: u16) -> String {
+        let color = match host_color {
+            PieceColor::White => "w",
+            PieceColor::Black => "b",
+        };
+        format!("{BEACON_PREFIX} {game_id} {color} {port}")
+    }
+
+    /// Read an announce sent from `ip`, `None` when it isn't one
+    pub fn from_beacon(beacon: &str, ip: IpAddr) -> Option<DiscoveredHost> {
+        let mut words = beacon.split_whitespace();
+        if words.next() != Some(BEACON_PREFIX) {
+            return None;
+        }
+        let game_id = words.next()?.to_string();
+        let host_color = match words.next()? {
+            "w" => PieceColor::White,
+            "b" => PieceColor::Black,
+            _ => return None,
+        };
+        let port = words.next()?.parse().ok()?;
+        Some(DiscoveredHost {
+            addr: SocketAddr::new(ip, port),
+            game_id,
+            host_color,
+        })
+    }
+}
+
+/// Listen to the games announced on the local network for `timeout`, each game is listed once
+/// Nothing is found when another scan already listens on this machine
+pub fn discover_hosts(timeout: Duration) -> Vec<DiscoveredHost> {
+    let mut hosts: Vec<DiscoveredHost> = vec![];
+    let socket = match UdpSocket::bind((Ipv4Addr::UNSPECIFIED, DISCOVERY_PORT)) {
+        Ok(socket) => socket,
+        Err(e) => {
+            log::error!("Failed to listen to the hosted games: {}", e);
+            return hosts;
+        }
+    };
+
+    let deadline = Instant::now() + timeout;
+    let mut buffer = [0; 64];
+    while let Some(remaining) = deadline.checked_duration_since(Instant::now()) {
+        if remaining.is_zero() || socket.set_read_timeout(Some(remaining)).is_err() {
+            break;
+        }
+        let Ok((bytes_read, sender)) = socket.recv_from(&mut buffer) else {
+            continue;
+        };
+        let beacon = String::from_utf8_lossy(&buffer[..bytes_read]);
+        if let Some(host) = DiscoveredHost::from_beacon(&beacon, sender.ip()) {
+            if !hosts.contains(&host) {
+                log::info!("Found a game hosted on {}", host.addr);
+                hosts.push(host);
+            }
+        }
+    }
+    hosts
+}
+
+/// Announce a hosted game on the local network until a player joins or the server stops
+pub fn announce_game(
+    host_color: PieceColor,
+    port: u16,
+    game_started: Arc<AtomicBool>,
+    stop_signal: Arc<AtomicBool>,
+) {
+    let socket = match UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 0)) {
+        Ok(socket) => socket,
+        Err(e) => {
+            log::error!("Failed to announce the game: {}", e);
+            return;
+        }
+    };
+    if let Err(e) = socket.set_broadcast(true) {
+        log::error!("Failed to announce the game: {}", e);
+        return;
+    }
+
+    let game_id = format!("{:04x}", RandomState::new().hash_one(port) % 0x10000);
+    let beacon = DiscoveredHost::beacon(&game_id, host_color, port);
+    log::info!("Announcing the game {} on the local network", game_id);
+    while !game_started.load(Ordering::SeqCst) && !stop_signal.load(Ordering::SeqCst) {
+        if let Err(e) = socket.send_to(beacon.as_bytes(), (Ipv4Addr::BROADCAST, DISCOVERY_PORT)) {
+            log::debug!("Failed to send the announce: {}", e);
+        }
+        thread::sleep(ANNOUNCE_INTERVAL);
+    }
+}
+
+/// A scan of the local network running in the background while the join popup is open
+pub struct HostDiscovery {
+    receiver: mpsc::Receiver<Vec<DiscoveredHost>>,
+    /// The games found, `None` until the scan is over
+    pub hosts: Option<Vec<DiscoveredHost>>,
+    /// The highlighted game
+    pub cursor: usize,
+}
+
+impl HostDiscovery {
+    pub fn start(timeout: Duration) -> HostDiscovery {
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            let _ = sender.send(discover_hosts(timeout));
+        });
+        HostDiscovery {
+            receiver,
+            hosts: None,
+            cursor: 0,
+        }
+    }
+
+    /// Collect the games found once the scan is over, without waiting for it
+    pub fn poll(&mut self) {
+        if self.hosts.is_none() {
+            if let Ok(hosts) = self.receiver.try_recv() {
+                self.hosts = Some(hosts);
+            }
+        }
+    }
+
+    pub fn selected(&self) -> Option<&DiscoveredHost> {
+        self.hosts.as_ref()?.get(self.cursor)
+    }
+
+    pub fn cursor_up(&mut self) {
+        self.cursor = self.cursor.saturating_sub(1);
+    }
+
+    pub fn cursor_down(&mut self) {
+        let hosts = self.hosts.as_ref().map_or(0, Vec::len);
+        if self.cursor + 1 < hosts {
+            self.cursor += 1;
+        }
+    }
+}
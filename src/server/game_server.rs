@@ -1,15 +1,23 @@
 use std::{
-    io::{Read, Write},
-    net::{TcpListener, TcpStream},
+    io::{self, Read, Write},
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, TcpListener, TcpStream},
     sync::{
         atomic::{AtomicBool, Ordering},
         mpsc, Arc, Mutex,
     },
     thread,
+    time::{Duration, Instant},
 };
 
 use log;
 
+use super::discovery::announce_game;
+use crate::logging::log_protocol_message;
+use crate::pieces::PieceColor;
+
+/// Time a player whose connection dropped has to join again before the game ends
+const REJOIN_WINDOW: Duration = Duration::from_secs(60);
+
 #[derive(Debug)]
 pub struct Client {
     addr: String,
@@ -21,25 +29,58 @@ pub struct GameServer {
     pub clients: Arc<Mutex<Vec<Client>>>,
     pub client_id: usize,
     pub is_host_white: bool,
+    /// Port the players connect to
+    pub port: u16,
+    /// No local player hosts the game, both players join and receive their color
+    pub is_headless: bool,
     pub stop_signal: Arc<AtomicBool>,
+    /// Both players joined, a player connecting afterwards joins the game again after a dropped connection
+    pub game_started: Arc<AtomicBool>,
+    /// Address of the player whose connection dropped, nobody else can take his seat meanwhile
+    pub dropped_player: Arc<Mutex<Option<IpAddr>>>,
+    /// Announce the game on the local network until the other player joins
+    pub lan_discovery: bool,
 }
 
 impl GameServer {
-    pub fn new(is_host_white: bool) -> Self {
+    pub fn new(is_host_white: bool, port: u16) -> Self {
         Self {
             clients: Arc::new(Mutex::new(vec![])),
             client_id: 0,
             is_host_white,
+            port,
+            is_headless: false,
             stop_signal: Arc::new(AtomicBool::new(false)),
+            game_started: Arc::new(AtomicBool::new(false)),
+            dropped_player: Arc::new(Mutex::new(None)),
+            lan_discovery: false,
+        }
+    }
+
+    /// Server relaying the game of two joining players, the first one to join plays white
+    pub fn new_headless(port: u16) -> Self {
+        Self {
+            is_headless: true,
+            ..Self::new(true, port)
         }
     }
 
     pub fn run(&self) {
-        log::info!("Starting game server on 0.0.0.0:2308");
-        let listener = TcpListener::bind("0.0.0.0:2308").expect("Failed to create listener");
-        listener
-            .set_nonblocking(true)
-            .expect("Failed to set listener to non-blocking");
+        let listeners = bind_listeners(self.port);
+
+        if self.lan_discovery {
+            let host_color = if self.is_host_white {
+                PieceColor::White
+            } else {
+                PieceColor::Black
+            };
+            let (port, game_started, stop_signal) = (
+                self.port,
+                self.game_started.clone(),
+                self.stop_signal.clone(),
+            );
+            thread::spawn(move || announce_game(host_color, port, game_started, stop_signal));
+        }
 
         let state = self.clients.clone();
         let stop_signal = self.stop_signal.clone();
@@ -62,23 +103,55 @@ impl GameServer {
             }
 
             // Handle incoming connections
-            match listener.accept() {
+            match accept(&listeners) {
                 Ok((mut stream, addr)) => {
                     log::info!("New connection from: {}", addr);
                     let state = Arc::clone(&state);
                     let stop_signal = Arc::clone(&stop_signal);
+                    let game_started = Arc::clone(&self.game_started);
+                    let dropped_player = Arc::clone(&self.dropped_player);
                     let color = if self.is_host_white { "w" } else { "b" };
+                    // Without a host the first player is waiting for the color of his opponent, not for the start
+                    let start_message = if !self.is_headless {
+                        "s"
+                    } else if self.is_host_white {
+                        "b"
+                    } else {
+                        "w"
+                    };
 
                     thread::spawn(move || {
                         {
                             let mut state_lock = state.lock().unwrap();
+                            // A player of the game connects again, he already knows his color
+                            if state_lock.len() == 1 && game_started.load(Ordering::SeqCst) {
+                                let mut dropped_player = dropped_player.lock().unwrap();
+                                if *dropped_player != Some(addr.ip().to_canonical()) {
+                                    log::warn!(
+                                        "{} is not the player whose connection dropped, the seat stays free",
+                                        addr
+                                    );
+                                    let _ = stream.write_all("Game is already full".as_bytes());
+                                    return;
+                                }
+                                *dropped_player = None;
+                                log::info!("{} joined the game again", addr);
+                            }
                             // There is already one player (host who choose the color) we will need to send the color to the joining player and inform the host of the game start
-                            if state_lock.len() == 1 {
+                            else if state_lock.len() == 1 {
+                                log_protocol_message(&format!("sent to {}", addr), color);
                                 stream.write_all(color.as_bytes()).unwrap();
                                 let other_player = state_lock.last().unwrap();
                                 let mut other_player_stream =
                                     other_player.stream.try_clone().unwrap();
-                                other_player_stream.write_all("s".as_bytes()).unwrap();
+                                log_protocol_message(
+                                    &format!("sent to {}", other_player.addr),
+                                    start_message,
+                                );
+                                other_player_stream
+                                    .write_all(start_message.as_bytes())
+                                    .unwrap();
+                                game_started.store(true, Ordering::SeqCst);
                             } else if state_lock.len() >= 2 {
                                 stream.write_all("Game is already full".as_bytes()).unwrap();
                                 return;
@@ -89,7 +162,7 @@ impl GameServer {
                                 stream: stream.try_clone().unwrap(),
                             });
                         }
-                        handle_client(state, stop_signal, stream);
+                        handle_client(state, stop_signal, game_started, dropped_player, stream);
                     });
                 }
                 Err(ref e) if e.kind() == std::io::ErrorKind::WouldBlock => {
@@ -103,12 +176,51 @@ impl GameServer {
     }
 }
 
+// Listen on IPv6 and IPv4, a single dual stack socket takes both on most systems
+// The IPv4 socket is only needed where the IPv6 one doesn't take the IPv4 connections, or when there is no IPv6
+fn bind_listeners(port: u16) -> Vec<TcpListener> {
+    let mut listeners = vec![];
+    for addr in [
+        SocketAddr::from((Ipv6Addr::UNSPECIFIED, port)),
+        SocketAddr::from((Ipv4Addr::UNSPECIFIED, port)),
+    ] {
+        match TcpListener::bind(addr) {
+            Ok(listener) => {
+                log::info!("Starting game server on {}", addr);
+                listener
+                    .set_nonblocking(true)
+                    .expect("Failed to set listener to non-blocking");
+                listeners.push(listener);
+            }
+            Err(e) => log::debug!("Not listening on {}: {}", addr, e),
+        }
+    }
+    if listeners.is_empty() {
+        panic!("Failed to create listener on port {}", port);
+    }
+    listeners
+}
+
+// The first connection waiting on one of the listeners, `WouldBlock` when there is none
+fn accept(listeners: &[TcpListener]) -> io::Result<(TcpStream, SocketAddr)> {
+    for listener in listeners {
+        match listener.accept() {
+            Err(ref e) if e.kind() == io::ErrorKind::WouldBlock => {}
+            result => return result,
+        }
+    }
+    Err(io::ErrorKind::WouldBlock.into())
+}
+
 fn handle_client(
     state: Arc<Mutex<Vec<Client>>>,
     stop_signal: Arc<AtomicBool>,
+    game_started: Arc<AtomicBool>,
+    dropped_player: Arc<Mutex<Option<IpAddr>>>,
     mut stream: TcpStream,
 ) {
-    let addr = stream.peer_addr().unwrap().to_string();
+    let peer_addr = stream.peer_addr().unwrap();
+    let addr = peer_addr.to_string();
     log::info!("Starting client handler for: {}", addr);
 
     // Set socket to non-blocking mode
@@ -122,14 +234,15 @@ fn handle_client(
         match stream.read(&mut buffer) {
             Ok(0) => {
                 log::info!("Client {} disconnected", addr);
-                broadcast_message(state.clone(), "ended".to_string(), &addr);
                 remove_client(&state, &addr);
-                stop_signal.store(true, Ordering::SeqCst);
+                *dropped_player.lock().unwrap() = Some(peer_addr.ip().to_canonical());
+                wait_for_rejoin(state, stop_signal, game_started, addr);
                 break;
             }
             Ok(bytes_read) => {
                 let request = String::from_utf8_lossy(&buffer[..bytes_read]);
                 log::debug!("Received message from {}: {}", addr, request.trim());
+                log_protocol_message(&format!("received from {}", addr), &request);
                 broadcast_message(state.clone(), format!("{}", request), &addr);
 
                 if request.trim() == "ended" {
@@ -146,20 +259,58 @@ fn handle_client(
             }
             Err(e) => {
                 log::error!("Error reading from client {}: {}", addr, e);
+                remove_client(&state, &addr);
+                *dropped_player.lock().unwrap() = Some(peer_addr.ip().to_canonical());
+                wait_for_rejoin(state, stop_signal, game_started, addr);
                 break;
             }
         }
     }
 }
 
+// A player left the game without ending it, the game goes on if he joins again in time
+// The game ends for the other player otherwise, as it does before the game starts
+fn wait_for_rejoin(
+    state: Arc<Mutex<Vec<Client>>>,
+    stop_signal: Arc<AtomicBool>,
+    game_started: Arc<AtomicBool>,
+    addr: String,
+) {
+    if game_started.load(Ordering::SeqCst) {
+        log::info!("Waiting for {} to join the game again", addr);
+        let left_at = Instant::now();
+        while left_at.elapsed() < REJOIN_WINDOW {
+            if stop_signal.load(Ordering::SeqCst) || state.lock().unwrap().len() == 2 {
+                return;
+            }
+            thread::sleep(Duration::from_millis(100));
+        }
+        log::info!("{} didn't join the game again", addr);
+    }
+    broadcast_message(state.clone(), "ended".to_string(), &addr);
+    stop_signal.store(true, Ordering::SeqCst);
+}
+
 fn broadcast_message(state: Arc<Mutex<Vec<Client>>>, message: String, sender_addr: &String) {
     let state = state.lock().unwrap();
     for client in state.iter() {
         if &client.addr == sender_addr {
             continue;
         }
-        let mut client_stream = client.stream.try_clone().unwrap();
-        client_stream.write_all(message.as_bytes()).unwrap();
+        // The message is lost for a player whose connection dropped, it is sent again once he joins again
+        let sent = client
+            .stream
+            .try_clone()
+            .and_then(|mut client_stream| client_stream.write_all(message.as_bytes()));
+        match sent {
+            Ok(()) => log_protocol_message(&format!("sent to {}", client.addr), &message),
+            Err(e) => log::error!(
+                "Failed to send {} to {}: {}",
+                message.trim(),
+                client.addr,
+                e
+            ),
+        }
     }
 }
 
@@ -0,0 +1,21 @@
+use super::game_server::GameServer;
+use crate::utils::{get_local_ip, get_local_ipv6};
+use std::net::SocketAddr;
+
+/// Relay a multiplayer game between two players joining from their own chess-tui, without any interface
+/// The first player to join plays white, the server stops when the game ends
+pub fn run_headless_host(port: u16) {
+    let game_server = GameServer::new_headless(port);
+
+    println!(
+        "Waiting for two players, join the game with the address {}",
+        SocketAddr::new(get_local_ip(), port)
+    );
+    if let Some(ipv6) = get_local_ipv6().filter(|&ip| ip != get_local_ip()) {
+        println!("Over IPv6 the address is {}", SocketAddr::new(ipv6, port));
+    }
+    println!("The first player to join plays white, press Ctrl-C to stop the server");
+
+    game_server.run();
+    log::info!("The game is over, stopping the headless server");
+}
@@ -1 +1,5 @@
+pub mod discovery;
 pub mod game_server;
+
+#[cfg(feature = "headless")]
+pub mod headless;
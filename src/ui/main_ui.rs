@@ -3,16 +3,19 @@ use ratatui::{
     prelude::{Alignment, Rect},
     style::{Color, Modifier, Style, Stylize},
     text::Line,
-    widgets::{Block, Paragraph},
+    widgets::{Block, Paragraph, Wrap},
     Frame,
 };
 
 use crate::{
     constants::Popups,
-    game_logic::{bot::Bot, game::GameState},
+    game_logic::{bot::Bot, evaluation::Evaluation, game::GameState, game_board::GameBoard},
+    i18n::t,
     ui::popups::{
-        render_color_selection_popup, render_credit_popup, render_end_popup,
-        render_engine_path_error_popup, render_help_popup, render_promotion_popup,
+        render_checking_engine_popup, render_claim_draw_popup, render_color_selection_popup,
+        render_connection_popup, render_credit_popup, render_draw_offer_popup, render_end_popup,
+        render_engine_not_uci_popup, render_engine_path_error_popup, render_exported_board_popup,
+        render_help_popup, render_promotion_popup, render_startup_page_unavailable_popup,
     },
 };
 
@@ -21,8 +24,9 @@ use super::popups::{
 };
 use crate::{
     app::App,
-    constants::{DisplayMode, Pages, TITLE},
+    constants::{CoordinatesDisplay, DisplayMode, Pages, TITLE, WHITE},
     pieces::PieceColor,
+    utils::col_to_letter,
 };
 
 /// Renders the user interface widgets.
@@ -45,7 +49,7 @@ pub fn render(app: &mut App, frame: &mut Frame<'_>) {
                     app.setup_game_server(app.selected_color.unwrap());
                     app.host_ip = Some("127.0.0.1".to_string());
                 } else {
-                    app.current_popup = Some(Popups::EnterHostIP);
+                    app.open_join_popup();
                 }
             } else {
                 app.create_opponent();
@@ -57,17 +61,29 @@ pub fn render(app: &mut App, frame: &mut Frame<'_>) {
     // Play against bot
     else if app.current_page == Pages::Bot {
         if app.chess_engine_path.is_none() || app.chess_engine_path.as_ref().unwrap().is_empty() {
-            render_engine_path_error_popup(frame);
+            render_engine_path_error_popup(frame, app);
+        } else if app.current_popup == Some(Popups::CheckingEngine) {
+            render_checking_engine_popup(frame, app.chess_engine_path.as_ref().unwrap());
+        } else if app.current_popup == Some(Popups::EngineNotUci) {
+            render_engine_not_uci_popup(frame, app.chess_engine_path.as_ref().unwrap());
         } else if app.selected_color.is_none() {
             app.current_popup = Some(Popups::ColorSelection);
         } else if app.game.bot.is_none() {
             let engine_path = app.chess_engine_path.clone().unwrap();
             let is_bot_starting = app.selected_color.unwrap() == PieceColor::Black;
-            app.game.bot = Some(Bot::new(engine_path.as_str(), is_bot_starting));
+            let mut bot = Bot::new(engine_path.as_str(), is_bot_starting);
+            bot.set_ponder(app.engine_ponder);
+            bot.set_options(&app.engine_options);
+            app.game.bot = Some(bot);
+            app.game.ui.white_perspective = app.bot_white_perspective;
         } else {
             render_game_ui(frame, app, main_area);
         }
     }
+    // Set up a position
+    else if app.current_page == Pages::BoardEditor {
+        render_board_editor_ui(frame, app, main_area);
+    }
     // Render menu
     else {
         render_menu_ui(frame, app, main_area);
@@ -86,13 +102,39 @@ pub fn render(app: &mut App, frame: &mut Frame<'_>) {
             render_multiplayer_selection_popup(frame, app);
         }
         Some(Popups::EnterHostIP) => {
-            render_enter_multiplayer_ip(frame, &app.game.ui.prompt);
+            render_enter_multiplayer_ip(frame, &app.game.ui.prompt, app.host_discovery.as_ref());
         }
         Some(Popups::WaitingForOpponentToJoin) => {
-            render_wait_for_other_player(frame, app.get_host_ip());
+            render_wait_for_other_player(
+                frame,
+                &app.host_address(),
+                app.host_ipv6_address().as_deref(),
+            );
         }
         Some(Popups::Help) => {
-            render_help_popup(frame);
+            render_help_popup(frame, &app.current_page, app.help_key);
+        }
+        Some(Popups::ExportedBoard) => {
+            if let Some(exported_board) = &app.exported_board {
+                render_exported_board_popup(frame, exported_board);
+            }
+        }
+        Some(Popups::DrawOffer) => {
+            render_draw_offer_popup(frame);
+        }
+        Some(Popups::ClaimDraw) => {
+            render_claim_draw_popup(frame);
+        }
+        Some(Popups::Reconnecting) => {
+            render_connection_popup(frame, false);
+        }
+        Some(Popups::ConnectionLost) => {
+            render_connection_popup(frame, true);
+        }
+        Some(Popups::StartupPageUnavailable) => {
+            if let Some(startup_page) = &app.unavailable_startup_page {
+                render_startup_page_unavailable_popup(frame, startup_page);
+            }
         }
         _ => {}
     }
@@ -127,6 +169,55 @@ pub fn render_cell(frame: &mut Frame, square: Rect, color: Color, modifier: Opti
     frame.render_widget(cell, square);
 }
 
+/// The bar is filled from the bottom by the side drawn there, the score is written at the end of the side ahead
+pub fn render_evaluation_bar(
+    frame: &mut Frame,
+    evaluation: Evaluation,
+    is_black_at_bottom: bool,
+    area: Rect,
+) {
+    if area.width == 0 || area.height == 0 {
+        return;
+    }
+
+    let white_share = evaluation.white_share();
+    let bottom_share = if is_black_at_bottom {
+        1.0 - white_share
+    } else {
+        white_share
+    };
+    let bottom_height = (bottom_share * area.height as f64).round() as u16;
+    let (top_color, bottom_color) = if is_black_at_bottom {
+        (Color::White, Color::Black)
+    } else {
+        (Color::Black, Color::White)
+    };
+
+    let top_area = Rect::new(area.x, area.y, area.width, area.height - bottom_height);
+    let bottom_area = Rect::new(
+        area.x,
+        area.y + area.height - bottom_height,
+        area.width,
+        bottom_height,
+    );
+    render_cell(frame, top_area, top_color, None);
+    render_cell(frame, bottom_area, bottom_color, None);
+
+    let is_label_at_bottom = (white_share >= 0.5) != is_black_at_bottom;
+    let (label_y, label_color, label_background) = if is_label_at_bottom {
+        (area.y + area.height - 1, top_color, bottom_color)
+    } else {
+        (area.y, bottom_color, top_color)
+    };
+    frame.render_widget(
+        Paragraph::new(evaluation.label())
+            .alignment(Alignment::Center)
+            .fg(label_color)
+            .bg(label_background),
+        Rect::new(area.x, label_y, area.width, 1),
+    );
+}
+
 // Method to render the home menu and the options
 pub fn render_menu_ui(frame: &mut Frame, app: &App, main_area: Rect) {
     let main_layout_horizontal = Layout::default()
@@ -148,7 +239,7 @@ pub fn render_menu_ui(frame: &mut Frame, app: &App, main_area: Rect) {
     frame.render_widget(title_paragraph, main_layout_horizontal[0]);
 
     // Board block representing the full board div
-    let text: Vec<Line<'_>> = vec![Line::from(""), Line::from("A chess game made in 🦀")];
+    let text: Vec<Line<'_>> = vec![Line::from(""), Line::from(t("menu.subtitle"))];
     let sub_title = Paragraph::new(text)
         .alignment(Alignment::Center)
         .block(Block::default());
@@ -157,21 +248,25 @@ pub fn render_menu_ui(frame: &mut Frame, app: &App, main_area: Rect) {
     // Determine the "display mode" text
     let display_mode_menu = {
         let display_mode = match app.game.ui.display_mode {
-            DisplayMode::DEFAULT => "Default",
+            DisplayMode::DEFAULT => t("menu.display_mode.default"),
             DisplayMode::ASCII => "ASCII",
         };
-        format!("Display mode: {display_mode}")
+        t("menu.display_mode").replace("{}", display_mode)
     };
 
     // Board block representing the full board div
-    let menu_items = [
-        "Normal game",
-        "Multiplayer",
-        "Play against a bot",
+    let mut menu_items = vec![
+        t("menu.solo"),
+        t("menu.multiplayer"),
+        t("menu.bot"),
+        t("menu.board_editor"),
         &display_mode_menu,
-        "Help",
-        "Credits",
+        t("menu.help"),
+        t("menu.credits"),
     ];
+    if app.last_game.is_some() {
+        menu_items.push(t("menu.resume"));
+    }
     let mut menu_body: Vec<Line<'_>> = vec![];
 
     for (i, menu_item) in menu_items.iter().enumerate() {
@@ -194,6 +289,12 @@ pub fn render_menu_ui(frame: &mut Frame, app: &App, main_area: Rect) {
 
 // Method to render the game board and handle game popups
 pub fn render_game_ui(frame: &mut Frame<'_>, app: &mut App, main_area: Rect) {
+    if app.presentation_mode {
+        render_presentation_board(frame, app, main_area);
+        render_game_popups(frame, app);
+        return;
+    }
+
     let main_layout_horizontal = Layout::default()
         .direction(Direction::Vertical)
         .constraints(
@@ -236,52 +337,248 @@ pub fn render_game_ui(frame: &mut Frame<'_>, app: &mut App, main_area: Rect) {
     // We render the board_block in the center layout made above
     frame.render_widget(board_block.clone(), main_layout_vertical[1]);
 
-    let game_clone = app.game.clone();
+    let game_clone = app.game.history_view().unwrap_or_else(|| app.game.clone());
     app.game.ui.board_render(
         board_block.inner(main_layout_vertical[1]),
         frame,
         &game_clone,
     ); // Mutable borrow now allowed
 
-    //top box for white material
-    app.game.ui.black_material_render(
-        board_block.inner(right_box_layout[0]),
-        frame,
-        &app.game.game_board.black_taken_pieces,
+    // Evaluation bar between the board and the side panel
+    if let Some(evaluation) = app
+        .evaluator
+        .as_ref()
+        .and_then(|evaluator| evaluator.evaluation)
+    {
+        let is_black_at_bottom = app.game.game_board.is_flipped != app.game.is_view_rotated();
+        render_evaluation_bar(
+            frame,
+            evaluation,
+            is_black_at_bottom,
+            board_block.inner(main_layout_vertical[2]),
+        );
+    }
+
+    // Status line under the board
+    let status_area = Rect::new(
+        main_layout_vertical[1].x,
+        main_layout_horizontal[2].y,
+        main_layout_vertical[1].width,
+        main_layout_horizontal[2].height,
     );
+    app.game.ui.status_render(status_area, frame, &app.game);
+
+    if app.game.ui.show_captured {
+        //top box for white material
+        app.game.ui.black_material_render(
+            board_block.inner(right_box_layout[0]),
+            frame,
+            &app.game.game_board.black_taken_pieces,
+            app.game.game_board.material_advantage(),
+        );
+
+        // We make the inside of the board
+        app.game
+            .ui
+            .history_render(board_block.inner(right_box_layout[1]), frame, &app.game);
+
+        //bottom box for black matetrial
+        app.game.ui.white_material_render(
+            board_block.inner(right_box_layout[2]),
+            frame,
+            &app.game.game_board.white_taken_pieces,
+            app.game.game_board.material_advantage(),
+            app.help_key,
+        );
+    } else {
+        // Without the captured pieces the history takes the whole column
+        app.game
+            .ui
+            .history_render(board_block.inner(main_layout_vertical[3]), frame, &app.game);
+    }
+
+    render_game_popups(frame, app);
+}
+
+/// Renders the board editor: the position being set up and the keys editing it
+pub fn render_board_editor_ui(frame: &mut Frame<'_>, app: &mut App, main_area: Rect) {
+    let main_layout_horizontal = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            [
+                Constraint::Ratio(1, 18),
+                Constraint::Ratio(16, 18),
+                Constraint::Ratio(1, 18),
+            ]
+            .as_ref(),
+        )
+        .split(main_area);
+
+    let main_layout_vertical = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(
+            [
+                Constraint::Ratio(2, 17),
+                Constraint::Ratio(9, 17),
+                Constraint::Ratio(1, 17),
+                Constraint::Ratio(5, 17),
+            ]
+            .as_ref(),
+        )
+        .split(main_layout_horizontal[1]);
 
-    // We make the inside of the board
+    // The edited position is drawn as the board of a game, with the cursor of the game
+    let mut editor_game = app.game.clone();
+    editor_game.game_board = GameBoard::new(app.board_editor.board, vec![], vec![]);
+    editor_game.player_turn = app.board_editor.player_turn;
     app.game
         .ui
-        .history_render(board_block.inner(right_box_layout[1]), frame, &app.game);
+        .board_render(main_layout_vertical[1], frame, &editor_game);
 
-    //bottom box for black matetrial
-    app.game.ui.white_material_render(
-        board_block.inner(right_box_layout[2]),
-        frame,
-        &app.game.game_board.white_taken_pieces,
-    );
+    let player_turn = match app.board_editor.player_turn {
+        PieceColor::White => t("editor.white"),
+        PieceColor::Black => t("editor.black"),
+    };
+    let mut text = vec![
+        Line::from(t("editor.title").underlined().bold()),
+        Line::from(""),
+        Line::from(t("editor.to_move").replace("{}", player_turn)),
+        Line::from(t("editor.castling").replace("{}", &app.board_editor.castling_fen())),
+        Line::from(""),
+        Line::from(app.board_editor.fen()),
+        Line::from(""),
+    ];
+    if let Some(error) = &app.board_editor.error {
+        text.push(Line::from(error.as_str()).fg(Color::LightRed));
+        text.push(Line::from(""));
+    }
+    text.extend([
+        Line::from(t("editor.cycle")),
+        Line::from(t("editor.clear_cell")),
+        Line::from(t("editor.turn")),
+        Line::from(t("editor.castlings")),
+        Line::from(t("editor.empty")),
+        Line::from(t("editor.start_position")),
+        Line::from(t("editor.play")),
+        Line::from(t("help.back")),
+        Line::from(t("help.toggle").replace("{}", &app.help_key.to_string())),
+    ]);
+
+    let panel = Paragraph::new(text)
+        .block(Block::default())
+        .wrap(Wrap { trim: true });
+    frame.render_widget(panel, main_layout_vertical[3]);
+}
 
+// Method to render the popups related to the game state (promotion, end of the game)
+fn render_game_popups(frame: &mut Frame<'_>, app: &mut App) {
     if app.game.game_state == GameState::Promotion {
         render_promotion_popup(frame, app);
     }
 
+    // The end popup would hide the positions replayed from the history
+    if app.game.ui.history_index.is_some() {
+        return;
+    }
+
     if app.game.game_state == GameState::Checkmate {
         let victorious_player = app.game.player_turn.opposite();
 
-        let string_color = match victorious_player {
-            PieceColor::White => "White",
-            PieceColor::Black => "Black",
+        let sentence = match victorious_player {
+            PieceColor::White => t("end.white_won"),
+            PieceColor::Black => t("end.black_won"),
         };
 
-        render_end_popup(
-            frame,
-            &format!("{string_color} Won !!!"),
-            app.game.opponent.is_some(),
-        );
+        render_end_popup(frame, sentence, app.game.opponent.is_some());
     }
 
     if app.game.game_state == GameState::Draw {
-        render_end_popup(frame, "That's a draw", app.game.opponent.is_some());
+        render_end_popup(frame, t("end.draw"), app.game.opponent.is_some());
+    }
+
+    if let GameState::Resigned(resigning_player) = app.game.game_state {
+        let sentence = match resigning_player {
+            PieceColor::White => t("end.white_resigned"),
+            PieceColor::Black => t("end.black_resigned"),
+        };
+
+        render_end_popup(frame, sentence, app.game.opponent.is_some());
+    }
+}
+
+// Method to render only the board, centered and as large as possible, with minimal coordinates
+pub fn render_presentation_board(frame: &mut Frame<'_>, app: &mut App, main_area: Rect) {
+    // We keep a line below and two columns on the left of the board for the coordinates
+    let cell_height = main_area.height.saturating_sub(2) / 8;
+    // A terminal cell is about twice as high as it is wide
+    let cell_width = (main_area.width.saturating_sub(4) / 8).min(cell_height * 2);
+
+    let board_area = Rect::new(
+        main_area.x + (main_area.width - cell_width * 8) / 2,
+        main_area.y + (main_area.height - cell_height * 8) / 2,
+        cell_width * 8,
+        cell_height * 8,
+    );
+
+    // The labels are drawn outside of the cells here, where the board leaves room for them
+    let show_coordinates = app.game.ui.show_coordinates;
+    app.game.ui.show_coordinates = false;
+    let game_clone = app.game.history_view().unwrap_or_else(|| app.game.clone());
+    app.game.ui.board_render(board_area, frame, &game_clone);
+    app.game.ui.show_coordinates = show_coordinates;
+    if !show_coordinates {
+        return;
+    }
+
+    // The black pieces are drawn at the bottom
+    let is_flipped = app.game.game_board.is_flipped != app.game.is_view_rotated();
+
+    // Drawn row and column of the cursor, the hover mode only labels them
+    let cursor = app.game.ui.cursor_coordinates;
+    let hovered = if !cursor.is_valid() || app.game.ui.mouse_used {
+        None
+    } else if app.game.is_view_rotated() {
+        Some((7 - cursor.row as u16, 7 - cursor.col as u16))
+    } else {
+        Some((cursor.row as u16, cursor.col as u16))
+    };
+    let label_style = |is_hovered: bool| match app.game.ui.coordinates_display {
+        CoordinatesDisplay::Always => Some(Style::default().fg(WHITE)),
+        CoordinatesDisplay::Hover if is_hovered => Some(
+            Style::default()
+                .fg(Color::LightBlue)
+                .add_modifier(Modifier::BOLD),
+        ),
+        CoordinatesDisplay::Hover => None,
+    };
+
+    for i in 0..8u16 {
+        let rank = if is_flipped { i + 1 } else { 8 - i };
+        let file = if is_flipped { 7 - i } else { i };
+        let rank_style = label_style(hovered.is_some_and(|(row, _)| row == i));
+        let file_style = label_style(hovered.is_some_and(|(_, col)| col == i));
+
+        let rank_area = Rect::new(
+            board_area.x.saturating_sub(2),
+            board_area.y + i * cell_height + cell_height / 2,
+            1,
+            1,
+        );
+        if let Some(style) = rank_style {
+            frame.render_widget(Paragraph::new(rank.to_string()).style(style), rank_area);
+        }
+
+        let file_area = Rect::new(
+            board_area.x + i * cell_width + cell_width / 2,
+            board_area.y + board_area.height,
+            1,
+            1,
+        );
+        if let Some(style) = file_style {
+            frame.render_widget(
+                Paragraph::new(col_to_letter(file as u8)).style(style),
+                file_area,
+            );
+        }
     }
 }
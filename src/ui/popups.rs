@@ -1,9 +1,9 @@
-use std::net::IpAddr;
-
 use crate::{
     app::App,
-    constants::WHITE,
-    pieces::{bishop::Bishop, knight::Knight, pawn::Pawn, queen::Queen, rook::Rook},
+    constants::{Pages, WHITE},
+    i18n::t,
+    pieces::{bishop::Bishop, knight::Knight, pawn::Pawn, queen::Queen, rook::Rook, PieceColor},
+    server::discovery::HostDiscovery,
     ui::main_ui::centered_rect,
 };
 use ratatui::{
@@ -17,24 +17,241 @@ use ratatui::{
 use super::prompt::Prompt;
 
 // This renders a popup when the selected game mode is bot and there is no chess engine path
-pub fn render_engine_path_error_popup(frame: &mut Frame) {
+// The engines found on the system are listed so one can be picked
+pub fn render_engine_path_error_popup(frame: &mut Frame, app: &App) {
     let block = Block::default()
-        .title("Error")
+        .title(t("popup.error"))
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
         .padding(Padding::horizontal(1))
         .border_style(Style::default().fg(WHITE));
     let area = centered_rect(40, 40, frame.area());
 
-    let text = vec![
-        Line::from("You didn't specify the chess engine path").alignment(Alignment::Center),
+    let mut text = vec![
+        Line::from(t("engine.missing")).alignment(Alignment::Center),
         Line::from(""),
-        Line::from("To do so use the -e argument when running chess-tui to store an engine path"),
+        Line::from(t("engine.use_argument")),
         Line::from(""),
-        Line::from("Example: "),
+        Line::from(t("engine.example")),
         Line::from("chess-tui -e /opt/homebrew/opt/stockfish"),
     ];
 
+    if !app.detected_engines.is_empty() {
+        text.push(Line::from(""));
+        text.push(Line::from(t("engine.detected")));
+        text.push(Line::from(""));
+        for (i, engine_path) in app.detected_engines.iter().enumerate() {
+            let style = if i == app.menu_cursor as usize {
+                Style::default().add_modifier(Modifier::UNDERLINED | Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+            text.push(Line::from(Span::styled(
+                engine_path.to_string_lossy().to_string(),
+                style,
+            )));
+        }
+        text.push(Line::from(""));
+        text.push(Line::from(t("engine.use_selected")));
+    }
+
+    let paragraph = Paragraph::new(text)
+        .block(block.clone())
+        .alignment(Alignment::Left)
+        .wrap(Wrap { trim: true });
+
+    frame.render_widget(Clear, area); //this clears out the background
+    frame.render_widget(block, area);
+    frame.render_widget(paragraph, area);
+}
+
+// This renders a popup when the chess engine doesn't answer to the UCI handshake
+pub fn render_engine_not_uci_popup(frame: &mut Frame, engine_path: &str) {
+    let block = Block::default()
+        .title(t("popup.error"))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .padding(Padding::horizontal(1))
+        .border_style(Style::default().fg(WHITE));
+    let area = centered_rect(40, 40, frame.area());
+
+    let text = vec![
+        Line::from(t("engine.not_uci")).alignment(Alignment::Center),
+        Line::from(""),
+        Line::from(t("engine.no_uciok").replace("{}", engine_path)),
+        Line::from(""),
+        Line::from(t("engine.use_other")),
+        Line::from(""),
+        Line::from(t("popup.back_to_menu")),
+    ];
+
+    let paragraph = Paragraph::new(text)
+        .block(block.clone())
+        .alignment(Alignment::Left)
+        .wrap(Wrap { trim: true });
+
+    frame.render_widget(Clear, area); //this clears out the background
+    frame.render_widget(block, area);
+    frame.render_widget(paragraph, area);
+}
+
+// This renders a popup while the chess engine is checked
+pub fn render_checking_engine_popup(frame: &mut Frame, engine_path: &str) {
+    let block = Block::default()
+        .title(t("engine.checking_title"))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .padding(Padding::horizontal(1))
+        .border_style(Style::default().fg(WHITE));
+    let area = centered_rect(40, 40, frame.area());
+
+    let text = vec![
+        Line::from(t("engine.checking").replace("{}", engine_path)).alignment(Alignment::Center),
+        Line::from(""),
+        Line::from(t("popup.back_to_menu")),
+    ];
+
+    let paragraph = Paragraph::new(text)
+        .block(block.clone())
+        .alignment(Alignment::Left)
+        .wrap(Wrap { trim: true });
+
+    frame.render_widget(Clear, area); //this clears out the background
+    frame.render_widget(block, area);
+    frame.render_widget(paragraph, area);
+}
+
+// This renders a popup with the exported board when it couldn't be copied to the clipboard
+pub fn render_exported_board_popup(frame: &mut Frame, exported_board: &str) {
+    let block = Block::default()
+        .title(t("export.title"))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .padding(Padding::horizontal(1))
+        .border_style(Style::default().fg(WHITE));
+    let area = centered_rect(40, 50, frame.area());
+
+    let mut text = vec![
+        Line::from(t("export.no_clipboard")).alignment(Alignment::Center),
+        Line::from(""),
+    ];
+    text.extend(exported_board.lines().map(Line::from));
+    text.push(Line::from(""));
+    text.push(Line::from(t("popup.close")).alignment(Alignment::Center));
+
+    let paragraph = Paragraph::new(text)
+        .block(block.clone())
+        .alignment(Alignment::Left);
+
+    frame.render_widget(Clear, area); //this clears out the background
+    frame.render_widget(block, area);
+    frame.render_widget(paragraph, area);
+}
+
+// This renders a popup explaining why the configured startup page wasn't opened
+pub fn render_startup_page_unavailable_popup(frame: &mut Frame, startup_page: &str) {
+    let block = Block::default()
+        .title(t("startup.title"))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .padding(Padding::horizontal(1))
+        .border_style(Style::default().fg(WHITE));
+    let area = centered_rect(40, 40, frame.area());
+
+    let text = vec![
+        Line::from(t("startup.unavailable").replace("{}", startup_page))
+            .alignment(Alignment::Center),
+        Line::from(""),
+        Line::from(t("startup.accepted")),
+        Line::from(""),
+        Line::from(t("popup.back_to_menu")),
+    ];
+
+    let paragraph = Paragraph::new(text)
+        .block(block.clone())
+        .alignment(Alignment::Left)
+        .wrap(Wrap { trim: true });
+
+    frame.render_widget(Clear, area); //this clears out the background
+    frame.render_widget(block, area);
+    frame.render_widget(paragraph, area);
+}
+
+// This renders the popup answering a draw offered by the opponent
+pub fn render_draw_offer_popup(frame: &mut Frame) {
+    let block = Block::default()
+        .title(t("draw_offer.title"))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .padding(Padding::horizontal(1))
+        .border_style(Style::default().fg(WHITE));
+    let area = centered_rect(40, 40, frame.area());
+
+    let text = vec![
+        Line::from(t("draw_offer.offered")).alignment(Alignment::Center),
+        Line::from(""),
+        Line::from(t("draw_offer.answer")),
+    ];
+
+    let paragraph = Paragraph::new(text)
+        .block(block.clone())
+        .alignment(Alignment::Left)
+        .wrap(Wrap { trim: true });
+
+    frame.render_widget(Clear, area); //this clears out the background
+    frame.render_widget(block, area);
+    frame.render_widget(paragraph, area);
+}
+
+pub fn render_claim_draw_popup(frame: &mut Frame) {
+    let block = Block::default()
+        .title(t("claim_draw.title"))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .padding(Padding::horizontal(1))
+        .border_style(Style::default().fg(WHITE));
+    let area = centered_rect(40, 40, frame.area());
+
+    let text = vec![
+        Line::from(t("claim_draw.repeated")).alignment(Alignment::Center),
+        Line::from(""),
+        Line::from(t("claim_draw.answer")),
+    ];
+
+    let paragraph = Paragraph::new(text)
+        .block(block.clone())
+        .alignment(Alignment::Left)
+        .wrap(Wrap { trim: true });
+
+    frame.render_widget(Clear, area); //this clears out the background
+    frame.render_widget(block, area);
+    frame.render_widget(paragraph, area);
+}
+
+/// The multiplayer connection dropped, `is_lost` once every attempt to connect again failed
+pub fn render_connection_popup(frame: &mut Frame, is_lost: bool) {
+    let block = Block::default()
+        .title(t("connection.title"))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .padding(Padding::horizontal(1))
+        .border_style(Style::default().fg(WHITE));
+    let area = centered_rect(40, 40, frame.area());
+
+    let (state, leave) = if is_lost {
+        (t("connection.lost"), t("connection.lost.leave"))
+    } else {
+        (
+            t("connection.reconnecting"),
+            t("connection.reconnecting.leave"),
+        )
+    };
+    let text = vec![
+        Line::from(state).alignment(Alignment::Center),
+        Line::from(""),
+        Line::from(leave),
+    ];
+
     let paragraph = Paragraph::new(text)
         .block(block.clone())
         .alignment(Alignment::Left)
@@ -48,7 +265,7 @@ pub fn render_engine_path_error_popup(frame: &mut Frame) {
 // This renders a popup for a promotion
 pub fn render_end_popup(frame: &mut Frame, sentence: &str, is_multiplayer: bool) {
     let block = Block::default()
-        .title("Game ended")
+        .title(t("end.title"))
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
         .padding(Padding::horizontal(1))
@@ -60,9 +277,9 @@ pub fn render_end_popup(frame: &mut Frame, sentence: &str, is_multiplayer: bool)
         Line::from(""),
         Line::from(""),
         Line::from(if is_multiplayer {
-            "Press `B` to go back to the menu"
+            t("end.back")
         } else {
-            "Press `R` to restart a new game"
+            t("end.restart")
         })
         .alignment(Alignment::Center),
     ];
@@ -80,7 +297,7 @@ pub fn render_end_popup(frame: &mut Frame, sentence: &str, is_multiplayer: bool)
 // This renders a popup for a promotion
 pub fn render_promotion_popup(frame: &mut Frame, app: &mut App) {
     let block = Block::default()
-        .title("Pawn promotion")
+        .title(t("promotion.title"))
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
         .padding(Padding::horizontal(1))
@@ -89,7 +306,7 @@ pub fn render_promotion_popup(frame: &mut Frame, app: &mut App) {
 
     let text = vec![
         Line::from(""),
-        Line::from("-- Choose your pawn promotion --").alignment(Alignment::Center),
+        Line::from(t("promotion.choose")).alignment(Alignment::Center),
         Line::from(""),
     ];
 
@@ -175,7 +392,7 @@ pub fn render_promotion_popup(frame: &mut Frame, app: &mut App) {
 // This render the credit popup
 pub fn render_credit_popup(frame: &mut Frame) {
     let block = Block::default()
-        .title("Credits")
+        .title(t("credits.title"))
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
         .padding(Padding::horizontal(1))
@@ -184,18 +401,18 @@ pub fn render_credit_popup(frame: &mut Frame) {
 
     let credits_text = vec![
         Line::from(""),
-        Line::from("Hi 👋, I'm Thomas, a 22 years old French computer science student."),
-        Line::from("Thank you for playing Chess-tui! This project started as a personal journey to improve my algorithmic skills and learn Rust."),
+        Line::from(t("credits.hello")),
+        Line::from(t("credits.thanks")),
         Line::from(""),
-        Line::from("The entire source code is available on GitHub at https://github.com/thomas-mauran/chess-tui"),
-        Line::from("Feel free to contribute by picking an issue or creating a new one."),
+        Line::from(t("credits.source")),
+        Line::from(t("credits.contribute")),
         Line::from(""),
-        Line::from("Special thanks to my classmates for their support and inspiration!"),
+        Line::from(t("credits.classmates")),
         Line::from(""),
         Line::from(""),
         Line::from(""),
         Line::from(""),
-        Line::from("Press `Esc` to close the popup.").alignment(Alignment::Center),
+        Line::from(t("popup.close")).alignment(Alignment::Center),
     ];
 
     // Assuming Alignment is an enum or struct you have defined
@@ -210,53 +427,151 @@ pub fn render_credit_popup(frame: &mut Frame) {
     frame.render_widget(paragraph, area);
 }
 
-// This render the help popup
-pub fn render_help_popup(frame: &mut Frame) {
+// This render the help popup, with the keys of the page it is opened on
+pub fn render_help_popup(frame: &mut Frame, page: &Pages, help_key: char) {
     let block = Block::default()
-        .title("Help menu")
+        .title(t("help.title"))
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
         .padding(Padding::horizontal(1))
         .border_style(Style::default().fg(WHITE));
     let area = centered_rect(40, 65, frame.area());
 
-    let text = vec![
-        Line::from("Game controls:".underlined().bold()),
-        Line::from(""),
-        Line::from(vec![
-            "←/h ↑/k ↓/j →/l: Use these keys or the mouse to move the ".into(),
-            "blue".blue(),
-            " cursor".into(),
-        ]),
-        Line::from(""),
-        Line::from("`Ctrl` '+' or '-': Zoom in or out to adjust pieces sizes"),
-        Line::from("(Might differ in certain terminals)"),
-        Line::from(""),
-        Line::from("`Space`: Select a piece"),
-        Line::from(""),
-        Line::from("`Esc`: Deselect a piece / hide popups"),
-        Line::from(""),
-        Line::from("q: Quit the game"),
-        Line::from(""),
-        Line::from("b: Go to the home menu / reset the game"),
-        Line::from(""),
-        Line::from(""),
-        Line::from("Color codes:".underlined().bold()),
-        Line::from(""),
-        Line::from(vec!["Blue cell".blue(), ": Your cursor ".into()]),
-        Line::from(""),
-        Line::from(vec!["Green cell".green(), ": Selected Piece ".into()]),
-        Line::from(""),
-        Line::from(vec![
-            "Purple cell".magenta(),
-            ": The king is getting checked ".into(),
-        ]),
-        Line::from(""),
-        Line::from("Grey cell: Available cells for the selected piece"),
-        Line::from(""),
-        Line::from(""),
-        Line::from("Press `Esc` to close the popup.").alignment(Alignment::Center),
-    ];
+    let cursor_line = Line::from(vec![
+        t("help.cursor").into(),
+        t("help.cursor.blue").blue(),
+        t("help.cursor.end").into(),
+    ]);
+    let help_line = Line::from(t("help.toggle").replace("{}", &help_key.to_string()));
+
+    let mut text = match page {
+        Pages::Home => vec![
+            Line::from(t("help.menu").underlined().bold()),
+            Line::from(""),
+            Line::from(t("help.menu.move")),
+            Line::from(""),
+            Line::from(t("help.menu.select")),
+            Line::from(""),
+            help_line,
+            Line::from(""),
+            Line::from(t("help.quit")),
+        ],
+        Pages::Credit => vec![
+            Line::from(t("credits.title").underlined().bold()),
+            Line::from(""),
+            Line::from(t("help.back")),
+            Line::from(""),
+            help_line,
+            Line::from(""),
+            Line::from(t("help.quit")),
+        ],
+        Pages::BoardEditor => vec![
+            Line::from(t("help.editor").underlined().bold()),
+            Line::from(""),
+            cursor_line,
+            Line::from(""),
+            Line::from(t("editor.cycle")),
+            Line::from(""),
+            Line::from(t("editor.clear_cell")),
+            Line::from(""),
+            Line::from(t("editor.turn")),
+            Line::from(""),
+            Line::from(t("editor.castlings")),
+            Line::from(""),
+            Line::from(t("editor.empty")),
+            Line::from(""),
+            Line::from(t("editor.start_position")),
+            Line::from(""),
+            Line::from(t("editor.play")),
+            Line::from(""),
+            Line::from(t("help.back")),
+            Line::from(""),
+            help_line,
+            Line::from(""),
+            Line::from(t("help.quit")),
+        ],
+        Pages::Solo | Pages::Bot | Pages::Multiplayer => vec![
+            Line::from(t("help.controls").underlined().bold()),
+            Line::from(""),
+            cursor_line,
+            Line::from(""),
+            Line::from(t("help.zoom")),
+            Line::from(t("help.zoom.terminals")),
+            Line::from(""),
+            Line::from(t("help.select")),
+            Line::from(""),
+            Line::from(t("help.deselect")),
+            Line::from(""),
+            help_line,
+            Line::from(""),
+            Line::from(t("help.quit")),
+            Line::from(""),
+            Line::from(t("help.home")),
+            Line::from(""),
+            Line::from(t("help.presentation")),
+            Line::from(""),
+            Line::from(t("help.coordinates")),
+            Line::from(""),
+            Line::from(t("help.last_move_reach")),
+            Line::from(""),
+            Line::from(t("help.copy_move")),
+            Line::from(""),
+            Line::from(t("help.copy_board")),
+            Line::from(""),
+            Line::from(t("help.save_board")),
+            Line::from(""),
+            Line::from(t("help.captured")),
+            Line::from(""),
+            Line::from(t("help.show_coordinates")),
+            Line::from(""),
+            Line::from(t("help.claim_draw")),
+            Line::from(""),
+            Line::from(t("help.checked_king")),
+            Line::from(""),
+            Line::from(t("help.take_back")),
+            Line::from(""),
+            Line::from(t("help.offer_draw")),
+            Line::from(""),
+            Line::from(t("help.resign")),
+            Line::from(""),
+            Line::from(t("help.flip_board")),
+            Line::from(""),
+            Line::from(t("help.replay")),
+            Line::from(""),
+            Line::from(""),
+            Line::from(t("help.colors").underlined().bold()),
+            Line::from(""),
+            Line::from(vec![
+                t("help.colors.blue").blue(),
+                t("help.colors.cursor").into(),
+            ]),
+            Line::from(""),
+            Line::from(vec![
+                t("help.colors.green").green(),
+                t("help.colors.selected").into(),
+            ]),
+            Line::from(""),
+            Line::from(vec![
+                t("help.colors.purple").magenta(),
+                t("help.colors.checked").into(),
+            ]),
+            Line::from(""),
+            Line::from(vec![
+                t("help.colors.yellow").yellow(),
+                t("help.colors.escape").into(),
+            ]),
+            Line::from(""),
+            Line::from(t("help.colors.grey")),
+            Line::from(""),
+            Line::from(vec![
+                t("help.colors.red").light_red(),
+                t("help.colors.reach").into(),
+            ]),
+        ],
+    };
+    text.push(Line::from(""));
+    text.push(Line::from(""));
+    text.push(Line::from(t("popup.close")).alignment(Alignment::Center));
 
     let paragraph = Paragraph::new(text)
         .block(block.clone())
@@ -271,7 +586,7 @@ pub fn render_help_popup(frame: &mut Frame) {
 // This renders a popup for the color selection
 pub fn render_color_selection_popup(frame: &mut Frame, app: &App) {
     let block = Block::default()
-        .title("Color selection")
+        .title(t("color.title"))
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
         .padding(Padding::horizontal(1))
@@ -280,7 +595,7 @@ pub fn render_color_selection_popup(frame: &mut Frame, app: &App) {
 
     let text = vec![
         Line::from(""),
-        Line::from("-- Choose your color --").alignment(Alignment::Center),
+        Line::from(t("color.choose")).alignment(Alignment::Center),
         Line::from(""),
     ];
 
@@ -358,7 +673,7 @@ pub fn render_multiplayer_selection_popup(frame: &mut Frame, app: &App) {
 
     let text = vec![
         Line::from(""),
-        Line::from("-- Are you hosting or joining a game ? --").alignment(Alignment::Center),
+        Line::from(t("multiplayer.host_or_join")).alignment(Alignment::Center),
         Line::from(""),
     ];
 
@@ -395,7 +710,7 @@ pub fn render_multiplayer_selection_popup(frame: &mut Frame, app: &App) {
         .split(inner_popup_layout_vertical[1]);
 
     let hosting = Paragraph::new(Text::from(vec![Line::from(vec![Span::styled(
-        "HOSTING",
+        t("multiplayer.hosting"),
         Style::default().add_modifier(if app.menu_cursor == 0 {
             Modifier::UNDERLINED
         } else {
@@ -408,7 +723,7 @@ pub fn render_multiplayer_selection_popup(frame: &mut Frame, app: &App) {
     frame.render_widget(hosting, inner_popup_layout_horizontal[0]);
 
     let joining = Paragraph::new(Text::from(vec![Line::from(vec![Span::styled(
-        "JOINING",
+        t("multiplayer.joining"),
         Style::default().add_modifier(if app.menu_cursor == 1 {
             Modifier::UNDERLINED
         } else {
@@ -422,21 +737,28 @@ pub fn render_multiplayer_selection_popup(frame: &mut Frame, app: &App) {
 
 // MULTIPLAYER POPUPS
 // This renders a popup indicating we are waiting for the other player
-pub fn render_wait_for_other_player(frame: &mut Frame, ip: IpAddr) {
+pub fn render_wait_for_other_player(frame: &mut Frame, address: &str, ipv6_address: Option<&str>) {
     let block = Block::default()
-        .title("Waiting ...")
+        .title(t("multiplayer.waiting.title"))
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
         .padding(Padding::horizontal(1))
         .border_style(Style::default().fg(WHITE));
     let area = centered_rect(40, 40, frame.area());
 
-    let text = vec![
+    let mut text = vec![
         Line::from(""),
         Line::from(""),
-        Line::from("Waiting for other player").alignment(Alignment::Center),
-        Line::from(format!("Host IP address and port: {}:2308", ip)).alignment(Alignment::Center),
+        Line::from(t("multiplayer.waiting")).alignment(Alignment::Center),
+        Line::from(t("multiplayer.host_address").replace("{}", address))
+            .alignment(Alignment::Center),
     ];
+    if let Some(ipv6_address) = ipv6_address {
+        text.push(
+            Line::from(t("multiplayer.host_address.ipv6").replace("{}", ipv6_address))
+                .alignment(Alignment::Center),
+        );
+    }
 
     let paragraph = Paragraph::new(text)
         .block(block.clone())
@@ -449,9 +771,13 @@ pub fn render_wait_for_other_player(frame: &mut Frame, ip: IpAddr) {
 }
 
 // This renders a popup allowing us to get a user input
-pub fn render_enter_multiplayer_ip(frame: &mut Frame, prompt: &Prompt) {
+pub fn render_enter_multiplayer_ip(
+    frame: &mut Frame,
+    prompt: &Prompt,
+    host_discovery: Option<&HostDiscovery>,
+) {
     let block = Block::default()
-        .title("Join a game")
+        .title(t("multiplayer.join.title"))
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
         .padding(Padding::horizontal(1))
@@ -460,20 +786,58 @@ pub fn render_enter_multiplayer_ip(frame: &mut Frame, prompt: &Prompt) {
 
     let current_input = prompt.input.as_str();
 
-    let text = vec![
-        Line::from("Enter the ip address and port of the host:").alignment(Alignment::Center),
+    let mut text = vec![
+        Line::from(t("multiplayer.join.enter")).alignment(Alignment::Center),
         Line::from(""),
         Line::from(current_input),
         Line::from(""),
-        Line::from(""),
-        Line::from(""),
-        Line::from(""),
-        Line::from("Example: 10.111.6.50:2308;"),
-        Line::from("Documentation: https://thomas-mauran.github.io/chess-tui/docs/Multiplayer/Online%20multiplayer/"),
-        Line::from(""),
-        Line::from(""),
-        Line::from("Press `Esc` to close the popup.").alignment(Alignment::Center),
     ];
+    // The games announced on the local network, joined with Enter while the address is empty
+    match host_discovery {
+        None => text.extend([Line::from(""), Line::from(""), Line::from("")]),
+        Some(HostDiscovery { hosts: None, .. }) => {
+            text.push(Line::from(t("multiplayer.discovery.searching")));
+            text.push(Line::from(""));
+        }
+        Some(HostDiscovery {
+            hosts: Some(hosts),
+            cursor,
+            ..
+        }) => {
+            if hosts.is_empty() {
+                text.push(Line::from(t("multiplayer.discovery.none")));
+            } else {
+                text.push(Line::from(t("multiplayer.discovery.found")));
+            }
+            for (i, host) in hosts.iter().enumerate() {
+                let style = if i == *cursor {
+                    Style::default().add_modifier(Modifier::UNDERLINED | Modifier::BOLD)
+                } else {
+                    Style::default()
+                };
+                // The joining player plays the other color
+                let key = match host.host_color {
+                    PieceColor::White => "multiplayer.discovery.play_black",
+                    PieceColor::Black => "multiplayer.discovery.play_white",
+                };
+                text.push(Line::from(Span::styled(
+                    t(key).replace("{}", &host.addr.to_string()),
+                    style,
+                )));
+            }
+            text.push(Line::from(""));
+        }
+    }
+    text.extend([
+        Line::from(t("multiplayer.join.example")),
+        Line::from(format!(
+            "{}https://thomas-mauran.github.io/chess-tui/docs/Multiplayer/Online%20multiplayer/",
+            t("multiplayer.join.documentation")
+        )),
+        Line::from(""),
+        Line::from(""),
+        Line::from(t("popup.close")).alignment(Alignment::Center),
+    ]);
 
     let paragraph = Paragraph::new(text)
         .block(block.clone())
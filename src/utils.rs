@@ -2,7 +2,7 @@ use crate::game_logic::coord::Coord;
 use crate::game_logic::game::Game;
 use crate::game_logic::game_board::GameBoard;
 use crate::{
-    constants::{DisplayMode, UNDEFINED_POSITION},
+    constants::{DisplayMode, MIN_NETWORK_PORT, UNDEFINED_POSITION},
     pieces::{PieceColor, PieceType},
 };
 use ratatui::{
@@ -10,6 +10,7 @@ use ratatui::{
     style::{Color, Stylize},
     widgets::{Block, Padding, Paragraph},
 };
+use std::net::{IpAddr, Ipv4Addr, SocketAddr, UdpSocket};
 
 /// method to clean `positions`: remove impossible positions
 pub fn cleaned_positions(positions: &[Coord]) -> Vec<Coord> {
@@ -112,6 +113,14 @@ pub fn get_cell_paragraph<'a>(
     let piece_type = game.game_board.get_piece_type(cell_coordinates);
     let piece_enum = PieceType::piece_type_to_string_enum(piece_type, &game.ui.display_mode);
 
+    // Debug grid: empty cells show their coordinates
+    if game.ui.debug_coords && piece_type.is_none() {
+        return Paragraph::new(game.game_board.coord_to_square(cell_coordinates))
+            .fg(Color::DarkGray)
+            .block(Block::new().padding(Padding::vertical(bounding_rect.height / 2)))
+            .alignment(Alignment::Center);
+    }
+
     let paragraph = match game.ui.display_mode {
         DisplayMode::DEFAULT => {
             let color_enum = color_to_ratatui_enum(piece_color);
@@ -141,3 +150,53 @@ pub fn get_cell_paragraph<'a>(
 pub fn invert_position(coord: &Coord) -> Coord {
     Coord::new(7 - coord.row, 7 - coord.col)
 }
+
+/// The address of this machine on the local network, the one to give to the players joining a game
+/// The IPv6 address is used without IPv4 route, and the loopback one without any network
+pub fn get_local_ip() -> IpAddr {
+    // Use an external IP to identify the default route
+    route_source_ip("0.0.0.0:0", "8.8.8.8:80")
+        .or_else(get_local_ipv6)
+        .unwrap_or(IpAddr::V4(Ipv4Addr::LOCALHOST))
+}
+
+/// The global IPv6 address of this machine, `None` without IPv6 route
+pub fn get_local_ipv6() -> Option<IpAddr> {
+    route_source_ip("[::]:0", "[2001:4860:4860::8888]:80")
+}
+
+// The address the packets sent to `remote_addr` would come from, nothing is sent to find it
+fn route_source_ip(bind_addr: &str, remote_addr: &str) -> Option<IpAddr> {
+    let socket = UdpSocket::bind(bind_addr).ok()?;
+    socket.connect(remote_addr).ok()?;
+    Some(socket.local_addr().ok()?.ip())
+}
+
+/// The port given in the configuration, `None` when it is out of range or reserved to the system services
+pub fn network_port(port: i64) -> Option<u16> {
+    u16::try_from(port)
+        .ok()
+        .filter(|&port| port >= MIN_NETWORK_PORT)
+}
+
+/// The address to join, with the given port when the player only typed the host
+/// The IPv6 addresses are written in brackets when a port follows them: `[2001:db8::1]:2308`
+pub fn address_with_port(addr: &str, port: u16) -> String {
+    let addr = addr.trim();
+    if addr.parse::<SocketAddr>().is_ok() {
+        return addr.to_string();
+    }
+    let ip = addr
+        .strip_prefix('[')
+        .and_then(|addr| addr.strip_suffix(']'))
+        .unwrap_or(addr);
+    if let Ok(ip) = ip.parse::<IpAddr>() {
+        return SocketAddr::new(ip, port).to_string();
+    }
+    // A host name, with or without its port
+    if addr.contains(':') {
+        addr.to_string()
+    } else {
+        format!("{addr}:{port}")
+    }
+}
@@ -0,0 +1,122 @@
+#[cfg(test)]
+mod tests {
+    use chess_tui::app::App;
+    use chess_tui::constants::{Pages, FLIP_ANIMATION_FRAMES, MOVE_ANIMATION_FRAMES};
+    use chess_tui::game_logic::coord::Coord;
+    use chess_tui::game_logic::ui::MoveAnimation;
+
+    fn app_with_animation() -> App {
+        let mut app = App::default();
+        app.game.ui.move_animation = true;
+        app
+    }
+
+    #[test]
+    fn no_animation_by_default() {
+        let mut app = App::default();
+        app.game.execute_move(&Coord::new(6, 4), &Coord::new(4, 4));
+
+        assert_eq!(app.game.ui.animation, None);
+    }
+
+    #[test]
+    fn piece_slides_to_its_destination() {
+        let mut app = app_with_animation();
+        // Ra1-a7
+        let mut animation = MoveAnimation {
+            from: Coord::new(7, 0),
+            to: Coord::new(1, 0),
+            frame: 0,
+        };
+        assert_eq!(animation.current_cell(), Coord::new(7, 0));
+        animation.frame = MOVE_ANIMATION_FRAMES / 2;
+        assert_eq!(animation.current_cell(), Coord::new(4, 0));
+
+        app.game.execute_move(&Coord::new(6, 4), &Coord::new(4, 4));
+        assert_eq!(
+            app.game.ui.animation,
+            Some(MoveAnimation {
+                from: Coord::new(6, 4),
+                to: Coord::new(4, 4),
+                frame: 0,
+            })
+        );
+
+        for _ in 0..MOVE_ANIMATION_FRAMES {
+            app.tick();
+        }
+        assert_eq!(app.game.ui.animation, None);
+    }
+
+    #[test]
+    fn new_move_replaces_the_running_animation() {
+        let mut app = app_with_animation();
+        app.game.execute_move(&Coord::new(6, 4), &Coord::new(4, 4));
+        app.tick();
+        app.game.switch_player_turn();
+        app.game.game_board.flip_the_board();
+
+        // The black pieces are now stored at the bottom, the cells are kept from white's side
+        app.game.execute_move(&Coord::new(6, 3), &Coord::new(4, 3));
+        assert_eq!(
+            app.game.ui.animation,
+            Some(MoveAnimation {
+                from: Coord::new(1, 4),
+                to: Coord::new(3, 4),
+                frame: 0,
+            })
+        );
+    }
+
+    // Play 1. e4 in a solo game with the flip animation
+    fn solo_game_after_first_move(flip_animation: bool, white_perspective: bool) -> App {
+        let mut app = App {
+            current_page: Pages::Solo,
+            ..Default::default()
+        };
+        app.game.ui.flip_animation = flip_animation;
+        app.game.ui.white_perspective = white_perspective;
+        app.game.ui.cursor_coordinates = Coord::new(6, 4);
+        app.game.handle_cell_click();
+        app.game.ui.cursor_coordinates = Coord::new(4, 4);
+        app.game.handle_cell_click();
+        app
+    }
+
+    #[test]
+    fn flip_is_drawn_row_by_row() {
+        let mut app = solo_game_after_first_move(true, false);
+        assert!(app.game.game_board.is_flipped);
+        assert_eq!(app.game.ui.flip_frame, Some(0));
+        assert!(app.game.ui.is_row_before_flip(0));
+
+        app.tick();
+        assert!(!app.game.ui.is_row_before_flip(0));
+        assert!(app.game.ui.is_row_before_flip(1));
+
+        for _ in 1..FLIP_ANIMATION_FRAMES {
+            app.tick();
+        }
+        assert_eq!(app.game.ui.flip_frame, None);
+        assert!(!app.game.ui.is_row_before_flip(7));
+    }
+
+    #[test]
+    fn flip_animation_only_when_the_drawn_board_turns() {
+        let app = solo_game_after_first_move(false, false);
+        assert_eq!(app.game.ui.flip_frame, None);
+
+        // White stays at the bottom, nothing turns on screen
+        let app = solo_game_after_first_move(true, true);
+        assert_eq!(app.game.ui.flip_frame, None);
+    }
+
+    #[test]
+    fn move_during_the_flip_snaps_to_the_final_orientation() {
+        let mut app = solo_game_after_first_move(true, false);
+        app.tick();
+
+        app.game.execute_move(&Coord::new(6, 3), &Coord::new(4, 3));
+        assert_eq!(app.game.ui.flip_frame, None);
+    }
+}
@@ -0,0 +1,168 @@
+#[cfg(test)]
+mod tests {
+    use chess_tui::app::App;
+    use chess_tui::constants::Pages;
+    use chess_tui::game_logic::board_editor::BoardEditor;
+    use chess_tui::game_logic::coord::Coord;
+    use chess_tui::handler::handle_key_events;
+    use chess_tui::pieces::{PieceColor, PieceType};
+    use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    fn press(app: &mut App, code: KeyCode) {
+        handle_key_events(KeyEvent::new(code, KeyModifiers::NONE), app).unwrap();
+    }
+
+    // White king e1 and rook h1, black king e8
+    fn editor_with_kings_and_rook() -> BoardEditor {
+        let mut board_editor = BoardEditor::default();
+        board_editor.clear_board();
+        board_editor.board[7][4] = Some((PieceType::King, PieceColor::White));
+        board_editor.board[7][7] = Some((PieceType::Rook, PieceColor::White));
+        board_editor.board[0][4] = Some((PieceType::King, PieceColor::Black));
+        board_editor.castling_rights = [true, false, false, false];
+        board_editor
+    }
+
+    #[test]
+    fn cells_cycle_through_every_piece() {
+        let mut board_editor = BoardEditor::default();
+        board_editor.clear_board();
+        let coord = Coord::new(4, 4);
+
+        let mut pieces = vec![];
+        for _ in 0..13 {
+            board_editor.cycle_piece(&coord);
+            pieces.push(board_editor.board[4][4]);
+        }
+
+        assert_eq!(pieces[0], Some((PieceType::Pawn, PieceColor::White)));
+        assert_eq!(pieces[5], Some((PieceType::King, PieceColor::White)));
+        assert_eq!(pieces[6], Some((PieceType::Pawn, PieceColor::Black)));
+        assert_eq!(pieces[11], Some((PieceType::King, PieceColor::Black)));
+        assert_eq!(pieces[12], None);
+    }
+
+    #[test]
+    fn fen_of_the_edited_position() {
+        let mut board_editor = BoardEditor::default();
+        assert_eq!(
+            board_editor.fen(),
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1"
+        );
+
+        let mut board_editor_with_rook = editor_with_kings_and_rook();
+        board_editor_with_rook.toggle_player_turn();
+        assert_eq!(
+            board_editor_with_rook.fen(),
+            "4k3/8/8/8/8/8/8/4K2R b K - 0 1"
+        );
+
+        board_editor.clear_board();
+        board_editor.castling_rights = [false; 4];
+        assert_eq!(board_editor.fen(), "8/8/8/8/8/8/8/8 w - - 0 1");
+    }
+
+    #[test]
+    fn illegal_positions_are_refused() {
+        assert_eq!(BoardEditor::default().validate(), Ok(()));
+        assert_eq!(editor_with_kings_and_rook().validate(), Ok(()));
+
+        let mut board_editor = editor_with_kings_and_rook();
+        board_editor.clear_cell(&Coord::new(0, 4));
+        assert!(board_editor.validate().is_err());
+
+        let mut board_editor = editor_with_kings_and_rook();
+        board_editor.board[0][0] = Some((PieceType::Pawn, PieceColor::White));
+        assert!(board_editor.validate().is_err());
+
+        // The black king is attacked by the rook while white is to move
+        let mut board_editor = editor_with_kings_and_rook();
+        board_editor.board[0][7] = Some((PieceType::Rook, PieceColor::White));
+        assert!(board_editor.validate().is_err());
+        board_editor.toggle_player_turn();
+        assert_eq!(board_editor.validate(), Ok(()));
+
+        // No queen side rook for the white queen side castling
+        let mut board_editor = editor_with_kings_and_rook();
+        board_editor.toggle_castling_right(1);
+        assert!(board_editor.validate().is_err());
+    }
+
+    #[test]
+    fn game_starts_from_the_edited_position() {
+        let mut app = App {
+            menu_cursor: 3,
+            ..Default::default()
+        };
+        app.menu_select();
+        assert_eq!(app.current_page, Pages::BoardEditor);
+
+        // An empty board can't be played
+        press(&mut app, KeyCode::Char('e'));
+        press(&mut app, KeyCode::Char('s'));
+        assert_eq!(app.current_page, Pages::BoardEditor);
+        assert!(app.board_editor.error.is_some());
+
+        app.board_editor = editor_with_kings_and_rook();
+        press(&mut app, KeyCode::Char('t'));
+        press(&mut app, KeyCode::Char('s'));
+        assert_eq!(app.current_page, Pages::Solo);
+        assert_eq!(app.game.player_turn, PieceColor::Black);
+
+        // Black is at the bottom as it is the player to move
+        assert!(app.game.game_board.is_flipped);
+        assert_eq!(
+            app.game.game_board.board[7][3],
+            Some((PieceType::King, PieceColor::Black))
+        );
+        assert!(app
+            .game
+            .is_move_legal(&Coord::new(7, 3), &Coord::new(6, 3), None));
+    }
+
+    #[test]
+    fn castling_follows_the_edited_rights() {
+        let mut app = App::default();
+        app.open_board_editor();
+        app.board_editor = editor_with_kings_and_rook();
+        app.start_board_editor_game();
+        assert!(app
+            .game
+            .is_move_legal(&Coord::new(7, 4), &Coord::new(7, 7), None));
+
+        app.open_board_editor();
+        app.board_editor = editor_with_kings_and_rook();
+        press(&mut app, KeyCode::Char('1'));
+        press(&mut app, KeyCode::Char('s'));
+        assert_eq!(app.current_page, Pages::Solo);
+        assert!(!app
+            .game
+            .is_move_legal(&Coord::new(7, 4), &Coord::new(7, 7), None));
+        assert!(app
+            .game
+            .is_move_legal(&Coord::new(7, 4), &Coord::new(7, 5), None));
+    }
+
+    #[test]
+    fn editor_keys_edit_the_cursor_cell() {
+        let mut app = App::default();
+        app.open_board_editor();
+
+        // e2 holds a white pawn, the next piece is a knight
+        app.game.ui.cursor_coordinates = Coord::new(6, 4);
+        press(&mut app, KeyCode::Enter);
+        assert_eq!(
+            app.board_editor.board[6][4],
+            Some((PieceType::Knight, PieceColor::White))
+        );
+        press(&mut app, KeyCode::Backspace);
+        assert_eq!(app.board_editor.board[6][4], None);
+
+        // The arrows still move the cursor
+        press(&mut app, KeyCode::Up);
+        assert_eq!(app.game.ui.cursor_coordinates, Coord::new(5, 4));
+
+        press(&mut app, KeyCode::Esc);
+        assert_eq!(app.current_page, Pages::Home);
+    }
+}
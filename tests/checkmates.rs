@@ -1,6 +1,8 @@
 #[cfg(test)]
 mod tests {
-    use chess_tui::game_logic::game::Game;
+    use chess_tui::app::App;
+    use chess_tui::game_logic::coord::Coord;
+    use chess_tui::game_logic::game::{Game, GameState};
     use chess_tui::game_logic::game_board::GameBoard;
     use chess_tui::pieces::{PieceColor, PieceType};
 
@@ -355,4 +357,100 @@ mod tests {
 
         assert!(!game.game_board.is_checkmate(game.player_turn));
     }
+
+    #[test]
+    fn cursor_jumps_to_checked_king() {
+        let mut custom_board = [[None; 8]; 8];
+        custom_board[7][6] = Some((PieceType::King, PieceColor::White));
+        custom_board[0][0] = Some((PieceType::King, PieceColor::Black));
+        custom_board[1][6] = Some((PieceType::Rook, PieceColor::Black));
+
+        let game_board = GameBoard::new(custom_board, vec![], vec![]);
+        let mut game = Game::new(game_board, PieceColor::White);
+        game.ui.cursor_coordinates = Coord::new(3, 3);
+
+        game.move_cursor_to_checked_king();
+        assert_eq!(game.ui.cursor_coordinates, Coord::new(7, 6));
+
+        // Nothing happens when the king is not checked
+        game.game_board.board[1][6] = None;
+        game.ui.cursor_coordinates = Coord::new(3, 3);
+        game.move_cursor_to_checked_king();
+        assert_eq!(game.ui.cursor_coordinates, Coord::new(3, 3));
+    }
+
+    #[test]
+    fn mated_king_cell_in_both_orientations() {
+        // Fool's mate, white is mated with the white pieces at the bottom
+        let mut app = App::default();
+        app.start_position_game(
+            GameBoard::from_fen("rnb1kbnr/pppp1ppp/8/4p3/6Pq/5P2/PPPPP2P/RNBQKBNR w KQkq - 1 3")
+                .unwrap(),
+        );
+        assert_eq!(app.game.game_state, GameState::Checkmate);
+        assert!(!app.game.game_board.is_flipped);
+        assert_eq!(app.game.mated_king_cell(), Some(Coord::new(7, 4)));
+
+        // Scholar's mate, black is mated with the black pieces at the bottom
+        app.start_position_game(
+            GameBoard::from_fen(
+                "r1bqkb1r/pppp1Qpp/2n2n2/4p3/2B1P3/8/PPPP1PPP/RNB1K1NR b KQkq - 0 4",
+            )
+            .unwrap(),
+        );
+        assert!(app.game.game_board.is_flipped);
+        assert_eq!(app.game.mated_king_cell(), Some(Coord::new(7, 3)));
+        assert_eq!(
+            app.game.game_board.board[7][3],
+            Some((PieceType::King, PieceColor::Black))
+        );
+    }
+
+    #[test]
+    fn checked_king_is_not_mated() {
+        let mut app = App::default();
+        app.start_position_game(GameBoard::from_fen("4k3/8/8/8/8/8/8/4RK2 b - - 0 1").unwrap());
+        assert!(app
+            .game
+            .game_board
+            .is_getting_checked(app.game.game_board.board, PieceColor::Black));
+        assert_eq!(app.game.game_state, GameState::Playing);
+        assert_eq!(app.game.mated_king_cell(), None);
+    }
+
+    #[test]
+    fn king_escape_positions_of_a_checked_king() {
+        // The rook checks the king on e4, which can take it or step off its file and its row
+        let game_board = GameBoard::from_fen("8/8/8/4r3/4K3/8/8/k7 w - - 0 1").unwrap();
+        let mut positions = game_board.get_king_escape_positions(PieceColor::White);
+        positions.sort();
+        assert_eq!(
+            positions,
+            vec![
+                Coord::new(3, 4),
+                Coord::new(4, 3),
+                Coord::new(4, 5),
+                Coord::new(5, 3),
+                Coord::new(5, 5),
+            ]
+        );
+    }
+
+    #[test]
+    fn king_escape_positions_without_escape() {
+        // Back rank mate, the pawns keep the king in
+        let game_board = GameBoard::from_fen("k7/8/8/8/8/8/6PP/r6K w - - 0 1").unwrap();
+        assert!(game_board.is_getting_checked(game_board.board, PieceColor::White));
+        assert_eq!(
+            game_board.get_king_escape_positions(PieceColor::White),
+            vec![]
+        );
+
+        // A king that isn't checked has nothing to escape
+        let game_board = GameBoard::default();
+        assert_eq!(
+            game_board.get_king_escape_positions(PieceColor::White),
+            vec![]
+        );
+    }
 }
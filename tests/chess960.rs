@@ -0,0 +1,180 @@
+#[cfg(test)]
+mod tests {
+    use chess_tui::app::App;
+    use chess_tui::constants::Pages;
+    use chess_tui::game_logic::board::{chess960_board, random_chess960_position, Board};
+    use chess_tui::game_logic::coord::Coord;
+    use chess_tui::game_logic::game_board::GameBoard;
+    use chess_tui::pieces::{PieceColor, PieceType};
+    use std::collections::HashSet;
+
+    fn white_back_rank(board: &Board) -> String {
+        board[7]
+            .iter()
+            .map(|&cell| {
+                let (piece_type, piece_color) = cell.unwrap();
+                PieceType::piece_to_fen_enum(Some(piece_type), Some(piece_color))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn known_positions() {
+        for (position, back_rank) in [
+            (0, "BBQNNRKR"),
+            (1, "BQNBNRKR"),
+            (518, "RNBQKBNR"),
+            (959, "RKRNNQBB"),
+        ] {
+            let board = chess960_board(position).unwrap();
+            assert_eq!(white_back_rank(&board), back_rank, "position {position}");
+        }
+        assert_eq!(chess960_board(960), None);
+    }
+
+    #[test]
+    fn every_position_follows_the_rules() {
+        let mut back_ranks = HashSet::new();
+        for position in 0..960 {
+            let board = chess960_board(position).unwrap();
+            let back_rank = white_back_rank(&board);
+
+            let bishops: Vec<usize> = back_rank.match_indices('B').map(|(col, _)| col).collect();
+            assert_eq!(bishops.len(), 2);
+            assert_ne!(bishops[0] % 2, bishops[1] % 2, "{back_rank}");
+
+            let rooks: Vec<usize> = back_rank.match_indices('R').map(|(col, _)| col).collect();
+            let king = back_rank.find('K').unwrap();
+            assert!(rooks[0] < king && king < rooks[1], "{back_rank}");
+
+            // The black pieces mirror the white ones
+            for (black_piece, white_piece) in board[0].iter().zip(board[7]) {
+                let (piece_type, _) = white_piece.unwrap();
+                assert_eq!(*black_piece, Some((piece_type, PieceColor::Black)));
+            }
+            assert_eq!(board[1], [Some((PieceType::Pawn, PieceColor::Black)); 8]);
+            assert_eq!(board[6], [Some((PieceType::Pawn, PieceColor::White)); 8]);
+            back_ranks.insert(back_rank);
+        }
+        assert_eq!(back_ranks.len(), 960);
+        assert!(random_chess960_position() < 960);
+    }
+
+    #[test]
+    fn chess960_game_keeps_the_castlings() {
+        let mut app = App::default();
+        app.start_chess960_game(959);
+        assert_eq!(app.current_page, Pages::Solo);
+        assert_eq!(app.game.player_turn, PieceColor::White);
+        assert!(app.game.game_board.chess960);
+        assert_eq!(
+            app.game.game_board.position_fen(PieceColor::White),
+            "rkrnnqbb/pppppppp/8/8/8/8/PPPPPPPP/RKRNNQBB w KQkq - 0 1"
+        );
+
+        app.start_chess960_game(518);
+        assert_eq!(
+            app.game.game_board.position_fen(PieceColor::White),
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1"
+        );
+    }
+
+    // Play the moves from the position, written with their cells as the saved games do
+    fn play(fen: &str, moves: &[&str]) -> App {
+        let mut app = App::default();
+        app.start_position_game(GameBoard::from_fen(fen).unwrap());
+        for saved_move in moves {
+            assert!(
+                app.game.play_saved_move(saved_move),
+                "{saved_move} in {fen}"
+            );
+        }
+        app
+    }
+
+    #[test]
+    fn castlings_of_position_959() {
+        // RKRNNQBB with the pieces between the rooks and the g file gone
+        let app = play(
+            "rkr5/pppppppp/8/8/8/8/PPPPPPPP/RKR5 w KQkq - 0 1",
+            &["b1c1", "b8c8"],
+        );
+        assert_eq!(
+            app.game.game_board.position_fen(app.game.player_turn),
+            "r4rk1/pppppppp/8/8/8/8/PPPPPPPP/R4RK1 w - - 2 2"
+        );
+        assert_eq!(app.game.game_board.san_history, ["O-O", "O-O"]);
+
+        // The other rook stands on the king cell of the queen side
+        let app = play("rkr5/pppppppp/8/8/8/8/PPPPPPPP/RKR5 w KQkq - 0 1", &[]);
+        assert!(!app
+            .game
+            .is_move_legal(&Coord::new(7, 1), &Coord::new(7, 0), None));
+    }
+
+    #[test]
+    fn castlings_of_position_0() {
+        // BBQNNRKR: the king side castling only moves the rook, the queen side one goes through the e and d files
+        let app = play(
+            "bbqnnrkr/pppppppp/8/8/8/8/PPPPPPPP/5RKR w KQkq - 0 1",
+            &["g1f1"],
+        );
+        assert_eq!(
+            app.game.game_board.position_fen(app.game.player_turn),
+            "bbqnnrkr/pppppppp/8/8/8/8/PPPPPPPP/2KR3R b kq - 1 1"
+        );
+        assert_eq!(app.game.game_board.san_history, ["O-O-O"]);
+
+        // The f1 rook stands on the way of the h1 one
+        let app = play("bbqnnrkr/pppppppp/8/8/8/8/PPPPPPPP/5RKR w KQkq - 0 1", &[]);
+        assert!(!app
+            .game
+            .is_move_legal(&Coord::new(7, 6), &Coord::new(7, 7), None));
+        let app = play("4k3/8/8/8/8/8/8/6KR w K - 0 1", &["g1h1"]);
+        assert_eq!(
+            app.game.game_board.position_fen(app.game.player_turn),
+            "4k3/8/8/8/8/8/8/5RK1 b - - 1 1"
+        );
+        assert_eq!(app.game.game_board.san_history, ["O-O"]);
+    }
+
+    #[test]
+    fn castling_king_path_cant_be_attacked() {
+        // The e1 cell is on the way of the king going from g1 to c1
+        let app = play("4k3/8/8/8/8/8/4r3/5RKR w Q - 0 1", &[]);
+        assert!(!app
+            .game
+            .is_move_legal(&Coord::new(7, 6), &Coord::new(7, 5), None));
+
+        // Only the rook crosses the b file
+        let app = play("4k3/8/8/8/8/8/1r6/5RKR w Q - 0 1", &["g1f1"]);
+        assert_eq!(
+            app.game.game_board.position_fen(app.game.player_turn),
+            "4k3/8/8/8/8/8/1r6/2KR3R b - - 1 1"
+        );
+    }
+
+    #[test]
+    fn castling_rights_with_the_rook_file() {
+        // Shredder-FEN rights are the same castlings as the KQkq ones of the outermost rooks
+        let game_board =
+            GameBoard::from_fen("rkr5/pppppppp/8/8/8/8/PPPPPPPP/RKR5 w CAca - 0 1").unwrap();
+        assert_eq!(
+            game_board.position_fen(PieceColor::White),
+            "rkr5/pppppppp/8/8/8/8/PPPPPPPP/RKR5 w KQkq - 0 1"
+        );
+
+        // The b1 rook castles while the a1 one doesn't
+        let app = play("3k4/8/8/8/8/8/8/RR1K4 w B - 0 1", &[]);
+        assert_eq!(
+            app.game.game_board.position_fen(PieceColor::White),
+            "3k4/8/8/8/8/8/8/RR1K4 w B - 0 1"
+        );
+        let app = play("3k4/8/8/8/8/8/8/RR1K4 w B - 0 1", &["d1b1"]);
+        assert_eq!(
+            app.game.game_board.position_fen(app.game.player_turn),
+            "3k4/8/8/8/8/8/8/R1KR4 b - - 1 1"
+        );
+        assert!(GameBoard::from_fen("3k4/8/8/8/8/8/8/RR1K4 w C - 0 1").is_err());
+    }
+}
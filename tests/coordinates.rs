@@ -0,0 +1,95 @@
+#[cfg(test)]
+mod tests {
+    use chess_tui::app::App;
+    use chess_tui::constants::{Pages, Popups};
+    use chess_tui::game_logic::coord::Coord;
+    use chess_tui::game_logic::game::Game;
+    use chess_tui::handler::handle_key_events;
+    use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use ratatui::{backend::TestBackend, buffer::Buffer, layout::Rect, Terminal};
+
+    fn render(game: &Game, width: u16, height: u16) -> Buffer {
+        let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+        let mut ui = game.ui.clone();
+        terminal
+            .draw(|frame| ui.board_render(Rect::new(0, 0, width, height), frame, game))
+            .unwrap();
+        terminal.backend().buffer().clone()
+    }
+
+    // Draw the board on 3x2 cells and read the characters of the top right and the bottom right corner of a drawn cell
+    fn corners(game: &Game, row: u16, col: u16) -> (String, String) {
+        let buffer = render(game, 24, 16);
+        (
+            buffer[(col * 3 + 2, row * 2)].symbol().to_string(),
+            buffer[(col * 3 + 2, row * 2 + 1)].symbol().to_string(),
+        )
+    }
+
+    #[test]
+    fn labels_along_the_bottom_and_the_left_of_the_board() {
+        let game = Game::default();
+        assert_eq!(corners(&game, 0, 0).0, "8");
+        assert_eq!(corners(&game, 4, 0).0, "4");
+        assert_eq!(corners(&game, 7, 0), ("1".to_string(), "a".to_string()));
+        assert_eq!(corners(&game, 7, 7).1, "h");
+        // The cells inside the board have no label
+        assert_eq!(corners(&game, 4, 4), (" ".to_string(), " ".to_string()));
+    }
+
+    #[test]
+    fn labels_follow_the_black_side_at_the_bottom() {
+        let mut game = Game::default();
+        game.game_board.flip_the_board();
+        assert_eq!(corners(&game, 0, 0).0, "1");
+        assert_eq!(corners(&game, 7, 0), ("8".to_string(), "h".to_string()));
+        assert_eq!(corners(&game, 7, 7).1, "a");
+
+        // Drawn from white's side, the labels turn with the board
+        game.ui.white_perspective = true;
+        assert_eq!(corners(&game, 0, 0).0, "8");
+        assert_eq!(corners(&game, 7, 0), ("1".to_string(), "a".to_string()));
+    }
+
+    #[test]
+    fn labels_can_be_hidden() {
+        let mut game = Game::default();
+        game.ui.toggle_show_coordinates();
+        assert_eq!(corners(&game, 4, 0).0, " ");
+        assert_eq!(corners(&game, 0, 0).0, " ");
+    }
+
+    #[test]
+    fn labels_leave_the_marker_of_the_selected_cell() {
+        let mut game = Game::default();
+        game.ui.selected_coordinates = Coord::new(0, 0);
+        let buffer = render(&game, 24, 16);
+        assert_eq!(buffer[(0, 0)].symbol(), "◆");
+        assert_eq!(buffer[(2, 0)].symbol(), "8");
+    }
+
+    #[test]
+    fn no_labels_on_cells_of_a_single_line() {
+        let game = Game::default();
+        let buffer = render(&game, 24, 8);
+        assert_eq!(buffer[(0, 7)].symbol(), " ");
+        assert_eq!(buffer[(2, 7)].symbol(), " ");
+    }
+
+    #[test]
+    fn labels_are_not_toggled_behind_the_reconnecting_popup() {
+        let mut app = App {
+            current_page: Pages::Multiplayer,
+            current_popup: Some(Popups::Reconnecting),
+            ..Default::default()
+        };
+        let show_coordinates = app.game.ui.show_coordinates;
+        handle_key_events(
+            KeyEvent::new(KeyCode::Char('c'), KeyModifiers::NONE),
+            &mut app,
+        )
+        .unwrap();
+        assert_eq!(app.game.ui.show_coordinates, show_coordinates);
+        assert_eq!(app.current_popup, Some(Popups::Reconnecting));
+    }
+}
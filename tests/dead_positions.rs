@@ -0,0 +1,112 @@
+#[cfg(test)]
+mod tests {
+    use chess_tui::game_logic::game_board::GameBoard;
+    use chess_tui::pieces::{PieceColor, PieceType};
+
+    // Kings on a8 and h1 plus the given pieces, cells are (row, col) of the stored board
+    fn board_with(pieces: &[(u8, u8, PieceType, PieceColor)]) -> GameBoard {
+        let mut board = [[None; 8]; 8];
+        board[0][0] = Some((PieceType::King, PieceColor::Black));
+        board[7][7] = Some((PieceType::King, PieceColor::White));
+        for &(row, col, piece_type, piece_color) in pieces {
+            board[row as usize][col as usize] = Some((piece_type, piece_color));
+        }
+        GameBoard::new(board, vec![], vec![board])
+    }
+
+    #[test]
+    fn king_against_king() {
+        assert!(board_with(&[]).is_dead_position());
+    }
+
+    #[test]
+    fn king_and_minor_piece_against_king() {
+        let knight = board_with(&[(4, 4, PieceType::Knight, PieceColor::White)]);
+        assert!(knight.is_dead_position());
+
+        let bishop = board_with(&[(4, 4, PieceType::Bishop, PieceColor::Black)]);
+        assert!(bishop.is_dead_position());
+    }
+
+    #[test]
+    fn bishops_on_the_same_cell_color() {
+        // Opposite sides, both on dark cells
+        let one_each = board_with(&[
+            (4, 3, PieceType::Bishop, PieceColor::White),
+            (2, 1, PieceType::Bishop, PieceColor::Black),
+        ]);
+        assert!(one_each.is_dead_position());
+
+        // Same side bishops on the same cell color can't mate either
+        let same_side = board_with(&[
+            (4, 3, PieceType::Bishop, PieceColor::White),
+            (5, 0, PieceType::Bishop, PieceColor::White),
+            (1, 2, PieceType::Bishop, PieceColor::Black),
+        ]);
+        assert!(same_side.is_dead_position());
+    }
+
+    #[test]
+    fn dead_position_does_not_depend_on_the_board_orientation() {
+        let mut game_board = board_with(&[
+            (4, 3, PieceType::Bishop, PieceColor::White),
+            (2, 1, PieceType::Bishop, PieceColor::Black),
+        ]);
+        game_board.flip_the_board();
+
+        assert!(game_board.is_dead_position());
+    }
+
+    #[test]
+    fn bishops_on_different_cell_colors_can_mate() {
+        let one_each = board_with(&[
+            (4, 3, PieceType::Bishop, PieceColor::White),
+            (2, 2, PieceType::Bishop, PieceColor::Black),
+        ]);
+        assert!(!one_each.is_dead_position());
+
+        let bishop_pair = board_with(&[
+            (4, 3, PieceType::Bishop, PieceColor::White),
+            (4, 4, PieceType::Bishop, PieceColor::White),
+        ]);
+        assert!(!bishop_pair.is_dead_position());
+    }
+
+    #[test]
+    fn knights_can_mate_with_help() {
+        let one_each = board_with(&[
+            (4, 4, PieceType::Knight, PieceColor::White),
+            (2, 2, PieceType::Knight, PieceColor::Black),
+        ]);
+        assert!(!one_each.is_dead_position());
+
+        let two_knights = board_with(&[
+            (4, 4, PieceType::Knight, PieceColor::White),
+            (4, 5, PieceType::Knight, PieceColor::White),
+        ]);
+        assert!(!two_knights.is_dead_position());
+
+        let knight_and_bishop = board_with(&[
+            (4, 4, PieceType::Knight, PieceColor::White),
+            (2, 1, PieceType::Bishop, PieceColor::Black),
+        ]);
+        assert!(!knight_and_bishop.is_dead_position());
+    }
+
+    #[test]
+    fn major_pieces_and_pawns_are_never_dead() {
+        for piece_type in [PieceType::Pawn, PieceType::Rook, PieceType::Queen] {
+            let game_board = board_with(&[(4, 4, piece_type, PieceColor::White)]);
+            assert!(!game_board.is_dead_position());
+        }
+    }
+
+    #[test]
+    fn dead_position_is_a_draw() {
+        let mut game_board = board_with(&[(4, 4, PieceType::Knight, PieceColor::White)]);
+        assert!(game_board.is_draw(PieceColor::White));
+
+        let mut game_board = board_with(&[(4, 4, PieceType::Rook, PieceColor::White)]);
+        assert!(!game_board.is_draw(PieceColor::White));
+    }
+}
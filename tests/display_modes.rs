@@ -0,0 +1,38 @@
+#[cfg(test)]
+mod tests {
+    use chess_tui::app::App;
+    use chess_tui::constants::{DisplayMode, Pages};
+
+    #[test]
+    fn game_mode_display_mode_is_not_the_global_one() {
+        let mut app = App {
+            solo_display_mode: Some(DisplayMode::ASCII),
+            current_page: Pages::Solo,
+            ..Default::default()
+        };
+        app.apply_page_display_mode();
+        assert_eq!(app.game.ui.display_mode.to_string(), "ASCII");
+        // The configuration keeps the global display mode
+        assert_eq!(app.global_display_mode().to_string(), "DEFAULT");
+
+        app.restore_display_mode();
+        assert_eq!(app.game.ui.display_mode.to_string(), "DEFAULT");
+        assert_eq!(app.global_display_mode().to_string(), "DEFAULT");
+    }
+
+    #[test]
+    fn global_display_mode_is_saved_once() {
+        let mut app = App {
+            solo_display_mode: Some(DisplayMode::ASCII),
+            current_page: Pages::Solo,
+            ..Default::default()
+        };
+        // Starting the game again applies the game mode display mode over itself
+        app.apply_page_display_mode();
+        app.apply_page_display_mode();
+        assert_eq!(app.global_display_mode().to_string(), "DEFAULT");
+
+        app.restore_display_mode();
+        assert_eq!(app.game.ui.display_mode.to_string(), "DEFAULT");
+    }
+}
@@ -1,9 +1,13 @@
 #[cfg(test)]
 mod tests {
+    use chess_tui::app::App;
+    use chess_tui::constants::{Pages, Popups};
     use chess_tui::game_logic::coord::Coord;
-    use chess_tui::game_logic::game::Game;
+    use chess_tui::game_logic::game::{Game, GameState};
     use chess_tui::game_logic::game_board::GameBoard;
+    use chess_tui::handler::handle_key_events;
     use chess_tui::pieces::{PieceColor, PieceMove, PieceType};
+    use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
     #[test]
     fn is_draw_true() {
         let custom_board = [
@@ -116,7 +120,17 @@ mod tests {
             [None, None, None, None, None, None, None, None],
             [None, None, None, None, None, None, None, None],
             [None, None, None, None, None, None, None, None],
-            [None, None, None, None, None, None, None, None],
+            // A pawn keeps enough material on the board for a checkmate
+            [
+                Some((PieceType::Pawn, PieceColor::White)),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            ],
             [None, None, None, None, None, None, None, None],
         ];
         // We setup the game
@@ -125,10 +139,10 @@ mod tests {
         let mut game = Game::new(game_board, PieceColor::White);
         game.game_board.board = custom_board;
 
-        game.game_board.set_consecutive_non_pawn_or_capture(49);
+        game.game_board.set_consecutive_non_pawn_or_capture(99);
         assert!(!game.game_board.is_draw(game.player_turn));
 
-        // Move the pawn to a make the 50th move
+        // Move the king to make the 100th halfmove, the 50th move of both players
         game.execute_move(&Coord::new(1, 6), &Coord::new(1, 5));
         assert!(game.game_board.is_draw(game.player_turn));
     }
@@ -151,7 +165,17 @@ mod tests {
             [None, None, None, None, None, None, None, None],
             [None, None, None, None, None, None, None, None],
             [None, None, None, None, None, None, None, None],
-            [None, None, None, None, None, None, None, None],
+            // A pawn keeps enough material on the board for a checkmate
+            [
+                Some((PieceType::Pawn, PieceColor::White)),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            ],
             [None, None, None, None, None, None, None, None],
         ];
 
@@ -227,4 +251,106 @@ mod tests {
         game.execute_move(&Coord::new(0, 2), &Coord::new(0, 1));
         assert!(game.game_board.is_draw(game.player_turn));
     }
+
+    // Kings and a pawn, one move before the fifty moves rule
+    fn game_before_fifty_moves(auto_claim_draws: bool) -> Game {
+        let mut custom_board = [[None; 8]; 8];
+        custom_board[1][2] = Some((PieceType::King, PieceColor::White));
+        custom_board[1][6] = Some((PieceType::King, PieceColor::Black));
+        custom_board[6][0] = Some((PieceType::Pawn, PieceColor::White));
+
+        let mut game_board = GameBoard::new(custom_board, vec![], vec![custom_board]);
+        game_board.auto_claim_draws = auto_claim_draws;
+        game_board.set_consecutive_non_pawn_or_capture(99);
+        Game::new(game_board, PieceColor::White)
+    }
+
+    #[test]
+    fn fifty_moves_draw_is_automatic_by_default() {
+        let mut game = game_before_fifty_moves(true);
+
+        game.execute_move(&Coord::new(1, 2), &Coord::new(1, 1));
+        assert!(game.game_board.is_draw_claimable());
+        assert!(game.game_board.is_draw(game.player_turn));
+    }
+
+    #[test]
+    fn fifty_moves_draw_after_the_limit_ends_the_game() {
+        let mut game = game_before_fifty_moves(true);
+        game.game_board.set_consecutive_non_pawn_or_capture(120);
+        assert!(game.game_board.is_draw_claimable());
+        assert!(game.game_board.is_draw(game.player_turn));
+    }
+
+    #[test]
+    fn fifty_moves_draw_has_to_be_claimed() {
+        let mut game = game_before_fifty_moves(false);
+        assert!(!game.claim_draw());
+
+        game.execute_move(&Coord::new(1, 2), &Coord::new(1, 1));
+        assert!(game.game_board.is_draw_claimable());
+        assert!(!game.game_board.is_draw(game.player_turn));
+        assert_eq!(game.game_state, GameState::Playing);
+
+        // The claim stays available while no pawn moves nor capture happen
+        game.execute_move(&Coord::new(1, 1), &Coord::new(1, 2));
+        assert!(game.game_board.is_draw_claimable());
+
+        assert!(game.claim_draw());
+        assert_eq!(game.game_state, GameState::Draw);
+    }
+
+    // Knights going back and forth until the starting position is reached for the third time
+    fn app_with_repetition_to_claim() -> App {
+        let mut app = App {
+            current_page: Pages::Solo,
+            ..Default::default()
+        };
+        app.game.game_board.auto_claim_repetition = false;
+        let knight_moves = ["g1f3", "g8f6", "f3g1", "f6g8"].repeat(2);
+        for knight_move in &knight_moves[..7] {
+            assert!(app.game.play_saved_move(knight_move));
+            app.tick();
+            assert_eq!(app.current_popup, None);
+        }
+        assert!(app.game.play_saved_move(knight_moves[7]));
+        assert!(!app.game.game_board.is_draw(app.game.player_turn));
+        assert_eq!(app.game.game_state, GameState::Playing);
+        app
+    }
+
+    #[test]
+    fn repetition_draw_is_asked_to_be_claimed() {
+        let mut app = app_with_repetition_to_claim();
+        app.tick();
+        assert_eq!(app.current_popup, Some(Popups::ClaimDraw));
+
+        handle_key_events(
+            KeyEvent::new(KeyCode::Char('y'), KeyModifiers::NONE),
+            &mut app,
+        )
+        .unwrap();
+        assert_eq!(app.current_popup, None);
+        assert_eq!(app.game.game_state, GameState::Draw);
+    }
+
+    #[test]
+    fn declined_repetition_draw_keeps_the_game_going() {
+        let mut app = app_with_repetition_to_claim();
+        app.tick();
+        handle_key_events(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE), &mut app).unwrap();
+        assert_eq!(app.current_popup, None);
+        assert_eq!(app.game.game_state, GameState::Playing);
+
+        // The same position isn't asked again
+        app.tick();
+        assert_eq!(app.current_popup, None);
+
+        // The next repetition is asked again
+        for knight_move in ["g1f3", "g8f6", "f3g1", "f6g8"] {
+            assert!(app.game.play_saved_move(knight_move));
+        }
+        app.tick();
+        assert_eq!(app.current_popup, Some(Popups::ClaimDraw));
+    }
 }
@@ -0,0 +1,179 @@
+#[cfg(test)]
+mod tests {
+    use chess_tui::game_logic::board::init_board;
+    use chess_tui::game_logic::bot::{detect_engines_in, Bot, EngineOptions};
+    use chess_tui::utils::convert_notation_into_position;
+    use std::env;
+    use std::fs::{self, File};
+
+    #[test]
+    fn engines_are_found_in_the_path_directories() {
+        let root = env::temp_dir().join(format!("chess-tui-engines-{}", std::process::id()));
+        let first = root.join("first");
+        let second = root.join("second");
+        fs::create_dir_all(&first).unwrap();
+        fs::create_dir_all(&second).unwrap();
+
+        let stockfish = format!("stockfish{}", env::consts::EXE_SUFFIX);
+        let lc0 = format!("lc0{}", env::consts::EXE_SUFFIX);
+        File::create(first.join(&stockfish)).unwrap();
+        File::create(second.join(&stockfish)).unwrap();
+        File::create(second.join(&lc0)).unwrap();
+        // A directory with an engine name is not an engine
+        fs::create_dir_all(first.join(format!("komodo{}", env::consts::EXE_SUFFIX))).unwrap();
+
+        let path = env::join_paths([&first, &second, &root.join("missing")]).unwrap();
+        let engines = detect_engines_in(&path);
+
+        // The first directory of the PATH wins, as for the shell
+        assert_eq!(engines, vec![first.join(&stockfish), second.join(&lc0)]);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn engine_options_out_of_range_are_ignored() {
+        let config: toml::Value =
+            "engine_threads = 4\nengine_hash_mb = 0\nengine_movetime_ms = 2500"
+                .parse()
+                .unwrap();
+        assert_eq!(
+            EngineOptions::from_config(&config),
+            EngineOptions {
+                threads: Some(4),
+                hash_mb: None,
+                movetime_ms: Some(2500),
+            }
+        );
+
+        let config: toml::Value = "engine_threads = \"all\"\nengine_movetime_ms = -5"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            EngineOptions::from_config(&config),
+            EngineOptions::default()
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn pondering_goes_on_after_the_expected_reply() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let root = env::temp_dir().join(format!("chess-tui-ponder-{}", std::process::id()));
+        fs::create_dir_all(&root).unwrap();
+        let commands = root.join("commands");
+        let engine = root.join("engine");
+        // An engine writing down the commands it gets, the stop of a ponder search gives another move
+        fs::write(
+            &engine,
+            format!(
+                r#"#!/bin/sh
+echo "Fake engine"
+while read -r line; do
+  echo "$line" >> "{}"
+  case "$line" in
+    uci) echo "uciok" ;;
+    isready) echo "readyok" ;;
+    "go movetime"*) echo "bestmove e7e5 ponder g1f3" ;;
+    stop) echo "bestmove b8c6 ponder f1b5" ;;
+    quit) exit 0 ;;
+  esac
+done
+"#,
+                commands.display()
+            ),
+        )
+        .unwrap();
+        fs::set_permissions(&engine, fs::Permissions::from_mode(0o755)).unwrap();
+
+        let mut bot = Bot::new(engine.to_str().unwrap(), false);
+        bot.set_ponder(true);
+        bot.set_options(&EngineOptions {
+            movetime_ms: Some(10),
+            ..Default::default()
+        });
+        let fen = "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - 0 1";
+
+        let board = init_board();
+        assert_eq!(
+            bot.get_bot_move(fen.to_string(), &board),
+            convert_notation_into_position("e7e5")
+        );
+        assert_eq!(bot.ponder_move.as_deref(), Some("g1f3"));
+
+        // The player plays the expected reply
+        bot.pondered_board = Some(board);
+        assert_eq!(
+            bot.get_bot_move(fen.to_string(), &board),
+            convert_notation_into_position("b8c6")
+        );
+
+        let commands = fs::read_to_string(&commands).unwrap();
+        let commands: Vec<&str> = commands.lines().collect();
+        assert_eq!(
+            commands[..9],
+            [
+                "uci",
+                "setoption name Ponder value true",
+                "isready",
+                &format!("position fen {fen}"),
+                "go movetime 10",
+                &format!("position fen {fen} moves e7e5 g1f3"),
+                "go ponder infinite",
+                "ponderhit",
+                "stop",
+            ]
+        );
+
+        drop(bot);
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn engine_is_checked_while_the_interface_runs() {
+        use chess_tui::app::App;
+        use chess_tui::constants::{Pages, Popups};
+        use std::os::unix::fs::PermissionsExt;
+        use std::time::{Duration, Instant};
+
+        let root = env::temp_dir().join(format!("chess-tui-uci-check-{}", std::process::id()));
+        fs::create_dir_all(&root).unwrap();
+        let engines = [
+            ("silent", "sleep 30"),
+            ("uci", "read -r line; echo uciok; sleep 30"),
+        ];
+        for (name, script) in engines {
+            fs::write(root.join(name), format!("#!/bin/sh\n{script}\n")).unwrap();
+            fs::set_permissions(root.join(name), fs::Permissions::from_mode(0o755)).unwrap();
+        }
+
+        // An engine that doesn't answer leaves the popup waiting, the menu is still a key away
+        let mut app = App {
+            current_page: Pages::Bot,
+            chess_engine_path: Some(root.join("silent").to_string_lossy().to_string()),
+            ..Default::default()
+        };
+        let started_at = Instant::now();
+        app.check_chess_engine();
+        assert!(started_at.elapsed() < Duration::from_secs(1));
+        assert_eq!(app.current_popup, Some(Popups::CheckingEngine));
+        app.tick();
+        assert_eq!(app.current_popup, Some(Popups::CheckingEngine));
+
+        // Once the engine answers, the color is picked
+        app.chess_engine_path = Some(root.join("uci").to_string_lossy().to_string());
+        app.check_chess_engine();
+        while app.current_popup == Some(Popups::CheckingEngine)
+            && started_at.elapsed() < Duration::from_secs(5)
+        {
+            std::thread::sleep(Duration::from_millis(10));
+            app.tick();
+        }
+        assert_eq!(app.current_popup, None);
+        assert!(app.engine_check.is_none());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,118 @@
+#[cfg(test)]
+mod tests {
+    use chess_tui::game_logic::coord::Coord;
+    use chess_tui::game_logic::evaluation::Evaluation;
+    use chess_tui::game_logic::game::Game;
+    use chess_tui::game_logic::game_board::GameBoard;
+    use chess_tui::pieces::PieceColor;
+    use chess_tui::utils::invert_position;
+
+    #[test]
+    fn scores_are_read_from_white_side() {
+        let line = "info depth 12 seldepth 18 multipv 1 score cp 35 nodes 1024 pv e2e4";
+        assert_eq!(
+            Evaluation::from_info_line(line, PieceColor::White),
+            Some(Evaluation::Centipawns(35))
+        );
+        assert_eq!(
+            Evaluation::from_info_line(line, PieceColor::Black),
+            Some(Evaluation::Centipawns(-35))
+        );
+
+        let line = "info depth 20 score mate 3 pv d1h5";
+        assert_eq!(
+            Evaluation::from_info_line(line, PieceColor::Black),
+            Some(Evaluation::Mate(-3))
+        );
+        assert_eq!(
+            Evaluation::from_info_line("info depth 5 score cp -120 upperbound", PieceColor::White),
+            Some(Evaluation::Centipawns(-120))
+        );
+
+        assert_eq!(
+            Evaluation::from_info_line("info string NNUE enabled", PieceColor::White),
+            None
+        );
+        assert_eq!(
+            Evaluation::from_info_line("bestmove e2e4 ponder e7e5", PieceColor::White),
+            None
+        );
+    }
+
+    #[test]
+    fn labels_and_bar_shares() {
+        assert_eq!(Evaluation::Centipawns(35).label(), "+0.35");
+        assert_eq!(Evaluation::Centipawns(-120).label(), "-1.20");
+        assert_eq!(Evaluation::Mate(3).label(), "#3");
+        assert_eq!(Evaluation::Mate(-2).label(), "#-2");
+
+        assert_eq!(Evaluation::Centipawns(0).white_share(), 0.5);
+        assert!(Evaluation::Centipawns(300).white_share() > 0.75);
+        assert!(Evaluation::Centipawns(-300).white_share() < 0.25);
+        assert_eq!(Evaluation::Mate(1).white_share(), 1.0);
+        assert_eq!(Evaluation::Mate(-1).white_share(), 0.0);
+    }
+
+    #[test]
+    fn position_fen_whatever_the_orientation() {
+        let mut game_board = GameBoard::default();
+        assert_eq!(
+            game_board.position_fen(PieceColor::White),
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1"
+        );
+
+        game_board.flip_the_board();
+        assert_eq!(
+            game_board.position_fen(PieceColor::Black),
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR b KQkq - 0 1"
+        );
+    }
+
+    #[test]
+    fn position_fen_keeps_the_castlings_left() {
+        let game_board = GameBoard::from_fen("r3k2r/8/8/8/8/8/8/R3K1R1 w Qk - 0 1").unwrap();
+        assert_eq!(
+            game_board.position_fen(PieceColor::White),
+            "r3k2r/8/8/8/8/8/8/R3K1R1 w Qk - 0 1"
+        );
+    }
+
+    // 1. e4 e5 2. Ke2 Ke7 3. Ke1 Ke8 4. Nf3 in a bot game, whose board is never turned toward the player to move
+    fn bot_game_with_kings_back(is_flipped: bool) -> Game {
+        let mut game_board = GameBoard::default();
+        if is_flipped {
+            game_board.flip_the_board();
+        }
+        let mut game = Game::new(game_board, PieceColor::White);
+        let moves = [
+            ((6, 4), (4, 4)),
+            ((1, 4), (3, 4)),
+            ((7, 4), (6, 4)),
+            ((0, 4), (1, 4)),
+            ((6, 4), (7, 4)),
+            ((1, 4), (0, 4)),
+            ((7, 6), (5, 5)),
+        ];
+        for ((from_row, from_col), (to_row, to_col)) in moves {
+            let (mut from, mut to) = (Coord::new(from_row, from_col), Coord::new(to_row, to_col));
+            if is_flipped {
+                (from, to) = (invert_position(&from), invert_position(&to));
+            }
+            game.execute_move(&from, &to);
+            game.switch_player_turn();
+        }
+        game
+    }
+
+    #[test]
+    fn position_fen_of_a_bot_game_with_black_to_move() {
+        for is_flipped in [false, true] {
+            let game = bot_game_with_kings_back(is_flipped);
+            assert_eq!(game.player_turn, PieceColor::Black);
+            assert_eq!(
+                game.game_board.position_fen(game.player_turn),
+                "rnbqkbnr/pppp1ppp/8/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R b - - 5 4"
+            );
+        }
+    }
+}
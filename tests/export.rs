@@ -0,0 +1,140 @@
+#[cfg(test)]
+mod tests {
+    use chess_tui::app::App;
+    use chess_tui::export::{board_to_unicode_block, save_board_diagram};
+    use chess_tui::game_logic::coord::Coord;
+    use chess_tui::game_logic::game_board::GameBoard;
+    use chess_tui::handler::handle_key_events;
+    use chess_tui::pieces::PieceColor;
+    use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::{env, fs};
+
+    fn play(app: &mut App, from: Coord, to: Coord) {
+        for cell in [from, to] {
+            app.game.ui.cursor_coordinates = cell;
+            handle_key_events(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE), app).unwrap();
+        }
+    }
+
+    fn diagram_fen(app: &App) -> String {
+        let diagram = app.game.game_board.ascii_diagram(app.game.player_turn);
+        diagram.lines().next().unwrap().to_string()
+    }
+
+    #[test]
+    fn unicode_block_of_the_starting_position() {
+        let game_board = GameBoard::default();
+
+        assert_eq!(
+            board_to_unicode_block(&game_board, false),
+            "  ┌─────────────────┐
+8 │ ♜ ♞ ♝ ♛ ♚ ♝ ♞ ♜ │
+7 │ ♟ ♟ ♟ ♟ ♟ ♟ ♟ ♟ │
+6 │ · · · · · · · · │
+5 │ · · · · · · · · │
+4 │ · · · · · · · · │
+3 │ · · · · · · · · │
+2 │ ♙ ♙ ♙ ♙ ♙ ♙ ♙ ♙ │
+1 │ ♖ ♘ ♗ ♕ ♔ ♗ ♘ ♖ │
+  └─────────────────┘
+    a b c d e f g h"
+        );
+    }
+
+    #[test]
+    fn unicode_block_from_black_side() {
+        let mut game_board = GameBoard::default();
+        let expected = "  ┌─────────────────┐
+1 │ ♖ ♘ ♗ ♔ ♕ ♗ ♘ ♖ │
+2 │ ♙ ♙ ♙ ♙ ♙ ♙ ♙ ♙ │
+3 │ · · · · · · · · │
+4 │ · · · · · · · · │
+5 │ · · · · · · · · │
+6 │ · · · · · · · · │
+7 │ ♟ ♟ ♟ ♟ ♟ ♟ ♟ ♟ │
+8 │ ♜ ♞ ♝ ♚ ♛ ♝ ♞ ♜ │
+  └─────────────────┘
+    h g f e d c b a";
+
+        assert_eq!(board_to_unicode_block(&game_board, true), expected);
+
+        // The way the board is stored doesn't change the drawing
+        game_board.flip_the_board();
+        assert_eq!(board_to_unicode_block(&game_board, true), expected);
+    }
+
+    #[test]
+    fn diagram_with_the_fen_in_a_file() {
+        let game_board =
+            GameBoard::from_fen("4k3/8/8/8/8/8/4P3/4K3 b - - 0 1").expect("valid position");
+        let diagram = game_board.ascii_diagram(PieceColor::Black);
+        assert_eq!(
+            diagram,
+            "4k3/8/8/8/8/8/4P3/4K3 b - - 0 1
+
+  ┌─────────────────┐
+8 │ · · · · ♚ · · · │
+7 │ · · · · · · · · │
+6 │ · · · · · · · · │
+5 │ · · · · · · · · │
+4 │ · · · · · · · · │
+3 │ · · · · · · · · │
+2 │ · · · · ♙ · · · │
+1 │ · · · · ♔ · · · │
+  └─────────────────┘
+    a b c d e f g h
+"
+        );
+
+        let folder = env::temp_dir().join(format!("chess-tui-export-{}", std::process::id()));
+        fs::create_dir_all(&folder).unwrap();
+        let path = save_board_diagram(&folder, &diagram).unwrap();
+        assert_eq!(path.parent(), Some(folder.as_path()));
+        assert!(path
+            .file_name()
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .starts_with("board-"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), diagram);
+
+        fs::remove_dir_all(&folder).unwrap();
+    }
+
+    #[test]
+    fn diagram_fen_with_the_en_passant_cell_and_the_counters() {
+        let mut app = App::default();
+        app.menu_select();
+
+        // 1. e4, the board is then turned toward black
+        play(&mut app, Coord::new(6, 4), Coord::new(4, 4));
+        assert_eq!(
+            diagram_fen(&app),
+            "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1"
+        );
+
+        // 1... d5, played with the black pieces at the bottom
+        play(&mut app, Coord::new(6, 4), Coord::new(4, 4));
+        assert_eq!(
+            diagram_fen(&app),
+            "rnbqkbnr/ppp1pppp/8/3p4/4P3/8/PPPP1PPP/RNBQKBNR w KQkq d6 0 2"
+        );
+
+        // 2. Nf3
+        play(&mut app, Coord::new(7, 6), Coord::new(5, 5));
+        assert_eq!(
+            diagram_fen(&app),
+            "rnbqkbnr/ppp1pppp/8/3p4/4P3/5N2/PPPP1PPP/RNBQKB1R b KQkq - 1 2"
+        );
+    }
+
+    #[test]
+    fn diagram_fen_keeps_the_counters_of_the_position_set_up() {
+        let game_board =
+            GameBoard::from_fen("4k3/8/8/8/8/8/4P3/4K3 b - - 3 12").expect("valid position");
+        assert_eq!(
+            game_board.position_fen(PieceColor::Black),
+            "4k3/8/8/8/8/8/4P3/4K3 b - - 3 12"
+        );
+    }
+}
@@ -1,7 +1,9 @@
 #[cfg(test)]
 mod tests {
+    use chess_tui::app::App;
+    use chess_tui::constants::Pages;
     use chess_tui::game_logic::coord::Coord;
-    use chess_tui::game_logic::game::Game;
+    use chess_tui::game_logic::game::{Game, GameState};
     use chess_tui::game_logic::game_board::GameBoard;
     use chess_tui::pieces::{PieceColor, PieceMove, PieceType};
 
@@ -42,7 +44,7 @@ mod tests {
 
         // Move the king to replicate a third time the same position
         assert_eq!(
-            game.game_board.fen_position(false, game.player_turn),
+            game.game_board.fen_position(false),
             "2k4R/8/4K3/8/8/8/8/8 b - - 0 0"
         );
     }
@@ -104,7 +106,7 @@ mod tests {
 
         // Move the king to replicate a third time the same position
         assert_eq!(
-            game.game_board.fen_position(false, game.player_turn),
+            game.game_board.fen_position(false),
             "2k4R/8/4K3/8/2P5/8/8/8 b - c3 0 0"
         );
     }
@@ -163,8 +165,137 @@ mod tests {
 
         // Move the king to replicate a third time the same position
         assert_eq!(
-            game.game_board.fen_position(false, game.player_turn),
-            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR b kq - 0 0"
+            game.game_board.fen_position(false),
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR b KQkq - 0 0"
         );
     }
+
+    #[test]
+    fn starting_position_fen() {
+        let game_board =
+            GameBoard::from_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1")
+                .unwrap();
+        assert_eq!(game_board.board, GameBoard::default().board);
+        assert!(!game_board.is_flipped);
+        assert!(game_board.moved_pieces.is_empty());
+    }
+
+    #[test]
+    fn black_to_move_is_stored_at_the_bottom() {
+        let game_board = GameBoard::from_fen("4k3/8/8/8/8/8/8/4K2R b K - 0 1").unwrap();
+        assert!(game_board.is_flipped);
+        assert_eq!(
+            game_board.board[7][3],
+            Some((PieceType::King, PieceColor::Black))
+        );
+        assert_eq!(
+            game_board.board[0][0],
+            Some((PieceType::Rook, PieceColor::White))
+        );
+        // Black can't castle
+        assert_eq!(game_board.moved_pieces.len(), 3);
+    }
+
+    #[test]
+    fn halfmove_clock_is_kept() {
+        let mut game_board = GameBoard::from_fen("4k3/8/8/8/8/8/8/R3K3 w - - 99 80").unwrap();
+        assert_eq!(game_board.get_consecutive_non_pawn_or_capture(), 99);
+        assert!(!game_board.is_draw(PieceColor::White));
+
+        game_board.increment_consecutive_non_pawn_or_capture(PieceType::Rook, None);
+        assert!(game_board.is_draw(PieceColor::Black));
+    }
+
+    #[test]
+    fn halfmove_clock_counts_both_players() {
+        // 60 halfmoves are 30 moves of each player
+        let mut game_board = GameBoard::from_fen("8/8/8/4k3/8/8/8/R3K3 w - - 60 80").unwrap();
+        assert!(!game_board.is_draw_claimable());
+        assert!(!game_board.is_draw(PieceColor::White));
+
+        // A clock past the limit ends the game at once
+        let mut game_board = GameBoard::from_fen("8/8/8/4k3/8/8/8/R3K3 w - - 120 80").unwrap();
+        assert!(game_board.is_draw_claimable());
+        assert!(game_board.is_draw(PieceColor::White));
+    }
+
+    #[test]
+    fn en_passant_target_is_kept() {
+        let fen = "4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 2";
+        let game_board = GameBoard::from_fen(fen).unwrap();
+        assert_eq!(game_board.position_fen(PieceColor::White), fen);
+
+        let mut app = App::default();
+        app.start_position_game(game_board);
+        assert!(app.game.play_saved_move("e5d6"));
+        assert_eq!(app.game.game_board.san_history, ["exd6"]);
+        assert_eq!(
+            app.game.game_board.position_fen(app.game.player_turn),
+            "4k3/8/3P4/8/8/8/8/4K3 b - - 0 2"
+        );
+
+        // The target is gone once a move was played
+        let mut app = App::default();
+        app.start_position_game(GameBoard::from_fen(fen).unwrap());
+        assert!(app.game.play_saved_move("e1e2"));
+        assert!(app.game.play_saved_move("e8e7"));
+        assert!(!app.game.play_saved_move("e5d6"));
+    }
+
+    #[test]
+    fn black_en_passant_target_is_kept() {
+        let mut app = App::default();
+        app.start_position_game(GameBoard::from_fen("4k3/8/8/8/5pP1/8/8/4K3 b - g3 0 1").unwrap());
+        assert!(app.game.play_saved_move("f4g3"));
+        assert_eq!(
+            app.game.game_board.position_fen(app.game.player_turn),
+            "4k3/8/8/8/8/6p1/8/4K3 w - - 0 2"
+        );
+    }
+
+    #[test]
+    fn malformed_fens_are_errors() {
+        for fen in [
+            "",
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP w KQkq - 0 1",
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNX w KQkq - 0 1",
+            "rnbqkbnr/pppppppp/9/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            "rnbqkbnr/pppppppp/7/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR x KQkq - 0 1",
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkx - 0 1",
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq e5 0 1",
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq e6 0 1",
+            "4k3/8/8/3pP3/8/8/8/4K3 b - d6 0 1",
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - -1 1",
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 0",
+            // Positions that can't be played
+            "8/8/8/8/8/8/8/4K3 w - - 0 1",
+            "4k3/8/8/8/8/8/8/4K3 w K - 0 1",
+            "4k3/4Q3/8/8/8/8/8/4K3 w - - 0 1",
+        ] {
+            assert!(GameBoard::from_fen(fen).is_err(), "{fen} should be refused");
+        }
+    }
+
+    #[test]
+    fn position_game_is_a_solo_game() {
+        let mut app = App::default();
+        let game_board = GameBoard::from_fen("4k3/8/8/8/8/8/4P3/4K3 b - - 0 1").unwrap();
+        app.start_position_game(game_board);
+
+        assert_eq!(app.current_page, Pages::Solo);
+        assert_eq!(app.game.player_turn, PieceColor::Black);
+        assert!(app
+            .game
+            .is_move_legal(&Coord::new(7, 3), &Coord::new(6, 3), None));
+        assert_eq!(app.game.game_state, GameState::Playing);
+    }
+
+    #[test]
+    fn finished_position_ends_the_game() {
+        let mut app = App::default();
+        let game_board = GameBoard::from_fen("7k/5Q2/6K1/8/8/8/8/8 b - - 0 1").unwrap();
+        app.start_position_game(game_board);
+        assert_eq!(app.game.game_state, GameState::Draw);
+    }
 }
@@ -0,0 +1,58 @@
+#[cfg(test)]
+mod tests {
+    use chess_tui::app::App;
+    use chess_tui::constants::{Pages, Popups};
+    use chess_tui::handler::handle_key_events;
+    use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    fn press(app: &mut App, code: KeyCode) {
+        handle_key_events(KeyEvent::new(code, KeyModifiers::NONE), app).unwrap();
+    }
+
+    #[test]
+    fn help_opens_on_every_page() {
+        for page in [Pages::Home, Pages::Solo, Pages::BoardEditor, Pages::Credit] {
+            let mut app = App {
+                current_page: page.clone(),
+                ..Default::default()
+            };
+            press(&mut app, KeyCode::Char('?'));
+            assert_eq!(app.current_popup, Some(Popups::Help), "{page:?}");
+            press(&mut app, KeyCode::Char('?'));
+            assert_eq!(app.current_popup, None, "{page:?}");
+            assert_eq!(app.current_page, page);
+        }
+    }
+
+    #[test]
+    fn help_key_can_be_changed() {
+        let mut app = App {
+            help_key: 'H',
+            ..Default::default()
+        };
+        press(&mut app, KeyCode::Char('?'));
+        assert_eq!(app.current_popup, None);
+        press(&mut app, KeyCode::Char('H'));
+        assert_eq!(app.current_popup, Some(Popups::Help));
+    }
+
+    #[test]
+    fn help_key_keeps_the_other_popups() {
+        let mut app = App {
+            current_popup: Some(Popups::ColorSelection),
+            ..Default::default()
+        };
+        press(&mut app, KeyCode::Char('?'));
+        assert_eq!(app.current_popup, Some(Popups::ColorSelection));
+
+        // The host address prompt takes the character
+        let mut app = App {
+            current_page: Pages::Multiplayer,
+            current_popup: Some(Popups::EnterHostIP),
+            ..Default::default()
+        };
+        press(&mut app, KeyCode::Char('?'));
+        assert_eq!(app.current_popup, Some(Popups::EnterHostIP));
+        assert_eq!(app.game.ui.prompt.input, "?");
+    }
+}
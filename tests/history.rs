@@ -0,0 +1,165 @@
+#[cfg(test)]
+mod tests {
+    use chess_tui::app::App;
+    use chess_tui::constants::HistoryNotation;
+    use chess_tui::game_logic::coord::Coord;
+    use chess_tui::game_logic::game::GameState;
+    use chess_tui::game_logic::game_board::GameBoard;
+    use chess_tui::handler::handle_key_events;
+    use chess_tui::pieces::{PieceColor, PieceType};
+    use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use ratatui::{backend::TestBackend, layout::Rect, Terminal};
+
+    fn press(app: &mut App, code: KeyCode) {
+        handle_key_events(KeyEvent::new(code, KeyModifiers::NONE), app).unwrap();
+    }
+
+    fn play(app: &mut App, from: Coord, to: Coord) {
+        app.game.ui.cursor_coordinates = from;
+        press(app, KeyCode::Enter);
+        app.game.ui.cursor_coordinates = to;
+        press(app, KeyCode::Enter);
+    }
+
+    // 1. f3 e5 2. g4 Qh4#, white is mated with the white pieces at the bottom
+    fn fools_mate() -> App {
+        let mut app = App::default();
+        app.menu_select();
+        play(&mut app, Coord::new(6, 5), Coord::new(5, 5));
+        play(&mut app, Coord::new(6, 3), Coord::new(4, 3));
+        play(&mut app, Coord::new(6, 6), Coord::new(4, 6));
+        play(&mut app, Coord::new(7, 4), Coord::new(3, 0));
+        assert_eq!(app.game.game_state, GameState::Checkmate);
+        assert!(!app.game.game_board.is_flipped);
+        app
+    }
+
+    #[test]
+    fn arrows_move_the_cursor_while_the_game_goes_on() {
+        let mut app = App::default();
+        app.menu_select();
+        press(&mut app, KeyCode::Left);
+        assert_eq!(app.game.ui.history_index, None);
+        assert_eq!(app.game.ui.cursor_coordinates, Coord::new(4, 3));
+    }
+
+    #[test]
+    fn stepping_stops_at_the_first_and_the_latest_positions() {
+        let mut app = fools_mate();
+
+        // The live board is the latest position
+        press(&mut app, KeyCode::Right);
+        assert_eq!(app.game.ui.history_index, None);
+
+        press(&mut app, KeyCode::Left);
+        assert_eq!(app.game.ui.history_index, Some(3));
+        press(&mut app, KeyCode::Home);
+        assert_eq!(app.game.ui.history_index, Some(0));
+        press(&mut app, KeyCode::Left);
+        assert_eq!(app.game.ui.history_index, Some(0));
+
+        let first_position = app.game.history_view().unwrap();
+        assert_eq!(first_position.game_board.board, GameBoard::default().board);
+        assert_eq!(first_position.player_turn, PieceColor::White);
+
+        press(&mut app, KeyCode::Right);
+        press(&mut app, KeyCode::Right);
+        press(&mut app, KeyCode::Right);
+        assert_eq!(app.game.ui.history_index, Some(3));
+        press(&mut app, KeyCode::Right);
+        assert_eq!(app.game.ui.history_index, None);
+        assert!(app.game.history_view().is_none());
+
+        press(&mut app, KeyCode::Home);
+        press(&mut app, KeyCode::End);
+        assert_eq!(app.game.ui.history_index, None);
+    }
+
+    #[test]
+    fn replayed_positions_keep_the_live_orientation() {
+        let mut app = fools_mate();
+
+        // After 1. f3 e5 the board was stored with black at the bottom, it is shown with white at the bottom
+        app.game.ui.history_index = Some(2);
+        let view = app.game.history_view().unwrap();
+        assert_eq!(view.player_turn, PieceColor::White);
+        assert_eq!(
+            view.game_board.board[5][5],
+            Some((PieceType::Pawn, PieceColor::White))
+        );
+        assert_eq!(
+            view.game_board.board[3][4],
+            Some((PieceType::Pawn, PieceColor::Black))
+        );
+        assert_eq!(
+            view.game_board.board[0][3],
+            Some((PieceType::Queen, PieceColor::Black))
+        );
+
+        // The move leading to the position is given with the same orientation, e7 to e5
+        let (_, move_cells) = app
+            .game
+            .game_board
+            .history_position(2, app.game.game_board.is_flipped)
+            .unwrap();
+        assert_eq!(move_cells, Some((Coord::new(1, 4), Coord::new(3, 4))));
+
+        // The same position seen from the black side
+        let (board, move_cells) = app.game.game_board.history_position(2, true).unwrap();
+        assert_eq!(board[4][3], Some((PieceType::Pawn, PieceColor::Black)));
+        assert_eq!(move_cells, Some((Coord::new(6, 3), Coord::new(4, 3))));
+    }
+
+    #[test]
+    fn restart_goes_back_to_the_live_board() {
+        let mut app = fools_mate();
+        press(&mut app, KeyCode::Home);
+        press(&mut app, KeyCode::Char('r'));
+        assert_eq!(app.game.ui.history_index, None);
+    }
+
+    // The text of the history panel, a line for each row of the terminal
+    fn history_panel(app: &App) -> Vec<String> {
+        let mut terminal = Terminal::new(TestBackend::new(50, 10)).unwrap();
+        terminal
+            .draw(|frame| {
+                app.game
+                    .ui
+                    .history_render(Rect::new(0, 0, 50, 10), frame, &app.game)
+            })
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        (0..10)
+            .map(|y| {
+                let line: String = (0..50).map(|x| buffer[(x, y)].symbol()).collect();
+                line.split_whitespace().collect::<Vec<_>>().join(" ")
+            })
+            .collect()
+    }
+
+    #[test]
+    fn history_of_a_position_with_black_to_move() {
+        let mut app = App::default();
+        let game_board = GameBoard::from_fen("4k3/8/8/8/8/8/4P3/4K3 b - - 0 12").unwrap();
+        app.start_position_game(game_board);
+        for saved_move in ["e8d8", "e2e4", "d8c8"] {
+            assert!(app.game.play_saved_move(saved_move));
+        }
+
+        app.game.ui.history_notation = HistoryNotation::San;
+        let panel = history_panel(&app);
+        assert!(panel.contains(&"│ 12. ... Kd8 │".to_string()), "{panel:?}");
+        assert!(panel.contains(&"│ 13. e4 Kc8 │".to_string()), "{panel:?}");
+
+        app.game.ui.history_notation = HistoryNotation::Coordinate;
+        let panel = history_panel(&app);
+        assert!(
+            panel.contains(&"│ 12. ... ♔ e8-d8 │".to_string()),
+            "{panel:?}"
+        );
+        assert!(
+            panel.contains(&"│ 13. ♟ e2-e4 ♔ d8-c8 │".to_string()),
+            "{panel:?}"
+        );
+    }
+}
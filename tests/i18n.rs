@@ -0,0 +1,54 @@
+#[cfg(test)]
+mod tests {
+    use chess_tui::i18n::{catalog, set_language, t, Language};
+
+    #[test]
+    fn every_language_has_the_english_keys() {
+        let english = catalog(Language::English);
+        let french = catalog(Language::French);
+
+        for (key, _) in english {
+            assert!(
+                french.iter().any(|(french_key, _)| french_key == key),
+                "{key} is not translated in French"
+            );
+        }
+        for (key, _) in french {
+            assert!(
+                english.iter().any(|(english_key, _)| english_key == key),
+                "{key} is unknown in English"
+            );
+            assert_eq!(
+                french
+                    .iter()
+                    .filter(|(other_key, _)| other_key == key)
+                    .count(),
+                1,
+                "{key} is translated twice"
+            );
+        }
+    }
+
+    #[test]
+    fn strings_follow_the_language() {
+        set_language(Language::French);
+        assert_eq!(t("menu.solo"), "Partie normale");
+        assert_eq!(
+            t("startup.unavailable").replace("{}", "puzzle"),
+            "La page puzzle ne peut pas être ouverte au démarrage"
+        );
+        // An unknown key is shown as is
+        assert_eq!(t("menu.unknown"), "menu.unknown");
+
+        set_language(Language::English);
+        assert_eq!(t("menu.solo"), "Normal game");
+    }
+
+    #[test]
+    fn language_codes() {
+        assert_eq!(Language::from_code("fr"), Some(Language::French));
+        assert_eq!(Language::from_code("English"), Some(Language::English));
+        assert_eq!(Language::from_code("de"), None);
+        assert_eq!(Language::French.code(), "fr");
+    }
+}
@@ -0,0 +1,170 @@
+#[cfg(test)]
+mod tests {
+    use chess_tui::app::App;
+    use chess_tui::constants::Pages;
+    use chess_tui::game_logic::coord::Coord;
+    use chess_tui::handler::handle_key_events;
+    use chess_tui::keybindings::KeyBindings;
+    use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use toml::Value;
+
+    fn press(app: &mut App, code: KeyCode) {
+        handle_key_events(KeyEvent::new(code, KeyModifiers::NONE), app).unwrap();
+    }
+
+    fn keybindings(config: &str) -> KeyBindings {
+        let config = config.parse::<Value>().unwrap();
+        KeyBindings::from_config(config.get("keybindings").unwrap(), '?')
+    }
+
+    #[test]
+    fn default_keys_are_kept() {
+        let keybindings = KeyBindings::default();
+        assert_eq!(keybindings.key("quit"), Some('q'));
+        assert_eq!(keybindings.key("select"), Some(' '));
+        assert_eq!(keybindings.key("unknown"), None);
+        for key in ['q', 'k', ' ', 'K', 'D', '1'] {
+            assert_eq!(
+                keybindings.to_default_key(KeyCode::Char(key)),
+                KeyCode::Char(key)
+            );
+        }
+        assert_eq!(keybindings.to_default_key(KeyCode::Up), KeyCode::Up);
+    }
+
+    #[test]
+    fn configured_keys_replace_the_default_ones() {
+        let keybindings = keybindings(
+            r#"
+            [keybindings]
+            move_up = "w"
+            move_down = "v"
+            select = "g"
+            quit = "Q"
+            "#,
+        );
+        assert_eq!(keybindings.key("move_up"), Some('w'));
+        assert_eq!(keybindings.key("quit"), Some('Q'));
+
+        assert_eq!(
+            keybindings.to_default_key(KeyCode::Char('w')),
+            KeyCode::Char('k')
+        );
+        assert_eq!(
+            keybindings.to_default_key(KeyCode::Char('Q')),
+            KeyCode::Char('q')
+        );
+        // The replaced default keys do nothing
+        assert_eq!(
+            keybindings.to_default_key(KeyCode::Char('k')),
+            KeyCode::Null
+        );
+        assert_eq!(
+            keybindings.to_default_key(KeyCode::Char('q')),
+            KeyCode::Null
+        );
+        // The keys of the other actions don't change
+        assert_eq!(
+            keybindings.to_default_key(KeyCode::Char('h')),
+            KeyCode::Char('h')
+        );
+    }
+
+    #[test]
+    fn wrong_bindings_are_ignored() {
+        let keybindings = keybindings(
+            r#"
+            [keybindings]
+            jump = "w"
+            quit = "ctrl"
+            move_up = 3
+            move_left = "g"
+            "#,
+        );
+        assert_eq!(keybindings.key("quit"), Some('q'));
+        assert_eq!(keybindings.key("move_up"), Some('k'));
+        assert_eq!(keybindings.key("move_left"), Some('g'));
+
+        let not_a_table = "keybindings = \"vim\"".parse::<Value>().unwrap();
+        assert_eq!(
+            KeyBindings::from_config(not_a_table.get("keybindings").unwrap(), '?'),
+            KeyBindings::default()
+        );
+    }
+
+    #[test]
+    fn keys_already_used_keep_the_default_ones() {
+        let keybindings = keybindings(
+            r#"
+            [keybindings]
+            move_up = "j"
+            move_down = "w"
+            quit = "w"
+            restart = "?"
+            home = "D"
+            resign = "t"
+            "#,
+        );
+        // Two actions can't share a key, the first one of the list keeps it
+        assert_eq!(keybindings.key("quit"), Some('w'));
+        assert_eq!(keybindings.key("move_down"), Some('j'));
+        // move_down is back on its default key, which move_up can't take anymore
+        assert_eq!(keybindings.key("move_up"), Some('k'));
+        // The help key and the fixed keys are never bound to an action
+        assert_eq!(keybindings.key("restart"), Some('r'));
+        assert_eq!(keybindings.key("home"), Some('b'));
+        assert_eq!(keybindings.key("resign"), Some('f'));
+    }
+
+    #[test]
+    fn used_keys_include_the_default_and_fixed_keys() {
+        let default_keybindings = KeyBindings::default();
+        assert!(default_keybindings.is_used('q'));
+        assert!(default_keybindings.is_used('D'));
+        assert!(!default_keybindings.is_used('?'));
+
+        // A default key bound to nothing anymore is free
+        let keybindings = keybindings(
+            r#"
+            [keybindings]
+            quit = "Q"
+            "#,
+        );
+        assert!(keybindings.is_used('Q'));
+        assert!(!keybindings.is_used('q'));
+    }
+
+    #[test]
+    fn configuration_table_reads_back_the_same_bindings() {
+        let mut keybindings = KeyBindings::default();
+        keybindings.set("select", 'g');
+        assert_eq!(
+            KeyBindings::from_config(&keybindings.to_config(), '?'),
+            keybindings
+        );
+    }
+
+    #[test]
+    fn handler_uses_the_configured_keys() {
+        let mut app = App {
+            current_page: Pages::Solo,
+            ..Default::default()
+        };
+        app.keybindings.set("move_up", 'w');
+        app.keybindings.set("quit", 'Q');
+        app.game.ui.cursor_coordinates = Coord::new(6, 4);
+
+        press(&mut app, KeyCode::Char('w'));
+        assert_eq!(app.game.ui.cursor_coordinates, Coord::new(5, 4));
+        press(&mut app, KeyCode::Char('k'));
+        assert_eq!(app.game.ui.cursor_coordinates, Coord::new(5, 4));
+        // The arrows always work
+        press(&mut app, KeyCode::Up);
+        assert_eq!(app.game.ui.cursor_coordinates, Coord::new(4, 4));
+
+        press(&mut app, KeyCode::Char('q'));
+        assert!(app.running);
+        press(&mut app, KeyCode::Char('Q'));
+        assert!(!app.running);
+    }
+}
@@ -0,0 +1,184 @@
+#[cfg(test)]
+mod tests {
+    use chess_tui::app::App;
+    use chess_tui::constants::Pages;
+    use chess_tui::game_logic::coord::Coord;
+    use chess_tui::game_logic::game::{Game, GameState};
+    use chess_tui::game_logic::game_board::GameBoard;
+    use chess_tui::last_game::SavedGame;
+    use chess_tui::pieces::{PieceColor, PieceType};
+    use chess_tui::utils::invert_position;
+
+    // Play a move with the cursor as the player does, the cells have the white pieces at the bottom
+    fn play(game: &mut Game, from: (i8, i8), to: (i8, i8), promotion_cursor: Option<i8>) {
+        let cell = |(row, col): (i8, i8)| {
+            let coord = Coord::new(row as u8, col as u8);
+            if game.game_board.is_flipped {
+                invert_position(&coord)
+            } else {
+                coord
+            }
+        };
+        let (from, to) = (cell(from), cell(to));
+        game.ui.cursor_coordinates = from;
+        game.handle_cell_click();
+        game.ui.cursor_coordinates = to;
+        game.handle_cell_click();
+        if let Some(promotion_cursor) = promotion_cursor {
+            assert_eq!(game.game_state, GameState::Promotion);
+            game.ui.promotion_cursor = promotion_cursor;
+            game.handle_cell_click();
+        }
+    }
+
+    fn resume(saved_game: SavedGame) -> App {
+        let mut app = App {
+            last_game: Some(saved_game),
+            ..Default::default()
+        };
+        assert_eq!(app.menu_len(), 8);
+        app.menu_cursor = 7;
+        app.menu_select();
+        assert_eq!(app.current_page, Pages::Solo);
+        assert!(app.last_game.is_none());
+        app
+    }
+
+    fn assert_same_game(resumed: &Game, played: &Game) {
+        assert_eq!(resumed.game_board.board, played.game_board.board);
+        assert_eq!(resumed.game_board.is_flipped, played.game_board.is_flipped);
+        assert_eq!(resumed.player_turn, played.player_turn);
+        assert_eq!(resumed.game_state, GameState::Playing);
+        assert_eq!(
+            resumed.game_board.san_history,
+            played.game_board.san_history
+        );
+        assert_eq!(
+            resumed.game_board.board_history,
+            played.game_board.board_history
+        );
+    }
+
+    #[test]
+    fn replaying_the_saved_moves_gives_the_same_position() {
+        let mut game = Game::default();
+        // 1. e4 d5 2. exd5 c6 3. dxc6 Nf6 4. cxb7 Nbd7 5. bxa8=N
+        play(&mut game, (6, 4), (4, 4), None);
+        play(&mut game, (1, 3), (3, 3), None);
+        play(&mut game, (4, 4), (3, 3), None);
+        play(&mut game, (1, 2), (2, 2), None);
+        play(&mut game, (3, 3), (2, 2), None);
+        play(&mut game, (0, 6), (2, 5), None);
+        play(&mut game, (2, 2), (1, 1), None);
+        play(&mut game, (0, 1), (1, 3), None);
+        play(&mut game, (1, 1), (0, 0), Some(3));
+
+        let saved_game = SavedGame::from_game(&game).unwrap();
+        assert_eq!(saved_game.mode, "solo");
+        assert_eq!(
+            saved_game.start_fen,
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1"
+        );
+        assert_eq!(saved_game.moves[0], "e2e4");
+        assert_eq!(saved_game.moves[1], "d7d5");
+        assert_eq!(saved_game.moves[8], "b7a8n");
+
+        let saved_game = SavedGame::from_toml(&saved_game.to_toml()).unwrap();
+        let app = resume(saved_game);
+        assert_eq!(app.game.player_turn, PieceColor::Black);
+        assert!(app.game.game_board.is_flipped);
+        assert_same_game(&app.game, &game);
+    }
+
+    #[test]
+    fn replaying_a_game_started_from_a_position() {
+        let fen = "4k3/8/8/8/8/8/4P3/4K2R b K - 0 1";
+        let mut game = Game::new(GameBoard::from_fen(fen).unwrap(), PieceColor::Black);
+        play(&mut game, (0, 4), (0, 3), None);
+        // The king castles by going to the rook cell
+        play(&mut game, (7, 4), (7, 7), None);
+
+        let saved_game = SavedGame::from_game(&game).unwrap();
+        assert_eq!(saved_game.start_fen, "4k3/8/8/8/8/8/4P3/4K2R b K - 0 1");
+        assert_eq!(saved_game.moves, vec!["e8d8", "e1h1"]);
+
+        // Black is at the bottom after the castling
+        let app = resume(saved_game);
+        assert_eq!(
+            app.game.game_board.board[0][1],
+            Some((PieceType::King, PieceColor::White))
+        );
+        assert_same_game(&app.game, &game);
+    }
+
+    #[test]
+    fn finished_or_empty_games_are_not_saved() {
+        let mut game = Game::default();
+        assert_eq!(SavedGame::from_game(&game), None);
+
+        play(&mut game, (6, 4), (4, 4), None);
+        assert!(SavedGame::from_game(&game).is_some());
+        game.resign();
+        assert_eq!(SavedGame::from_game(&game), None);
+    }
+
+    #[test]
+    fn saved_game_file_is_toml() {
+        let saved_game = SavedGame::from_toml(
+            "mode = \"solo\"\nstart_fen = \"4k3/8/8/8/8/8/4P3/4K3 w - - 0 1\"\nmoves = [\"e2e4\", \"e8d8\"]\n",
+        )
+        .unwrap();
+        assert_eq!(saved_game.mode, "solo");
+        assert_eq!(saved_game.start_fen, "4k3/8/8/8/8/8/4P3/4K3 w - - 0 1");
+        assert_eq!(saved_game.moves, vec!["e2e4", "e8d8"]);
+
+        // The values are escaped, whatever they hold
+        let saved_game = SavedGame {
+            mode: "solo \"quoted\" \\ game".to_string(),
+            ..saved_game
+        };
+        assert_eq!(
+            SavedGame::from_toml(&saved_game.to_toml()),
+            Some(saved_game)
+        );
+        assert_eq!(SavedGame::from_toml("mode = \"solo\"\nmoves = 1"), None);
+    }
+
+    #[test]
+    fn leaving_the_solo_game_keeps_it_to_resume() {
+        let mut app = App {
+            current_page: Pages::Solo,
+            last_game: Some(SavedGame {
+                mode: "solo".to_string(),
+                start_fen: "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1".to_string(),
+                moves: vec!["d2d4".to_string()],
+            }),
+            ..Default::default()
+        };
+        play(&mut app.game, (6, 4), (4, 4), None);
+        app.go_to_home();
+        assert_eq!(app.last_game.unwrap().moves, vec!["e2e4"]);
+
+        // A finished game leaves nothing to resume
+        let mut app = App {
+            current_page: Pages::Solo,
+            ..Default::default()
+        };
+        play(&mut app.game, (6, 4), (4, 4), None);
+        app.game.resign();
+        app.go_to_home();
+        assert_eq!(app.last_game, None);
+    }
+
+    #[test]
+    fn the_replay_stops_at_a_wrong_move() {
+        let saved_game = SavedGame {
+            mode: "solo".to_string(),
+            start_fen: "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1".to_string(),
+            moves: vec!["e2e4".to_string(), "e7e4".to_string(), "d7d5".to_string()],
+        };
+        let app = resume(saved_game);
+        assert_eq!(app.game.game_board.move_history.len(), 1);
+        assert_eq!(app.game.player_turn, PieceColor::Black);
+    }
+}
@@ -0,0 +1,96 @@
+#[cfg(test)]
+mod tests {
+    use chess_tui::app::App;
+    use chess_tui::constants::Pages;
+    use chess_tui::game_logic::coord::Coord;
+    use chess_tui::game_logic::game::GameState;
+    use chess_tui::handler::handle_key_events;
+    use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    fn press(app: &mut App, code: KeyCode) {
+        handle_key_events(KeyEvent::new(code, KeyModifiers::NONE), app).unwrap();
+    }
+
+    fn play(app: &mut App, from: Coord, to: Coord) {
+        app.game.ui.cursor_coordinates = from;
+        press(app, KeyCode::Enter);
+        app.game.ui.cursor_coordinates = to;
+        press(app, KeyCode::Enter);
+    }
+
+    fn sorted(mut cells: Vec<Coord>) -> Vec<Coord> {
+        cells.sort_by_key(|cell| (cell.row, cell.col));
+        cells
+    }
+
+    #[test]
+    fn no_reach_before_the_first_move() {
+        let app = App::default();
+        assert!(app.game.last_moved_piece_reach().is_empty());
+    }
+
+    #[test]
+    fn reach_of_the_last_moved_piece_on_the_flipped_board() {
+        let mut app = App {
+            current_page: Pages::Solo,
+            ..Default::default()
+        };
+
+        // 1. Nf3, the board is then stored with black at the bottom
+        play(&mut app, Coord::new(7, 6), Coord::new(5, 5));
+        assert!(app.game.game_board.is_flipped);
+
+        // From f3 the knight reaches e5, g5, h4 and d4, g1 is taken again
+        assert_eq!(
+            sorted(app.game.last_moved_piece_reach()),
+            sorted(vec![
+                Coord::new(4, 3),
+                Coord::new(4, 1),
+                Coord::new(3, 0),
+                Coord::new(3, 4),
+                Coord::new(0, 1),
+            ])
+        );
+
+        // 1... e5, the pawn reaches e4 only
+        play(&mut app, Coord::new(6, 3), Coord::new(4, 3));
+        assert!(!app.game.game_board.is_flipped);
+        assert_eq!(app.game.last_moved_piece_reach(), vec![Coord::new(4, 4)]);
+    }
+
+    #[test]
+    fn reach_follows_the_position_shown_from_the_history() {
+        let mut app = App {
+            current_page: Pages::Solo,
+            ..Default::default()
+        };
+        play(&mut app, Coord::new(7, 6), Coord::new(5, 5));
+        play(&mut app, Coord::new(6, 3), Coord::new(4, 3));
+        app.game.game_state = GameState::Draw;
+        let live_reach = app.game.last_moved_piece_reach();
+
+        app.game.show_previous_position();
+        assert_eq!(app.game.last_moved_piece_reach().len(), 5);
+
+        // The starting position has no move yet
+        app.game.show_first_position();
+        assert!(app.game.last_moved_piece_reach().is_empty());
+
+        app.game.show_last_position();
+        assert_eq!(app.game.last_moved_piece_reach(), live_reach);
+    }
+
+    #[test]
+    fn reach_key_toggles_the_overlay() {
+        let mut app = App {
+            current_page: Pages::Solo,
+            ..Default::default()
+        };
+        press(&mut app, KeyCode::Char('a'));
+        assert!(app.game.ui.show_last_move_reach);
+        app.restart();
+        assert!(app.game.ui.show_last_move_reach);
+        press(&mut app, KeyCode::Char('a'));
+        assert!(!app.game.ui.show_last_move_reach);
+    }
+}
@@ -0,0 +1,172 @@
+#[cfg(test)]
+mod tests {
+    use chess_tui::game_logic::board::Board;
+    use chess_tui::game_logic::coord::Coord;
+    use chess_tui::game_logic::game::Game;
+    use chess_tui::game_logic::game_board::GameBoard;
+    use chess_tui::pieces::{PieceColor, PieceMove, PieceType};
+
+    fn game_with_board(board: Board) -> Game {
+        let game_board = GameBoard::new(board, vec![], vec![board]);
+        Game::new(game_board, PieceColor::White)
+    }
+
+    fn sorted_positions(game: &Game, coordinates: Coord) -> Vec<Coord> {
+        let mut positions = game
+            .game_board
+            .get_authorized_positions(PieceColor::White, coordinates);
+        positions.sort();
+        positions
+    }
+
+    #[test]
+    fn castling_through_check_is_illegal() {
+        let mut board = [[None; 8]; 8];
+        board[7][4] = Some((PieceType::King, PieceColor::White));
+        board[7][0] = Some((PieceType::Rook, PieceColor::White));
+        board[7][7] = Some((PieceType::Rook, PieceColor::White));
+        board[0][1] = Some((PieceType::King, PieceColor::Black));
+        // The black rook covers f1
+        board[0][5] = Some((PieceType::Rook, PieceColor::Black));
+        let game = game_with_board(board);
+
+        assert!(!game.is_move_legal(&Coord::new(7, 4), &Coord::new(7, 7), None));
+        assert!(game.is_move_legal(&Coord::new(7, 4), &Coord::new(7, 0), None));
+        assert!(!game.is_move_legal(&Coord::new(7, 4), &Coord::new(7, 5), None));
+    }
+
+    #[test]
+    fn pinned_piece_cannot_move() {
+        let mut board = [[None; 8]; 8];
+        board[7][4] = Some((PieceType::King, PieceColor::White));
+        board[6][4] = Some((PieceType::Bishop, PieceColor::White));
+        board[0][4] = Some((PieceType::Rook, PieceColor::Black));
+        board[0][0] = Some((PieceType::King, PieceColor::Black));
+        let game = game_with_board(board);
+
+        assert!(!game.is_move_legal(&Coord::new(6, 4), &Coord::new(5, 3), None));
+        assert!(game.is_move_legal(&Coord::new(7, 4), &Coord::new(7, 3), None));
+    }
+
+    #[test]
+    fn promotion_piece_is_required_on_last_row() {
+        let mut board = [[None; 8]; 8];
+        board[7][4] = Some((PieceType::King, PieceColor::White));
+        board[1][0] = Some((PieceType::Pawn, PieceColor::White));
+        board[0][7] = Some((PieceType::King, PieceColor::Black));
+        let game = game_with_board(board);
+
+        assert!(!game.is_move_legal(&Coord::new(1, 0), &Coord::new(0, 0), None));
+        assert!(game.is_move_legal(&Coord::new(1, 0), &Coord::new(0, 0), Some(PieceType::Queen)));
+        assert!(game.is_move_legal(
+            &Coord::new(1, 0),
+            &Coord::new(0, 0),
+            Some(PieceType::Knight)
+        ));
+        assert!(!game.is_move_legal(&Coord::new(1, 0), &Coord::new(0, 0), Some(PieceType::King)));
+        // No promotion outside of the last row
+        assert!(!game.is_move_legal(&Coord::new(7, 4), &Coord::new(6, 4), Some(PieceType::Queen)));
+    }
+
+    #[test]
+    fn black_promotion_on_the_white_side_board() {
+        let mut board = [[None; 8]; 8];
+        board[7][4] = Some((PieceType::King, PieceColor::White));
+        board[6][0] = Some((PieceType::Pawn, PieceColor::Black));
+        board[0][7] = Some((PieceType::King, PieceColor::Black));
+        // The board isn't flipped, the black pawns go down to the last row
+        let game_board = GameBoard::new(board, vec![], vec![board]);
+        let game = Game::new(game_board, PieceColor::Black);
+
+        assert!(!game.is_move_legal(&Coord::new(6, 0), &Coord::new(7, 0), None));
+        assert!(game.is_move_legal(&Coord::new(6, 0), &Coord::new(7, 0), Some(PieceType::Queen)));
+        assert!(!game.is_move_legal(&Coord::new(6, 0), &Coord::new(5, 0), None));
+    }
+
+    #[test]
+    fn only_the_current_player_can_move() {
+        let game = Game::default();
+
+        assert!(game.is_move_legal(&Coord::new(6, 4), &Coord::new(4, 4), None));
+        assert!(!game.is_move_legal(&Coord::new(1, 4), &Coord::new(3, 4), None));
+        assert!(!game.is_move_legal(&Coord::new(6, 4), &Coord::new(3, 4), None));
+        assert!(!game.is_move_legal(&Coord::undefined(), &Coord::new(4, 4), None));
+    }
+
+    #[test]
+    fn pinned_rook_only_moves_along_the_pin() {
+        let mut board = [[None; 8]; 8];
+        board[7][4] = Some((PieceType::King, PieceColor::White));
+        board[6][4] = Some((PieceType::Rook, PieceColor::White));
+        board[0][4] = Some((PieceType::Queen, PieceColor::Black));
+        board[0][0] = Some((PieceType::King, PieceColor::Black));
+        let game = game_with_board(board);
+
+        assert_eq!(
+            sorted_positions(&game, Coord::new(6, 4)),
+            vec![
+                Coord::new(0, 4),
+                Coord::new(1, 4),
+                Coord::new(2, 4),
+                Coord::new(3, 4),
+                Coord::new(4, 4),
+                Coord::new(5, 4),
+            ]
+        );
+    }
+
+    #[test]
+    fn en_passant_exposing_the_king_is_illegal() {
+        let mut board = [[None; 8]; 8];
+        board[3][0] = Some((PieceType::King, PieceColor::White));
+        board[3][2] = Some((PieceType::Pawn, PieceColor::White));
+        board[3][3] = Some((PieceType::Pawn, PieceColor::Black));
+        board[3][7] = Some((PieceType::Rook, PieceColor::Black));
+        board[0][7] = Some((PieceType::King, PieceColor::Black));
+        let mut game = game_with_board(board);
+        // d7-d5, stored with the black pieces at the bottom
+        game.game_board.move_history = vec![PieceMove {
+            piece_type: PieceType::Pawn,
+            piece_color: PieceColor::Black,
+            from: Coord::new(6, 4),
+            to: Coord::new(4, 4),
+        }];
+
+        // Taking on d6 would leave the king on the row of the rook
+        assert_eq!(
+            sorted_positions(&game, Coord::new(3, 2)),
+            vec![Coord::new(2, 2)]
+        );
+
+        // Without the rook the pawn can take en passant
+        game.game_board.board[3][7] = None;
+        assert_eq!(
+            sorted_positions(&game, Coord::new(3, 2)),
+            vec![Coord::new(2, 2), Coord::new(2, 3)]
+        );
+    }
+
+    #[test]
+    fn king_cannot_castle_through_or_out_of_check() {
+        let mut board = [[None; 8]; 8];
+        board[7][4] = Some((PieceType::King, PieceColor::White));
+        board[7][0] = Some((PieceType::Rook, PieceColor::White));
+        board[7][7] = Some((PieceType::Rook, PieceColor::White));
+        board[0][0] = Some((PieceType::King, PieceColor::Black));
+        // The black rook covers f1
+        board[0][5] = Some((PieceType::Rook, PieceColor::Black));
+        let mut game = game_with_board(board);
+
+        let positions = sorted_positions(&game, Coord::new(7, 4));
+        assert!(positions.contains(&Coord::new(7, 0)));
+        assert!(!positions.contains(&Coord::new(7, 7)));
+        assert!(!positions.contains(&Coord::new(7, 5)));
+
+        // The black rook checks the king, no castling at all
+        game.game_board.board[0][5] = None;
+        game.game_board.board[0][4] = Some((PieceType::Rook, PieceColor::Black));
+        let positions = sorted_positions(&game, Coord::new(7, 4));
+        assert!(!positions.contains(&Coord::new(7, 0)));
+        assert!(!positions.contains(&Coord::new(7, 7)));
+    }
+}
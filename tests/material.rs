@@ -0,0 +1,28 @@
+#[cfg(test)]
+mod tests {
+    use chess_tui::game_logic::game_board::GameBoard;
+    use chess_tui::pieces::{PieceColor, PieceType};
+
+    #[test]
+    fn material_advantage_of_the_captured_pieces() {
+        let mut game_board = GameBoard::default();
+        assert_eq!(game_board.material_advantage(), 0);
+
+        // White took a rook and a pawn, black took a knight and two pawns
+        game_board.push_to_taken_piece(PieceType::Rook, PieceColor::Black);
+        game_board.push_to_taken_piece(PieceType::Pawn, PieceColor::Black);
+        game_board.push_to_taken_piece(PieceType::Knight, PieceColor::White);
+        game_board.push_to_taken_piece(PieceType::Pawn, PieceColor::White);
+        game_board.push_to_taken_piece(PieceType::Pawn, PieceColor::White);
+        assert_eq!(game_board.material_advantage(), 1);
+
+        // Black takes the queen
+        game_board.push_to_taken_piece(PieceType::Queen, PieceColor::White);
+        assert_eq!(game_board.material_advantage(), -8);
+
+        // The bishop and the knight are worth the same
+        game_board.push_to_taken_piece(PieceType::Bishop, PieceColor::Black);
+        game_board.push_to_taken_piece(PieceType::Queen, PieceColor::Black);
+        assert_eq!(game_board.material_advantage(), 4);
+    }
+}
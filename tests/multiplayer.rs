@@ -0,0 +1,435 @@
+#[cfg(test)]
+mod tests {
+    use chess_tui::constants::NETWORK_PORT;
+    use chess_tui::game_logic::coord::Coord;
+    use chess_tui::game_logic::game::{Game, GameState};
+    use chess_tui::game_logic::opponent::{Connection, Opponent};
+    use chess_tui::pieces::{PieceColor, PieceType};
+    use chess_tui::server::discovery::{discover_hosts, DiscoveredHost, DISCOVERY_PORT};
+    use chess_tui::server::game_server::GameServer;
+    use std::io::{copy, Read, Write};
+    use std::net::{TcpListener, TcpStream, UdpSocket};
+    use std::sync::atomic::Ordering;
+    use std::thread;
+    use std::time::Duration;
+
+    // Minimal game server: the joining player receives the host color, then every message is relayed to the other player
+    fn connect_players(host_color: PieceColor) -> (Game, Game) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap().to_string();
+
+        let relay = thread::spawn(move || {
+            let (host_stream, _) = listener.accept().unwrap();
+            let (mut client_stream, _) = listener.accept().unwrap();
+            let color = match host_color {
+                PieceColor::White => "w",
+                PieceColor::Black => "b",
+            };
+            client_stream.write_all(color.as_bytes()).unwrap();
+
+            let (mut host_read, mut client_write) = (
+                host_stream.try_clone().unwrap(),
+                client_stream.try_clone().unwrap(),
+            );
+            let (mut client_read, mut host_write) = (client_stream, host_stream);
+            thread::spawn(move || copy(&mut host_read, &mut client_write));
+            thread::spawn(move || copy(&mut client_read, &mut host_write));
+        });
+
+        let host = Opponent::new(addr.clone(), Some(host_color.opposite()));
+        let client = Opponent::new(addr, None);
+        relay.join().unwrap();
+
+        let mut host_game = Game::default();
+        host_game.set_opponent(host);
+        let mut client_game = Game::default();
+        client_game.set_opponent(client);
+
+        (host_game, client_game)
+    }
+
+    fn play(game: &mut Game, from: Coord, to: Coord) {
+        game.ui.cursor_coordinates = from;
+        game.handle_cell_click();
+        game.ui.cursor_coordinates = to;
+        game.handle_cell_click();
+    }
+
+    fn receive(game: &mut Game) {
+        game.execute_opponent_move();
+        game.switch_player_turn();
+    }
+
+    fn check_orientation_and_moves(host_color: PieceColor) {
+        let (host_game, client_game) = connect_players(host_color);
+
+        assert_eq!(
+            client_game.opponent.as_ref().unwrap().color,
+            host_color,
+            "the client plays against the host color"
+        );
+
+        let (mut white_game, mut black_game) = match host_color {
+            PieceColor::White => (host_game, client_game),
+            PieceColor::Black => (client_game, host_game),
+        };
+
+        // Each player has his own pieces at the bottom
+        assert!(!white_game.game_board.is_flipped);
+        assert_eq!(
+            white_game.game_board.board[7][4],
+            Some((PieceType::King, PieceColor::White))
+        );
+        assert!(black_game.game_board.is_flipped);
+        assert_eq!(
+            black_game.game_board.board[7][3],
+            Some((PieceType::King, PieceColor::Black))
+        );
+
+        // 1. e4, the cells are the ones under the white player cursor
+        play(&mut white_game, Coord::new(6, 4), Coord::new(4, 4));
+        receive(&mut black_game);
+        assert_eq!(
+            black_game.game_board.board[3][3],
+            Some((PieceType::Pawn, PieceColor::White))
+        );
+        assert_eq!(black_game.game_board.board[1][3], None);
+        assert!(black_game.game_board.is_flipped);
+
+        // 1... e5, the cells are the ones under the black player cursor
+        play(&mut black_game, Coord::new(6, 3), Coord::new(4, 3));
+        receive(&mut white_game);
+        assert_eq!(
+            white_game.game_board.board[3][4],
+            Some((PieceType::Pawn, PieceColor::Black))
+        );
+        assert_eq!(white_game.game_board.board[1][4], None);
+        assert!(!white_game.game_board.is_flipped);
+
+        assert_eq!(white_game.game_board.board, {
+            let mut board = black_game.game_board.clone();
+            board.flip_the_board();
+            board.board
+        });
+    }
+
+    #[test]
+    fn host_white_client_black() {
+        check_orientation_and_moves(PieceColor::White);
+    }
+
+    #[test]
+    fn host_black_client_white() {
+        check_orientation_and_moves(PieceColor::Black);
+    }
+
+    #[test]
+    fn set_opponent_does_not_flip_twice() {
+        let (_, mut black_game) = connect_players(PieceColor::White);
+        let opponent = black_game.opponent.take().unwrap();
+
+        // Setting the opponent again keeps the black pieces at the bottom
+        black_game.set_opponent(opponent);
+        assert!(black_game.game_board.is_flipped);
+        assert_eq!(
+            black_game.game_board.board[7][3],
+            Some((PieceType::King, PieceColor::Black))
+        );
+    }
+
+    #[test]
+    fn declined_draw_offer_keeps_the_game_going() {
+        let (mut white_game, mut black_game) = connect_players(PieceColor::White);
+        white_game.opponent.as_mut().unwrap().game_started = true;
+        black_game.opponent.as_mut().unwrap().game_started = true;
+
+        white_game.offer_draw();
+        assert!(white_game.opponent.as_ref().unwrap().opponent_will_move);
+        assert!(!black_game.execute_opponent_move());
+        assert!(black_game.opponent.as_ref().unwrap().draw_offer_received);
+        assert_eq!(black_game.player_turn, PieceColor::White);
+
+        black_game.answer_draw_offer(false);
+        assert!(!white_game.execute_opponent_move());
+        assert_eq!(white_game.game_state, GameState::Playing);
+        assert!(!white_game.opponent.as_ref().unwrap().opponent_will_move);
+
+        // A single offer per turn
+        white_game.offer_draw();
+        assert!(!white_game.opponent.as_ref().unwrap().opponent_will_move);
+
+        // White still plays 1. e4
+        play(&mut white_game, Coord::new(6, 4), Coord::new(4, 4));
+        receive(&mut black_game);
+        assert_eq!(
+            black_game.game_board.board[3][3],
+            Some((PieceType::Pawn, PieceColor::White))
+        );
+        assert_eq!(black_game.player_turn, PieceColor::Black);
+    }
+
+    #[test]
+    fn accepted_draw_offer_ends_the_game() {
+        let (mut black_game, mut white_game) = connect_players(PieceColor::Black);
+        white_game.opponent.as_mut().unwrap().game_started = true;
+        black_game.opponent.as_mut().unwrap().game_started = true;
+
+        // Black can't offer a draw while white is to move
+        black_game.offer_draw();
+        assert!(black_game
+            .opponent
+            .as_ref()
+            .unwrap()
+            .draw_offered_at
+            .is_none());
+
+        white_game.offer_draw();
+        black_game.execute_opponent_move();
+        black_game.answer_draw_offer(true);
+        assert_eq!(black_game.game_state, GameState::Draw);
+
+        white_game.execute_opponent_move();
+        assert_eq!(white_game.game_state, GameState::Draw);
+    }
+
+    #[test]
+    fn premove_is_played_once_the_opponent_moved() {
+        let (mut white_game, mut black_game) = connect_players(PieceColor::White);
+        white_game.opponent.as_mut().unwrap().game_started = true;
+        black_game.opponent.as_mut().unwrap().game_started = true;
+
+        // 1... e5 is queued while white thinks, the board doesn't change
+        let board = black_game.game_board.board;
+        play(&mut black_game, Coord::new(6, 3), Coord::new(4, 3));
+        assert_eq!(
+            black_game.ui.premove,
+            Some((Coord::new(6, 3), Coord::new(4, 3)))
+        );
+        assert!(!black_game.ui.is_cell_selected());
+        assert_eq!(black_game.game_board.board, board);
+
+        // 1. e4 e5
+        play(&mut white_game, Coord::new(6, 4), Coord::new(4, 4));
+        receive(&mut black_game);
+        black_game.play_premove();
+        assert_eq!(black_game.ui.premove, None);
+        assert_eq!(
+            black_game.game_board.board[4][3],
+            Some((PieceType::Pawn, PieceColor::Black))
+        );
+        assert!(black_game.is_waiting_for_opponent());
+
+        receive(&mut white_game);
+        assert_eq!(
+            white_game.game_board.board[3][4],
+            Some((PieceType::Pawn, PieceColor::Black))
+        );
+        assert_eq!(white_game.player_turn, PieceColor::White);
+    }
+
+    #[test]
+    fn illegal_premove_is_dropped() {
+        let (mut white_game, mut black_game) = connect_players(PieceColor::White);
+        white_game.opponent.as_mut().unwrap().game_started = true;
+        black_game.opponent.as_mut().unwrap().game_started = true;
+
+        // The pawn can't go three cells forward
+        play(&mut black_game, Coord::new(6, 3), Coord::new(3, 3));
+        assert!(black_game.ui.premove.is_some());
+
+        play(&mut white_game, Coord::new(6, 4), Coord::new(4, 4));
+        receive(&mut black_game);
+        let board = black_game.game_board.board;
+        black_game.play_premove();
+        assert_eq!(black_game.ui.premove, None);
+        assert_eq!(black_game.game_board.board, board);
+        assert!(!black_game.is_waiting_for_opponent());
+        assert_eq!(black_game.player_turn, PieceColor::Black);
+    }
+
+    // The white player hosting a game, with the server side of his connection
+    fn connect_white_player(listener: &TcpListener) -> (Game, TcpStream) {
+        let addr = listener.local_addr().unwrap().to_string();
+        let opponent = Opponent::new(addr, Some(PieceColor::Black));
+        let (server_stream, _) = listener.accept().unwrap();
+        let mut game = Game::default();
+        game.set_opponent(opponent);
+        game.opponent.as_mut().unwrap().game_started = true;
+        (game, server_stream)
+    }
+
+    fn read_message(stream: &mut TcpStream) -> String {
+        let mut buffer = [0; 5];
+        let bytes_read = stream.read(&mut buffer).unwrap();
+        String::from_utf8_lossy(&buffer[..bytes_read]).to_string()
+    }
+
+    #[test]
+    fn lost_move_is_sent_again_to_a_player_connecting_again() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let (mut white_game, mut server_stream) = connect_white_player(&listener);
+
+        // 1. e4 never reaches black, who connects again without any move played
+        play(&mut white_game, Coord::new(6, 4), Coord::new(4, 4));
+        assert_eq!(read_message(&mut server_stream), "6444");
+        server_stream.write_all(b"r0").unwrap();
+
+        assert!(!white_game.execute_opponent_move());
+        assert_eq!(read_message(&mut server_stream), "6444");
+        assert!(white_game.opponent.as_ref().unwrap().opponent_will_move);
+        assert_eq!(white_game.game_board.move_history.len(), 1);
+    }
+
+    #[test]
+    fn dropped_connection_connects_again() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let (mut white_game, server_stream) = connect_white_player(&listener);
+
+        drop(server_stream);
+        white_game.update_opponent_connection();
+        assert!(matches!(
+            white_game.opponent.as_ref().unwrap().connection,
+            Connection::Reconnecting { attempts: 0, .. }
+        ));
+
+        white_game.update_opponent_connection();
+        assert_eq!(
+            white_game.opponent.as_ref().unwrap().connection,
+            Connection::Connected
+        );
+        let (mut server_stream, _) = listener.accept().unwrap();
+        assert_eq!(read_message(&mut server_stream), "r0");
+    }
+
+    #[test]
+    fn join_with_a_bracketed_ipv6_address() {
+        // Nothing to check on a machine without IPv6
+        let Ok(listener) = TcpListener::bind("[::1]:0") else {
+            return;
+        };
+        let port = listener.local_addr().unwrap().port();
+
+        let opponent = Opponent::new(format!("[::1]:{port}"), Some(PieceColor::Black));
+        let (_, peer_addr) = listener.accept().unwrap();
+        assert!(peer_addr.is_ipv6());
+        assert_eq!(opponent.color, PieceColor::Black);
+    }
+
+    #[test]
+    fn beacon_describes_the_hosted_game() {
+        let ip = "192.168.1.12".parse().unwrap();
+        let beacon = DiscoveredHost::beacon("3fa2", PieceColor::Black, 4000);
+        assert_eq!(beacon, "chess-tui 3fa2 b 4000");
+
+        let host = DiscoveredHost::from_beacon(&beacon, ip).unwrap();
+        assert_eq!(host.addr.to_string(), "192.168.1.12:4000");
+        assert_eq!(host.game_id, "3fa2");
+        assert_eq!(host.host_color, PieceColor::Black);
+
+        assert_eq!(DiscoveredHost::from_beacon("hello 3fa2 b 4000", ip), None);
+        assert_eq!(
+            DiscoveredHost::from_beacon("chess-tui 3fa2 x 4000", ip),
+            None
+        );
+        assert_eq!(DiscoveredHost::from_beacon("chess-tui 3fa2 w", ip), None);
+    }
+
+    #[test]
+    fn discovery_lists_each_announced_game_once() {
+        let announcer = thread::spawn(|| {
+            let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+            for _ in 0..5 {
+                thread::sleep(Duration::from_millis(50));
+                let beacon = DiscoveredHost::beacon("3fa2", PieceColor::White, 2308);
+                socket
+                    .send_to(beacon.as_bytes(), ("127.0.0.1", DISCOVERY_PORT))
+                    .unwrap();
+            }
+        });
+
+        let hosts = discover_hosts(Duration::from_millis(600));
+        announcer.join().unwrap();
+        assert_eq!(hosts.len(), 1);
+        assert_eq!(hosts[0].addr.to_string(), "127.0.0.1:2308");
+        assert_eq!(hosts[0].host_color, PieceColor::White);
+    }
+
+    #[test]
+    fn headless_server_gives_both_players_a_color() {
+        let game_server = GameServer::new_headless(NETWORK_PORT);
+        let stop_signal = game_server.stop_signal.clone();
+        let server = thread::spawn(move || game_server.run());
+        thread::sleep(Duration::from_millis(100));
+
+        // The first player waits for his color until the second one joins
+        let first = thread::spawn(|| Opponent::new("127.0.0.1:2308".to_string(), None));
+        thread::sleep(Duration::from_millis(200));
+        let second = Opponent::new("127.0.0.1:2308".to_string(), None);
+        let first = first.join().unwrap();
+
+        // The colors are the ones of the opponents, the first player plays white
+        assert_eq!(first.color, PieceColor::Black);
+        assert_eq!(second.color, PieceColor::White);
+
+        stop_signal.store(true, Ordering::SeqCst);
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn only_the_dropped_player_takes_his_seat_again() {
+        let port = TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+        let game_server = GameServer::new(true, port);
+        let stop_signal = game_server.stop_signal.clone();
+        let server = thread::spawn(move || game_server.run());
+        thread::sleep(Duration::from_millis(100));
+
+        let mut host = TcpStream::connect(("127.0.0.1", port)).unwrap();
+        thread::sleep(Duration::from_millis(100));
+        let mut joining = TcpStream::connect(("127.0.0.1", port)).unwrap();
+        assert_eq!(read_message(&mut joining), "w");
+        assert_eq!(read_message(&mut host), "s");
+
+        drop(joining);
+        thread::sleep(Duration::from_millis(300));
+
+        // Another machine can't take the seat, the IPv6 loopback is another address than the IPv4 one
+        if let Ok(mut other) = TcpStream::connect(("::1", port)) {
+            assert_eq!(read_message(&mut other), "Game ");
+        }
+
+        let mut joining = TcpStream::connect(("127.0.0.1", port)).unwrap();
+        thread::sleep(Duration::from_millis(100));
+        host.write_all(b"6444").unwrap();
+        assert_eq!(read_message(&mut joining), "6444");
+
+        stop_signal.store(true, Ordering::SeqCst);
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn quitting_ends_the_game_for_the_other_player() {
+        let port = TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+        let game_server = GameServer::new(true, port);
+        let stop_signal = game_server.stop_signal.clone();
+        let server = thread::spawn(move || game_server.run());
+        thread::sleep(Duration::from_millis(100));
+
+        let mut host = TcpStream::connect(("127.0.0.1", port)).unwrap();
+        thread::sleep(Duration::from_millis(100));
+        let mut opponent = Opponent::new(format!("127.0.0.1:{port}"), None);
+        assert_eq!(read_message(&mut host), "s");
+
+        opponent.send_end_game_to_server();
+        assert_eq!(read_message(&mut host), "ended");
+        server.join().unwrap();
+        assert!(stop_signal.load(Ordering::SeqCst));
+    }
+}
@@ -0,0 +1,73 @@
+#[cfg(test)]
+mod tests {
+    use chess_tui::app::App;
+    use chess_tui::game_logic::openings::find_opening;
+
+    fn opening_of(moves: &str) -> Option<(&'static str, &'static str)> {
+        let san_history: Vec<String> = moves.split_whitespace().map(String::from).collect();
+        find_opening(&san_history).map(|opening| (opening.eco, opening.name))
+    }
+
+    #[test]
+    fn deepest_known_line() {
+        assert_eq!(opening_of(""), None);
+        assert_eq!(opening_of("e4"), Some(("B00", "King's Pawn Game")));
+        assert_eq!(opening_of("e4 e5 Nf3 Nc6 Bb5"), Some(("C60", "Ruy Lopez")));
+        // Between two named lines the previous name stays
+        assert_eq!(
+            opening_of("e4 e5 Nf3 Nc6 Bb5 a6 Ba4"),
+            Some(("C60", "Ruy Lopez: Morphy Defense"))
+        );
+        assert_eq!(
+            opening_of("e4 e5 Nf3 Nc6 Bb5 a6 Ba4 Nf6 O-O Be7"),
+            Some(("C84", "Ruy Lopez: Closed"))
+        );
+        // The check signs are ignored
+        assert_eq!(
+            opening_of("e4 e5 Nf3 Nc6 Bc4 Nf6 Ng5 d5 exd5 Nxd5 Nxf7"),
+            opening_of("e4 e5 Nf3 Nc6 Bc4 Nf6 Ng5 d5 exd5 Nxd5 Nxf7+")
+        );
+    }
+
+    #[test]
+    fn out_of_the_known_lines() {
+        assert_eq!(opening_of("e4 e5 Nf3 Nc6 Bb5 h6"), None);
+        assert_eq!(opening_of("h4"), None);
+        assert_eq!(opening_of("e4 e5 Nf3 Nc6 Bb5 a6 Ba4 Nf6 O-O Be7 Re1"), None);
+    }
+
+    #[test]
+    fn opening_of_the_game_played() {
+        let mut app = App::default();
+        assert_eq!(app.game.game_board.opening(), None);
+        for (saved_move, eco) in [
+            ("e2e4", Some("B00")),
+            ("c7c5", Some("B20")),
+            ("g1f3", Some("B20")),
+            ("e7e6", Some("B40")),
+            ("h2h3", None),
+        ] {
+            assert!(app.game.play_saved_move(saved_move));
+            assert_eq!(
+                app.game.game_board.opening().map(|opening| opening.eco),
+                eco,
+                "{saved_move}"
+            );
+        }
+    }
+
+    #[test]
+    fn no_opening_from_another_position() {
+        let mut app = App::default();
+        app.start_chess960_game(0);
+        assert!(app.game.play_saved_move("e2e4"));
+        assert_eq!(app.game.game_board.opening(), None);
+
+        app.start_chess960_game(518);
+        assert!(app.game.play_saved_move("e2e4"));
+        assert_eq!(
+            app.game.game_board.opening().map(|opening| opening.name),
+            Some("King's Pawn Game")
+        );
+    }
+}
@@ -0,0 +1,218 @@
+#[cfg(test)]
+mod tests {
+    use chess_tui::app::App;
+    use chess_tui::constants::Pages;
+    use chess_tui::game_logic::coord::Coord;
+    use chess_tui::handler::{handle_key_events, handle_mouse_events};
+    use chess_tui::pieces::{PieceColor, PieceType};
+    use ratatui::crossterm::event::{
+        KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+    };
+
+    // Black to play with the black pieces stored at the bottom, as against a bot that plays first
+    fn black_game_from_white_perspective() -> App {
+        let mut app = App {
+            current_page: Pages::Bot,
+            selected_color: Some(PieceColor::Black),
+            ..Default::default()
+        };
+        app.game.game_board.flip_the_board();
+        app.game.player_turn = PieceColor::Black;
+        app.game.ui.white_perspective = true;
+
+        // The drawn board is a 10x5 cells grid starting at the top left corner
+        app.game.ui.top_x = 0;
+        app.game.ui.top_y = 0;
+        app.game.ui.width = 10;
+        app.game.ui.height = 5;
+        app
+    }
+
+    fn press(app: &mut App, code: KeyCode) {
+        handle_key_events(KeyEvent::new(code, KeyModifiers::NONE), app).unwrap();
+    }
+
+    // Click on a drawn square, (0, 0) being the top left one
+    fn click(app: &mut App, row: u16, col: u16) {
+        let mouse_event = MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column: col * 10 + 1,
+            row: row * 5 + 1,
+            modifiers: KeyModifiers::NONE,
+        };
+        handle_mouse_events(mouse_event, app).unwrap();
+    }
+
+    #[test]
+    fn view_is_rotated_only_when_black_is_stored_at_the_bottom() {
+        let mut app = black_game_from_white_perspective();
+        assert!(app.game.is_view_rotated());
+
+        app.game.game_board.flip_the_board();
+        assert!(!app.game.is_view_rotated());
+
+        app.game.ui.white_perspective = false;
+        app.game.game_board.flip_the_board();
+        assert!(!app.game.is_view_rotated());
+    }
+
+    #[test]
+    fn arrow_keys_follow_the_drawn_board() {
+        let mut app = black_game_from_white_perspective();
+        app.game.ui.cursor_coordinates = Coord::new(4, 4);
+
+        // Going up on the drawn board is going down on the stored one
+        press(&mut app, KeyCode::Up);
+        assert_eq!(app.game.ui.cursor_coordinates, Coord::new(5, 4));
+        press(&mut app, KeyCode::Down);
+        press(&mut app, KeyCode::Down);
+        assert_eq!(app.game.ui.cursor_coordinates, Coord::new(3, 4));
+        press(&mut app, KeyCode::Left);
+        assert_eq!(app.game.ui.cursor_coordinates, Coord::new(3, 5));
+        press(&mut app, KeyCode::Right);
+        press(&mut app, KeyCode::Right);
+        assert_eq!(app.game.ui.cursor_coordinates, Coord::new(3, 3));
+    }
+
+    #[test]
+    fn mouse_clicks_follow_the_drawn_board() {
+        let mut app = black_game_from_white_perspective();
+
+        // e7 is drawn on the second row, e5 on the fourth one
+        click(&mut app, 1, 4);
+        assert_eq!(app.game.ui.selected_coordinates, Coord::new(6, 3));
+        assert_eq!(
+            app.game.game_board.board[6][3],
+            Some((PieceType::Pawn, PieceColor::Black))
+        );
+
+        click(&mut app, 3, 4);
+        let last_move = app.game.game_board.move_history.last().unwrap();
+        assert_eq!(last_move.from, Coord::new(6, 3));
+        assert_eq!(last_move.to, Coord::new(4, 3));
+        assert_eq!(app.game.game_board.san_history, vec!["e5"]);
+    }
+
+    #[test]
+    fn solo_game_without_auto_flip_keeps_white_at_the_bottom() {
+        let mut app = App {
+            solo_auto_flip: false,
+            ..Default::default()
+        };
+        app.menu_select();
+        assert_eq!(app.current_page, Pages::Solo);
+
+        // 1. e4, the drawn board doesn't turn
+        app.game.ui.cursor_coordinates = Coord::new(6, 4);
+        press(&mut app, KeyCode::Enter);
+        app.game.ui.cursor_coordinates = Coord::new(4, 4);
+        press(&mut app, KeyCode::Enter);
+        assert_eq!(app.game.player_turn, PieceColor::Black);
+        assert!(app.game.is_view_rotated());
+
+        // Black still can't move a white piece
+        app.game.ui.cursor_coordinates = Coord::new(1, 4);
+        press(&mut app, KeyCode::Enter);
+        assert!(!app.game.ui.is_cell_selected());
+        app.game.ui.cursor_coordinates = Coord::new(6, 3);
+        press(&mut app, KeyCode::Enter);
+        assert_eq!(app.game.ui.selected_coordinates, Coord::new(6, 3));
+    }
+
+    #[test]
+    fn cursor_stays_on_its_square_after_the_solo_flip() {
+        let mut app = App::default();
+        app.menu_select();
+
+        // 1. e4, the cursor goes back to e2 which is stored on the other side once black is at the bottom
+        app.game.ui.cursor_coordinates = Coord::new(6, 4);
+        press(&mut app, KeyCode::Enter);
+        app.game.ui.cursor_coordinates = Coord::new(4, 4);
+        press(&mut app, KeyCode::Enter);
+        assert!(app.game.game_board.is_flipped);
+        assert_eq!(app.game.ui.cursor_coordinates, Coord::new(1, 3));
+        assert_eq!(
+            app.game.game_board.board[3][3],
+            Some((PieceType::Pawn, PieceColor::White))
+        );
+
+        // Without the auto flip, the cursor doesn't move on the drawn board either
+        let mut app = App {
+            solo_auto_flip: false,
+            ..Default::default()
+        };
+        app.menu_select();
+        app.game.ui.cursor_coordinates = Coord::new(6, 4);
+        press(&mut app, KeyCode::Enter);
+        app.game.ui.cursor_coordinates = Coord::new(4, 4);
+        press(&mut app, KeyCode::Enter);
+        assert!(app.game.is_view_rotated());
+        assert_eq!(app.game.ui.cursor_coordinates, Coord::new(1, 3));
+    }
+
+    #[test]
+    fn flip_keeps_the_selection_and_an_undefined_cursor() {
+        let mut app = App {
+            current_page: Pages::Solo,
+            ..Default::default()
+        };
+        app.game.ui.cursor_coordinates = Coord::new(6, 4);
+        press(&mut app, KeyCode::Enter);
+        app.game.ui.cursor_coordinates = Coord::new(5, 4);
+
+        app.game.flip_the_board();
+        assert_eq!(app.game.ui.selected_coordinates, Coord::new(1, 3));
+        assert_eq!(app.game.ui.cursor_coordinates, Coord::new(2, 3));
+
+        // Unselecting goes back to the selected piece
+        app.game.ui.unselect_cell();
+        assert_eq!(app.game.ui.cursor_coordinates, Coord::new(1, 3));
+
+        app.game.ui.cursor_coordinates = Coord::undefined();
+        app.game.flip_the_board();
+        assert_eq!(app.game.ui.cursor_coordinates, Coord::undefined());
+    }
+
+    #[test]
+    fn flip_key_turns_only_the_drawn_board() {
+        let mut app = App {
+            current_page: Pages::Solo,
+            ..Default::default()
+        };
+        app.game.ui.top_x = 0;
+        app.game.ui.top_y = 0;
+        app.game.ui.width = 10;
+        app.game.ui.height = 5;
+        app.game.ui.cursor_coordinates = Coord::new(4, 4);
+
+        press(&mut app, KeyCode::Char('F'));
+        assert!(app.game.is_view_rotated());
+        assert!(!app.game.game_board.is_flipped);
+        assert_eq!(app.game.player_turn, PieceColor::White);
+
+        press(&mut app, KeyCode::Up);
+        assert_eq!(app.game.ui.cursor_coordinates, Coord::new(5, 4));
+
+        // e2 is drawn on the second row, fifth column from the right
+        click(&mut app, 1, 3);
+        assert_eq!(app.game.ui.selected_coordinates, Coord::new(6, 4));
+        click(&mut app, 3, 3);
+        assert_eq!(app.game.game_board.san_history, vec!["e4"]);
+
+        // Flipping back draws the board as before
+        press(&mut app, KeyCode::Char('F'));
+        assert_eq!(
+            app.game.is_view_rotated(),
+            app.game.ui.white_perspective && app.game.game_board.is_flipped
+        );
+    }
+
+    #[test]
+    fn flip_key_turns_the_rotated_view_back() {
+        let mut app = black_game_from_white_perspective();
+        press(&mut app, KeyCode::Char('F'));
+        assert!(!app.game.is_view_rotated());
+        assert!(app.game.game_board.is_flipped);
+        assert_eq!(app.game.player_turn, PieceColor::Black);
+    }
+}
@@ -110,6 +110,7 @@ mod tests {
         ];
         let mut game = Game::default();
         game.game_board.board = custom_board;
+        game.game_board.is_flipped = true;
         let mut right_positions = vec![
             Coord::new(5, 3),
             Coord::new(4, 3),
@@ -160,6 +161,7 @@ mod tests {
         ];
         let mut game = Game::default();
         game.game_board.board = custom_board;
+        game.game_board.is_flipped = true;
         let mut right_positions = vec![Coord::new(5, 4), Coord::new(5, 2)];
         right_positions.sort();
 
@@ -337,6 +339,7 @@ mod tests {
         ];
         let mut game = Game::default();
         game.game_board.board = custom_board;
+        game.game_board.is_flipped = true;
         game.game_board.move_history = [(PieceMove {
             piece_type: PieceType::Pawn,
             piece_color: PieceColor::White,
@@ -414,6 +417,7 @@ mod tests {
         ];
         let mut game = Game::default();
         game.game_board.board = custom_board;
+        game.game_board.is_flipped = true;
         game.game_board.move_history = [(PieceMove {
             piece_type: PieceType::Pawn,
             piece_color: PieceColor::White,
@@ -468,6 +472,7 @@ mod tests {
         let game_board = GameBoard::new(custom_board, vec![], vec![]);
         let mut game = Game::new(game_board, PieceColor::Black);
         game.game_board.board = custom_board;
+        game.game_board.is_flipped = true;
 
         let is_king_checked = game
             .game_board
@@ -1,9 +1,11 @@
 #[cfg(test)]
 mod tests {
     use chess_tui::game_logic::coord::Coord;
-    use chess_tui::game_logic::game::Game;
+    use chess_tui::game_logic::game::{Game, GameState};
     use chess_tui::game_logic::game_board::GameBoard;
+    use chess_tui::game_logic::opponent::Opponent;
     use chess_tui::pieces::{PieceColor, PieceMove, PieceType};
+    use std::net::{TcpListener, TcpStream};
     #[test]
     fn is_promote_true() {
         let custom_board = [
@@ -180,6 +182,7 @@ mod tests {
         // The black king gets checkmated
         game.player_turn = PieceColor::Black;
         assert!(game.game_board.is_checkmate(game.player_turn));
+        assert_eq!(game.game_board.san_history, vec!["e8=Q#"]);
     }
 
     #[test]
@@ -294,4 +297,109 @@ mod tests {
         game.player_turn = PieceColor::White;
         assert!(game.game_board.is_draw(game.player_turn));
     }
+
+    #[test]
+    fn multiplayer_promotion_is_applied_on_both_boards() {
+        let custom_board = [
+            [
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some((PieceType::King, PieceColor::Black)),
+            ],
+            [
+                None,
+                None,
+                None,
+                None,
+                Some((PieceType::Pawn, PieceColor::White)),
+                None,
+                None,
+                None,
+            ],
+            [None, None, None, None, None, None, None, None],
+            [None, None, None, None, None, None, None, None],
+            [None, None, None, None, None, None, None, None],
+            [None, None, None, None, None, None, None, None],
+            [None, None, None, None, None, None, None, None],
+            [
+                None,
+                Some((PieceType::King, PieceColor::White)),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            ],
+        ];
+
+        // Both clients are linked with a local socket instead of going through the game server
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let white_stream = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+        let (black_stream, _) = listener.accept().unwrap();
+
+        // White player, the board is seen from the white side
+        let mut white_game = Game::new(
+            GameBoard::new(custom_board, vec![], vec![]),
+            PieceColor::White,
+        );
+        white_game.opponent = Some(Opponent {
+            stream: Some(white_stream),
+            opponent_will_move: false,
+            color: PieceColor::Black,
+            game_started: true,
+            ..Default::default()
+        });
+
+        // Black player, the board is seen from the black side
+        let mut black_board = GameBoard::new(custom_board, vec![], vec![]);
+        black_board.flip_the_board();
+        let mut black_game = Game::new(black_board, PieceColor::White);
+        black_game.opponent = Some(Opponent {
+            stream: Some(black_stream),
+            opponent_will_move: true,
+            color: PieceColor::White,
+            game_started: true,
+            ..Default::default()
+        });
+
+        // White moves the pawn to the last row and picks a queen
+        white_game.ui.cursor_coordinates = Coord::new(1, 4);
+        white_game.handle_cell_click();
+        white_game.handle_cell_click();
+        assert_eq!(white_game.game_state, GameState::Promotion);
+        white_game.handle_cell_click();
+        assert_eq!(white_game.game_state, GameState::Playing);
+
+        // Black receives the move with the promotion piece
+        black_game.execute_opponent_move();
+        black_game.switch_player_turn();
+
+        assert_eq!(
+            white_game.game_board.board[0][4],
+            Some((PieceType::Queen, PieceColor::White))
+        );
+        assert_eq!(
+            black_game.game_board.board[7][3],
+            Some((PieceType::Queen, PieceColor::White))
+        );
+        assert_eq!(
+            black_game
+                .game_board
+                .move_history
+                .last()
+                .unwrap()
+                .piece_type,
+            PieceType::Queen
+        );
+        assert_eq!(
+            black_game.game_board.board_history.last(),
+            white_game.game_board.board_history.last()
+        );
+    }
 }
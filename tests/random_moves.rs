@@ -0,0 +1,71 @@
+#[cfg(test)]
+mod tests {
+    use chess_tui::app::App;
+    use chess_tui::constants::Pages;
+    use chess_tui::game_logic::game::{Game, GameState};
+    use chess_tui::game_logic::game_board::GameBoard;
+    use chess_tui::handler::handle_key_events;
+    use chess_tui::pieces::{PieceColor, PieceType};
+    use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    #[test]
+    fn random_moves_are_played_in_turn() {
+        let mut game = Game::default();
+
+        let played = game.play_random_moves(10);
+
+        if game.game_state == GameState::Playing {
+            assert_eq!(played, 10);
+        }
+        assert_eq!(game.game_board.move_history.len(), played);
+        assert_eq!(game.game_board.san_history.len(), played);
+        for (index, piece_move) in game.game_board.move_history.iter().enumerate() {
+            let color = if index % 2 == 0 {
+                PieceColor::White
+            } else {
+                PieceColor::Black
+            };
+            assert_eq!(piece_move.piece_color, color);
+        }
+        // The player to move has his pieces at the bottom, as after a normal solo move
+        assert_eq!(
+            game.game_board.is_flipped,
+            game.player_turn == PieceColor::Black
+        );
+    }
+
+    #[test]
+    fn random_moves_stop_when_the_game_is_over() {
+        let mut board = [[None; 8]; 8];
+        // Back rank mate against white
+        board[7][7] = Some((PieceType::King, PieceColor::White));
+        board[6][6] = Some((PieceType::Pawn, PieceColor::White));
+        board[6][7] = Some((PieceType::Pawn, PieceColor::White));
+        board[7][0] = Some((PieceType::Rook, PieceColor::Black));
+        board[0][0] = Some((PieceType::King, PieceColor::Black));
+        let game_board = GameBoard::new(board, vec![], vec![board]);
+        let mut game = Game::new(game_board, PieceColor::White);
+
+        assert_eq!(game.play_random_moves(5), 0);
+        assert_eq!(game.game_state, GameState::Checkmate);
+        assert!(game.game_board.move_history.is_empty());
+    }
+
+    #[test]
+    fn demo_key_plays_the_configured_number_of_moves() {
+        let mut app = App {
+            current_page: Pages::Solo,
+            demo_random_moves: 3,
+            ..App::default()
+        };
+
+        handle_key_events(
+            KeyEvent::new(KeyCode::Char('D'), KeyModifiers::NONE),
+            &mut app,
+        )
+        .unwrap();
+
+        // No game can end in 3 moves
+        assert_eq!(app.game.game_board.move_history.len(), 3);
+    }
+}
@@ -0,0 +1,56 @@
+#[cfg(test)]
+mod tests {
+    use chess_tui::app::App;
+    use chess_tui::constants::Pages;
+    use chess_tui::game_logic::coord::Coord;
+    use chess_tui::game_logic::game::GameState;
+    use chess_tui::handler::handle_key_events;
+    use chess_tui::pieces::PieceColor;
+    use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    fn press(app: &mut App, code: KeyCode) {
+        handle_key_events(KeyEvent::new(code, KeyModifiers::NONE), app).unwrap();
+    }
+
+    fn play(app: &mut App, from: Coord, to: Coord) {
+        app.game.ui.cursor_coordinates = from;
+        press(app, KeyCode::Enter);
+        app.game.ui.cursor_coordinates = to;
+        press(app, KeyCode::Enter);
+    }
+
+    #[test]
+    fn player_to_move_resigns_a_solo_game() {
+        let mut app = App::default();
+        app.menu_select();
+
+        play(&mut app, Coord::new(6, 4), Coord::new(4, 4));
+        press(&mut app, KeyCode::Char('f'));
+        assert_eq!(app.game.game_state, GameState::Resigned(PieceColor::Black));
+
+        // The game is over but the board and the history stay as they were
+        assert_eq!(app.game.game_board.san_history, vec!["e4"]);
+        app.game.ui.cursor_coordinates = Coord::new(6, 3);
+        press(&mut app, KeyCode::Enter);
+        assert!(!app.game.ui.is_cell_selected());
+
+        // Neither a takeback nor a second resignation changes the result
+        press(&mut app, KeyCode::Char('z'));
+        press(&mut app, KeyCode::Char('f'));
+        assert_eq!(app.game.game_state, GameState::Resigned(PieceColor::Black));
+        assert_eq!(app.game.game_board.san_history, vec!["e4"]);
+
+        press(&mut app, KeyCode::Char('r'));
+        assert_eq!(app.game.game_state, GameState::Playing);
+    }
+
+    #[test]
+    fn multiplayer_games_can_not_be_resigned() {
+        let mut app = App {
+            current_page: Pages::Multiplayer,
+            ..Default::default()
+        };
+        press(&mut app, KeyCode::Char('f'));
+        assert_eq!(app.game.game_state, GameState::Playing);
+    }
+}
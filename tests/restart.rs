@@ -0,0 +1,41 @@
+#[cfg(test)]
+mod tests {
+    use chess_tui::app::App;
+    use chess_tui::constants::{DisplayMode, HistoryNotation, Pages};
+    use chess_tui::game_logic::coord::Coord;
+
+    #[test]
+    fn restart_keeps_the_settings_of_the_game() {
+        let mut app = App {
+            current_page: Pages::Solo,
+            ..Default::default()
+        };
+        app.game.ui.display_mode = DisplayMode::ASCII;
+        app.game.ui.history_notation = HistoryNotation::San;
+        app.game.ui.show_captured = false;
+        app.game.ui.move_animation = true;
+        app.game.game_board.auto_claim_draws = false;
+        app.game.game_board.auto_claim_repetition = true;
+        // The state of the game itself
+        app.game.ui.cursor_coordinates = Coord::new(1, 1);
+        app.game.ui.selected_coordinates = Coord::new(6, 4);
+        app.game.ui.view_flipped = true;
+        app.game.ui.status_message = Some("Saved the board".to_string());
+        app.game.execute_move(&Coord::new(6, 4), &Coord::new(4, 4));
+
+        app.restart();
+
+        assert_eq!(app.game.ui.display_mode.to_string(), "ASCII");
+        assert_eq!(app.game.ui.history_notation, HistoryNotation::San);
+        assert!(!app.game.ui.show_captured);
+        assert!(app.game.ui.move_animation);
+        assert!(!app.game.game_board.auto_claim_draws);
+        assert!(app.game.game_board.auto_claim_repetition);
+
+        assert!(app.game.game_board.move_history.is_empty());
+        assert_eq!(app.game.ui.cursor_coordinates, Coord::new(4, 4));
+        assert!(!app.game.ui.is_cell_selected());
+        assert!(!app.game.ui.view_flipped);
+        assert_eq!(app.game.ui.status_message, None);
+    }
+}
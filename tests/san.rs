@@ -0,0 +1,149 @@
+#[cfg(test)]
+mod tests {
+    use chess_tui::game_logic::board::init_board;
+    use chess_tui::game_logic::coord::Coord;
+    use chess_tui::game_logic::game::Game;
+    use chess_tui::game_logic::game_board::GameBoard;
+    use chess_tui::pieces::{PieceColor, PieceType};
+
+    #[test]
+    fn san_of_opening_moves() {
+        let game_board = GameBoard::new(init_board(), vec![], vec![init_board()]);
+        let game = Game::new(game_board, PieceColor::White);
+
+        assert_eq!(
+            game.game_board
+                .get_san(Coord::new(7, 6), Coord::new(5, 5), PieceColor::White, None),
+            "Nf3"
+        );
+        assert_eq!(
+            game.game_board
+                .get_san(Coord::new(6, 4), Coord::new(4, 4), PieceColor::White, None),
+            "e4"
+        );
+    }
+
+    #[test]
+    fn san_of_pawn_capture_on_flipped_board() {
+        let mut game = Game::default();
+
+        // 1. e4 d5
+        game.ui.cursor_coordinates = Coord::new(6, 4);
+        game.handle_cell_click();
+        game.ui.cursor_coordinates = Coord::new(4, 4);
+        game.handle_cell_click();
+        game.ui.cursor_coordinates = Coord::new(6, 4);
+        game.handle_cell_click();
+        game.ui.cursor_coordinates = Coord::new(4, 4);
+        game.handle_cell_click();
+
+        assert_eq!(game.game_board.san_history, vec!["e4", "d5"]);
+
+        // The board is now seen from white again, e4 can take d5
+        assert_eq!(
+            game.game_board
+                .get_san(Coord::new(4, 4), Coord::new(3, 3), PieceColor::White, None),
+            "exd5"
+        );
+
+        // Seen from black, d5 can take e4
+        game.game_board.flip_the_board();
+        assert_eq!(
+            game.game_board
+                .get_san(Coord::new(4, 4), Coord::new(3, 3), PieceColor::Black, None),
+            "dxe4"
+        );
+    }
+
+    #[test]
+    fn san_of_castling_and_checks() {
+        let custom_board = [
+            [
+                None,
+                None,
+                None,
+                None,
+                Some((PieceType::King, PieceColor::Black)),
+                None,
+                None,
+                None,
+            ],
+            [None, None, None, None, None, None, None, None],
+            [None, None, None, None, None, None, None, None],
+            [None, None, None, None, None, None, None, None],
+            [None, None, None, None, None, None, None, None],
+            [None, None, None, None, None, None, None, None],
+            [None, None, None, None, None, None, None, None],
+            [
+                Some((PieceType::Rook, PieceColor::White)),
+                None,
+                None,
+                None,
+                Some((PieceType::King, PieceColor::White)),
+                None,
+                None,
+                Some((PieceType::Rook, PieceColor::White)),
+            ],
+        ];
+        let game_board = GameBoard::new(custom_board, vec![], vec![custom_board]);
+        let game = Game::new(game_board, PieceColor::White);
+
+        assert_eq!(
+            game.game_board
+                .get_san(Coord::new(7, 4), Coord::new(7, 7), PieceColor::White, None),
+            "O-O"
+        );
+        assert_eq!(
+            game.game_board
+                .get_san(Coord::new(7, 4), Coord::new(7, 0), PieceColor::White, None),
+            "O-O-O"
+        );
+        assert_eq!(
+            game.game_board
+                .get_san(Coord::new(7, 0), Coord::new(0, 0), PieceColor::White, None),
+            "Ra8+"
+        );
+    }
+
+    #[test]
+    fn san_of_ambiguous_move() {
+        let mut board = [[None; 8]; 8];
+        board[0][4] = Some((PieceType::King, PieceColor::Black));
+        board[7][4] = Some((PieceType::King, PieceColor::White));
+        board[7][1] = Some((PieceType::Knight, PieceColor::White));
+        board[5][5] = Some((PieceType::Knight, PieceColor::White));
+        let game_board = GameBoard::new(board, vec![], vec![board]);
+        let game = Game::new(game_board, PieceColor::White);
+
+        // Both knights can go to d2
+        assert_eq!(
+            game.game_board
+                .get_san(Coord::new(7, 1), Coord::new(6, 3), PieceColor::White, None),
+            "Nbd2"
+        );
+        assert_eq!(
+            game.game_board
+                .get_san(Coord::new(5, 5), Coord::new(6, 3), PieceColor::White, None),
+            "Nfd2"
+        );
+    }
+
+    #[test]
+    fn selected_piece_label_follows_the_board_orientation() {
+        let mut game = Game::default();
+        assert_eq!(game.ui.selected_piece_label(&game), "");
+
+        game.ui.cursor_coordinates = Coord::new(7, 6);
+        game.handle_cell_click();
+        assert_eq!(game.ui.selected_piece_label(&game), "Ng1");
+
+        // 1. Nf3, black plays with his pieces at the bottom
+        game.ui.cursor_coordinates = Coord::new(5, 5);
+        game.handle_cell_click();
+        assert_eq!(game.ui.selected_piece_label(&game), "");
+
+        game.ui.cursor_coordinates = Coord::new(6, 3);
+        game.handle_cell_click();
+        assert_eq!(game.ui.selected_piece_label(&game), "e7");
+    }
+}
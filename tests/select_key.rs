@@ -0,0 +1,100 @@
+#[cfg(test)]
+mod tests {
+    use chess_tui::app::App;
+    use chess_tui::constants::{Pages, Popups};
+    use chess_tui::game_logic::coord::Coord;
+    use chess_tui::handler::handle_key_events;
+    use chess_tui::pieces::PieceColor;
+    use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    fn press_enter(app: &mut App) {
+        handle_key_events(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE), app).unwrap();
+    }
+
+    #[test]
+    fn enter_on_the_menu_selects_the_entry() {
+        let mut app = App::default();
+
+        press_enter(&mut app);
+        assert_eq!(app.current_page, Pages::Solo);
+    }
+
+    #[test]
+    fn enter_in_game_selects_a_piece() {
+        let mut app = App {
+            current_page: Pages::Solo,
+            ..Default::default()
+        };
+        app.game.ui.cursor_coordinates = Coord::new(6, 4);
+
+        press_enter(&mut app);
+        assert_eq!(app.game.ui.selected_coordinates, Coord::new(6, 4));
+    }
+
+    #[test]
+    fn enter_closes_the_help_without_touching_the_board() {
+        let mut app = App {
+            current_page: Pages::Solo,
+            current_popup: Some(Popups::Help),
+            ..Default::default()
+        };
+        app.game.ui.cursor_coordinates = Coord::new(6, 4);
+
+        press_enter(&mut app);
+        assert_eq!(app.current_popup, None);
+        assert_eq!(app.game.ui.selected_coordinates, Coord::undefined());
+
+        // On the menu the help is closed as well instead of opening a game
+        let mut app = App {
+            current_popup: Some(Popups::Help),
+            ..Default::default()
+        };
+        press_enter(&mut app);
+        assert_eq!(app.current_popup, None);
+        assert_eq!(app.current_page, Pages::Home);
+    }
+
+    #[test]
+    fn enter_confirms_the_choice_popups() {
+        let mut app = App {
+            current_page: Pages::Multiplayer,
+            current_popup: Some(Popups::MultiplayerSelection),
+            ..Default::default()
+        };
+        press_enter(&mut app);
+        assert_eq!(app.hosting, Some(true));
+        assert_eq!(app.current_popup, None);
+
+        let mut app = App {
+            current_page: Pages::Bot,
+            current_popup: Some(Popups::ColorSelection),
+            ..Default::default()
+        };
+        press_enter(&mut app);
+        assert_eq!(app.selected_color, Some(PieceColor::White));
+        assert_eq!(app.current_popup, None);
+    }
+
+    #[test]
+    fn enter_is_ignored_until_the_game_is_ready() {
+        let mut app = App {
+            current_page: Pages::Bot,
+            current_popup: Some(Popups::EngineNotUci),
+            ..Default::default()
+        };
+        press_enter(&mut app);
+        assert_eq!(app.current_popup, Some(Popups::EngineNotUci));
+        assert_eq!(app.selected_color, None);
+
+        // Waiting for the opponent, the board can't be played
+        let mut app = App {
+            current_page: Pages::Multiplayer,
+            hosting: Some(true),
+            selected_color: Some(PieceColor::White),
+            ..Default::default()
+        };
+        app.game.ui.cursor_coordinates = Coord::new(6, 4);
+        press_enter(&mut app);
+        assert_eq!(app.game.ui.selected_coordinates, Coord::undefined());
+    }
+}
@@ -0,0 +1,52 @@
+#[cfg(test)]
+mod tests {
+    use chess_tui::app::App;
+    use chess_tui::constants::{Pages, Popups};
+    use chess_tui::handler::handle_key_events;
+    use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::time::Duration;
+
+    #[test]
+    fn home_and_solo_startup_pages() {
+        let mut app = App::default();
+        app.open_startup_page("home");
+        assert_eq!(app.current_page, Pages::Home);
+        assert_eq!(app.current_popup, None);
+
+        app.open_startup_page("solo");
+        assert_eq!(app.current_page, Pages::Solo);
+        assert_eq!(app.current_popup, None);
+    }
+
+    #[test]
+    fn bot_startup_page_without_engine_asks_for_one() {
+        let mut app = App::default();
+        app.open_startup_page("bot");
+        assert_eq!(app.current_page, Pages::Bot);
+        assert_eq!(app.current_popup, Some(Popups::EnginePathError));
+    }
+
+    #[test]
+    fn unavailable_startup_page_stays_on_home() {
+        let mut app = App::default();
+        app.open_startup_page("puzzle");
+        assert_eq!(app.current_page, Pages::Home);
+        assert_eq!(app.current_popup, Some(Popups::StartupPageUnavailable));
+        assert_eq!(app.unavailable_startup_page.as_deref(), Some("puzzle"));
+
+        handle_key_events(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE), &mut app).unwrap();
+        assert_eq!(app.current_popup, None);
+        assert_eq!(app.unavailable_startup_page, None);
+        assert_eq!(app.current_page, Pages::Home);
+    }
+
+    #[test]
+    fn bot_vs_bot_without_engine_asks_for_one() {
+        let mut app = App::default();
+        app.start_bot_vs_bot(Duration::from_millis(100));
+        assert_eq!(app.current_page, Pages::Bot);
+        assert_eq!(app.current_popup, Some(Popups::EnginePathError));
+        assert!(app.bot_demo.is_none());
+        assert!(app.game.bot.is_none());
+    }
+}
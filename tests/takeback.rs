@@ -0,0 +1,136 @@
+#[cfg(test)]
+mod tests {
+    use chess_tui::app::App;
+    use chess_tui::constants::Pages;
+    use chess_tui::game_logic::coord::Coord;
+    use chess_tui::game_logic::game::GameState;
+    use chess_tui::game_logic::game_board::GameBoard;
+    use chess_tui::handler::handle_key_events;
+    use chess_tui::pieces::{PieceColor, PieceType};
+    use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    fn press(app: &mut App, code: KeyCode) {
+        handle_key_events(KeyEvent::new(code, KeyModifiers::NONE), app).unwrap();
+    }
+
+    // Play a move with the cells of the stored board
+    fn play(app: &mut App, from: Coord, to: Coord) {
+        app.game.ui.cursor_coordinates = from;
+        press(app, KeyCode::Enter);
+        app.game.ui.cursor_coordinates = to;
+        press(app, KeyCode::Enter);
+    }
+
+    fn solo_app() -> App {
+        let mut app = App::default();
+        app.menu_select();
+        assert_eq!(app.current_page, Pages::Solo);
+        app
+    }
+
+    #[test]
+    fn nothing_to_take_back_in_a_new_game() {
+        let mut app = solo_app();
+        press(&mut app, KeyCode::Char('z'));
+        assert_eq!(app.game.game_board.board, GameBoard::default().board);
+        assert_eq!(app.game.player_turn, PieceColor::White);
+        assert!(!app.game.game_board.is_flipped);
+    }
+
+    #[test]
+    fn take_back_turns_the_board_back() {
+        let mut app = solo_app();
+
+        // 1. e4, black is now at the bottom
+        play(&mut app, Coord::new(6, 4), Coord::new(4, 4));
+        assert!(app.game.game_board.is_flipped);
+        assert_eq!(app.game.player_turn, PieceColor::Black);
+
+        press(&mut app, KeyCode::Char('z'));
+        assert!(!app.game.game_board.is_flipped);
+        assert_eq!(app.game.player_turn, PieceColor::White);
+        assert_eq!(app.game.game_board.board, GameBoard::default().board);
+        assert!(app.game.game_board.move_history.is_empty());
+        assert!(app.game.game_board.san_history.is_empty());
+        assert_eq!(app.game.game_board.board_history.len(), 1);
+        // The cursor is back on e2
+        assert_eq!(app.game.ui.cursor_coordinates, Coord::new(6, 4));
+
+        // The move can be played again
+        play(&mut app, Coord::new(6, 4), Coord::new(4, 4));
+        assert_eq!(app.game.game_board.san_history, vec!["e4"]);
+        assert!(app.game.game_board.is_flipped);
+    }
+
+    #[test]
+    fn take_back_of_a_black_move_keeps_black_at_the_bottom() {
+        let mut app = solo_app();
+
+        // 1. e4 d5
+        play(&mut app, Coord::new(6, 4), Coord::new(4, 4));
+        play(&mut app, Coord::new(6, 4), Coord::new(4, 4));
+        assert!(!app.game.game_board.is_flipped);
+
+        press(&mut app, KeyCode::Char('z'));
+        assert!(app.game.game_board.is_flipped);
+        assert_eq!(app.game.player_turn, PieceColor::Black);
+        assert_eq!(app.game.game_board.san_history, vec!["e4"]);
+        assert_eq!(
+            app.game.game_board.board[3][3],
+            Some((PieceType::Pawn, PieceColor::White))
+        );
+        assert_eq!(
+            app.game.game_board.board[6][4],
+            Some((PieceType::Pawn, PieceColor::Black))
+        );
+    }
+
+    #[test]
+    fn take_back_gives_the_captured_piece_back() {
+        let mut app = solo_app();
+
+        // 1. e4 d5 2. exd5
+        play(&mut app, Coord::new(6, 4), Coord::new(4, 4));
+        play(&mut app, Coord::new(6, 4), Coord::new(4, 4));
+        play(&mut app, Coord::new(4, 4), Coord::new(3, 3));
+        assert_eq!(
+            app.game.game_board.white_taken_pieces,
+            vec![PieceType::Pawn]
+        );
+
+        press(&mut app, KeyCode::Char('z'));
+        assert!(app.game.game_board.white_taken_pieces.is_empty());
+        assert_eq!(app.game.player_turn, PieceColor::White);
+        assert_eq!(
+            app.game.game_board.board[3][3],
+            Some((PieceType::Pawn, PieceColor::Black))
+        );
+    }
+
+    #[test]
+    fn take_back_of_a_mate_resumes_the_game() {
+        let mut app = solo_app();
+
+        // 1. f3 e5 2. g4 Qh4#
+        play(&mut app, Coord::new(6, 5), Coord::new(5, 5));
+        play(&mut app, Coord::new(6, 3), Coord::new(4, 3));
+        play(&mut app, Coord::new(6, 6), Coord::new(4, 6));
+        play(&mut app, Coord::new(7, 4), Coord::new(3, 0));
+        assert_eq!(app.game.game_state, GameState::Checkmate);
+
+        press(&mut app, KeyCode::Char('z'));
+        assert_eq!(app.game.game_state, GameState::Playing);
+        assert_eq!(app.game.player_turn, PieceColor::Black);
+        assert_eq!(app.game.game_board.move_history.len(), 3);
+    }
+
+    #[test]
+    fn bot_games_can_not_take_back() {
+        let mut app = solo_app();
+        play(&mut app, Coord::new(6, 4), Coord::new(4, 4));
+
+        app.current_page = Pages::Bot;
+        press(&mut app, KeyCode::Char('z'));
+        assert_eq!(app.game.game_board.move_history.len(), 1);
+    }
+}
@@ -1,6 +1,9 @@
 #[cfg(test)]
 mod tests {
-    use chess_tui::utils::{convert_notation_into_position, convert_position_into_notation};
+    use chess_tui::utils::{
+        address_with_port, convert_notation_into_position, convert_position_into_notation,
+        network_port,
+    };
 
     #[test]
     fn convert_position_into_notation_1() {
@@ -24,4 +27,36 @@ mod tests {
     fn convert_notation_into_position_3() {
         assert_eq!(convert_notation_into_position("g1f3"), "7655")
     }
+
+    #[test]
+    fn network_port_range() {
+        assert_eq!(network_port(2308), Some(2308));
+        assert_eq!(network_port(1024), Some(1024));
+        assert_eq!(network_port(65535), Some(65535));
+        assert_eq!(network_port(80), None);
+        assert_eq!(network_port(65536), None);
+        assert_eq!(network_port(-1), None);
+    }
+
+    #[test]
+    fn address_with_port_keeps_the_typed_port() {
+        assert_eq!(address_with_port("10.0.0.2", 4000), "10.0.0.2:4000");
+        assert_eq!(address_with_port("10.0.0.2:2308", 4000), "10.0.0.2:2308");
+        assert_eq!(address_with_port("bore.pub", 4000), "bore.pub:4000");
+        assert_eq!(address_with_port("bore.pub:12455", 4000), "bore.pub:12455");
+    }
+
+    #[test]
+    fn address_with_port_brackets_ipv6() {
+        assert_eq!(
+            address_with_port("[2001:db8::1]:2308", 4000),
+            "[2001:db8::1]:2308"
+        );
+        assert_eq!(
+            address_with_port("[2001:db8::1]", 4000),
+            "[2001:db8::1]:4000"
+        );
+        assert_eq!(address_with_port("2001:db8::1", 4000), "[2001:db8::1]:4000");
+        assert_eq!(address_with_port("::1", 2308), "[::1]:2308");
+    }
 }